@@ -0,0 +1,55 @@
+use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
+
+fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+fn solve_p1(_data: &[&str]) -> usize {
+    // TODO: Implement Part 1
+    0
+}
+
+fn solve_p2(_data: &[&str]) -> usize {
+    // TODO: Implement Part 2
+    0
+}
+
+struct DayDAY_NUMBER;
+
+impl Solution for DayDAY_NUMBER {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Vec<&str> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(DAY_NUMBER);
+    aoc_core::run::<DayDAY_NUMBER>(&input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_with_test_input() {
+        let input = read_test_input(DAY_NUMBER);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
+        assert_eq!(answer, EXPECTED_P1);
+        let answer = solve_p2(&data);
+        assert_eq!(answer, EXPECTED_P2);
+    }
+}
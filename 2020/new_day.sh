@@ -22,7 +22,7 @@ SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
 cd "$SCRIPT_DIR"
 
 # File paths
-TEMPLATE_FILE="src/bin/day_template.rs"
+TEMPLATE_FILE="day_template.rs"
 NEW_BIN_FILE="src/bin/day${DAY}.rs"
 INPUT_FILE="inputs/day${DAY}.txt"
 TEST_INPUT_FILE="inputs/day${DAY}_test.txt"
@@ -24,7 +24,7 @@ def setup_day(day_num: int):
     script_dir = Path(__file__).parent
     src_bin_dir = script_dir / "src" / "bin"
     inputs_dir = script_dir / "inputs"
-    template_file = src_bin_dir / "day_template.rs"
+    template_file = script_dir / "day_template.rs"
     target_file = src_bin_dir / f"day{day_str}.rs"
     test_input_file = inputs_dir / f"day{day_str}_test.txt"
     input_file = inputs_dir / f"day{day_str}.txt"
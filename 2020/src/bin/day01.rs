@@ -1,15 +1,16 @@
 use aoc2020::*;
+use aoc_core::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<i32> {
     parse_lines(input)
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let data = parse_input(input);
+fn solve_p1(data: &[i32]) -> i32 {
     let target = 2020;
     let mut seen = HashSet::new();
-    for &num in &data {
+    for &num in data {
         let complement = target - num;
         if seen.contains(&complement) {
             return num * complement;
@@ -19,8 +20,7 @@ fn solve_p1(input: &str) -> i32 {
     0
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let data = parse_input(input);
+fn solve_p2(data: &[i32]) -> i32 {
     let target = 2020;
     let set: HashSet<i32> = data.iter().copied().collect();
 
@@ -38,18 +38,27 @@ fn solve_p2(input: &str) -> i32 {
     0
 }
 
-fn main() {
-    let input = read_input(1);
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<i32> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(1);
+    aoc_core::run::<Day01>(&input);
 }
 
 #[cfg(test)]
@@ -59,9 +68,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(1);
-        let answer = solve_p1(&input);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 514579);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 241861950);
     }
 }
@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 struct PasswordPolicy {
     min: usize,
@@ -28,8 +30,7 @@ fn parse_input(input: &str) -> Vec<(PasswordPolicy, String)> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let passwords = parse_input(input);
+fn solve_p1(passwords: &[(PasswordPolicy, String)]) -> i32 {
     let mut valid_count = 0;
     for (policy, password) in passwords {
         let letter_count = password.chars().filter(|&c| c == policy.letter).count();
@@ -40,8 +41,7 @@ fn solve_p1(input: &str) -> i32 {
     valid_count
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let passwords = parse_input(input);
+fn solve_p2(passwords: &[(PasswordPolicy, String)]) -> i32 {
     let mut valid_count = 0;
     for (policy, password) in passwords {
         let first_pos = policy.min - 1;
@@ -55,18 +55,27 @@ fn solve_p2(input: &str) -> i32 {
     valid_count
 }
 
-fn main() {
-    let input = read_input(2);
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<(PasswordPolicy, String)>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<(PasswordPolicy, String)> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(2);
+    aoc_core::run::<Day02>(&input);
 }
 
 #[cfg(test)]
@@ -76,9 +85,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(2);
-        let answer = solve_p1(&input);
+        let passwords = parse_input(&input);
+        let answer = solve_p1(&passwords);
         assert_eq!(answer, 2);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&passwords);
         assert_eq!(answer, 1);
     }
 }
@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Grid<char> {
     parse_grid(input)
@@ -18,33 +20,40 @@ fn traverse_slope(slope: &Grid<char>, right: usize, down: usize) -> usize {
     tree_count
 }
 
-fn solve_p1(input: &str) -> usize {
-    let slope = parse_input(input);
-    traverse_slope(&slope, 3, 1)
+fn solve_p1(slope: &Grid<char>) -> usize {
+    traverse_slope(slope, 3, 1)
 }
 
-fn solve_p2(input: &str) -> usize {
-    let slope = parse_input(input);
+fn solve_p2(slope: &Grid<char>) -> usize {
     let mut product = 1;
     for (right, down) in &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)] {
-        let count = traverse_slope(&slope, *right, *down);
+        let count = traverse_slope(slope, *right, *down);
         product *= count;
     }
     product
 }
 
-fn main() {
-    let input = read_input(3);
+struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Grid<char>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Grid<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(3);
+    aoc_core::run::<Day03>(&input);
 }
 
 #[cfg(test)]
@@ -54,9 +63,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(3);
-        let answer = solve_p1(&input);
+        let slope = parse_input(&input);
+        let answer = solve_p1(&slope);
         assert_eq!(answer, 7);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&slope);
         assert_eq!(answer, 336);
     }
 }
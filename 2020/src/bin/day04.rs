@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 const REQUIRED_FIELDS: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
 
@@ -20,10 +22,9 @@ fn parse_input(input: &str) -> Vec<HashMap<String, String>> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let passports = parse_input(input);
+fn solve_p1(passports: &[HashMap<String, String>]) -> usize {
     passports
-        .into_iter()
+        .iter()
         .filter(|passport| {
             REQUIRED_FIELDS
                 .iter()
@@ -84,10 +85,9 @@ fn validate_field(key: &str, value: &str) -> bool {
     }
 }
 
-fn solve_p2(input: &str) -> usize {
-    let passports = parse_input(input);
+fn solve_p2(passports: &[HashMap<String, String>]) -> usize {
     passports
-        .into_iter()
+        .iter()
         .filter(|passport| {
             REQUIRED_FIELDS
                 .iter()
@@ -99,18 +99,27 @@ fn solve_p2(input: &str) -> usize {
         .count()
 }
 
-fn main() {
-    let input = read_input(4);
+struct Day04;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day04 {
+    type Input<'a> = Vec<HashMap<String, String>>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<HashMap<String, String>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(4);
+    aoc_core::run::<Day04>(&input);
 }
 
 #[cfg(test)]
@@ -120,9 +129,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(4);
-        let answer = solve_p1(&input);
+        let passports = parse_input(&input);
+        let answer = solve_p1(&passports);
         assert_eq!(answer, 2);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&passports);
         assert_eq!(answer, 2);
     }
 }
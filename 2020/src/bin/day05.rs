@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 #[allow(dead_code)]
 struct Seat {
@@ -25,18 +27,17 @@ fn parse_input(input: &str) -> Vec<&str> {
     input.lines().collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let seats = parse_input(input);
+fn solve_p1(seats: &[&str]) -> usize {
     seats
-        .into_iter()
+        .iter()
         .map(|s| s.parse::<Seat>().unwrap().id as usize)
         .max()
         .unwrap()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let seat_ids = parse_input(input)
-        .into_iter()
+fn solve_p2(seats: &[&str]) -> usize {
+    let seat_ids = seats
+        .iter()
         .map(|s| s.parse::<Seat>().unwrap().id)
         .collect::<Vec<_>>();
 
@@ -52,18 +53,27 @@ fn solve_p2(input: &str) -> usize {
     0
 }
 
-fn main() {
-    let input = read_input(5);
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<&'a str>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<&str> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(5);
+    aoc_core::run::<Day05>(&input);
 }
 
 #[cfg(test)]
@@ -73,7 +83,7 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(5);
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 820);
     }
 
@@ -1,30 +1,31 @@
 use std::collections::HashSet;
 
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
-fn parse_input(input: &str) -> Vec<HashSet<char>> {
-    let group: Vec<&str> = input.split("\n\n").collect();
-    group
-        .into_iter()
-        .map(|g| g.lines().flat_map(|line| line.trim().chars()).collect())
+/// The answers of each person in each group
+fn parse_input(input: &str) -> Vec<Vec<&str>> {
+    input
+        .split("\n\n")
+        .map(|g| g.split_whitespace().collect())
         .collect()
 }
 
-fn parse_groups(input: &str) -> Vec<HashSet<&str>> {
-    let group: Vec<&str> = input.split("\n\n").collect();
-    group
-        .into_iter()
-        .map(|g| g.lines().flat_map(|line| line.split_whitespace()).collect())
-        .collect()
-}
-
-fn solve_p1(input: &str) -> usize {
-    let data = parse_input(input);
-    data.iter().map(|s| s.len()).sum()
+fn solve_p1(groups: &[Vec<&str>]) -> usize {
+    groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .flat_map(|person| person.chars())
+                .collect::<HashSet<char>>()
+                .len()
+        })
+        .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let groups = parse_groups(input);
+fn solve_p2(groups: &[Vec<&str>]) -> usize {
     groups
         .iter()
         .map(|group| {
@@ -43,18 +44,27 @@ fn solve_p2(input: &str) -> usize {
         .sum()
 }
 
-fn main() {
-    let input = read_input(6);
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<Vec<&'a str>>;
+
+    fn parse(input: &str) -> Vec<Vec<&str>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+fn main() {
+    let input = read_input(6);
+    aoc_core::run::<Day06>(&input);
 }
 
 #[cfg(test)]
@@ -64,9 +74,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(6);
-        let answer = solve_p1(&input);
+        let groups = parse_input(&input);
+        let answer = solve_p1(&groups);
         assert_eq!(answer, 11);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&groups);
         assert_eq!(answer, 6);
     }
 }
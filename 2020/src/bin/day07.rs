@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct BagRule {
@@ -34,15 +36,14 @@ fn parse_input(input: &str) -> Vec<BagRule> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let bag_rules = parse_input(input);
+fn solve_p1(bag_rules: &[BagRule]) -> usize {
     let my_bag = "shiny gold";
 
     let mut can_contain_my_bag = std::collections::HashSet::new();
     let mut changed = true;
     while changed {
         changed = false;
-        for rule in &bag_rules {
+        for rule in bag_rules {
             if rule
                 .inners
                 .iter()
@@ -57,7 +58,7 @@ fn solve_p1(input: &str) -> usize {
 }
 
 fn count_inner_bags(
-    bag_rules: &Vec<BagRule>,
+    bag_rules: &[BagRule],
     bag_color: &str,
     memo: &mut std::collections::HashMap<String, usize>,
 ) -> usize {
@@ -74,26 +75,34 @@ fn count_inner_bags(
     total
 }
 
-fn solve_p2(input: &str) -> usize {
-    let bag_rules = parse_input(input);
+fn solve_p2(bag_rules: &[BagRule]) -> usize {
     let my_bag = "shiny gold";
 
     let mut memo = std::collections::HashMap::new();
-    count_inner_bags(&bag_rules, my_bag, &mut memo)
+    count_inner_bags(bag_rules, my_bag, &mut memo)
 }
 
-fn main() {
-    let input = read_input(7);
+struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<BagRule>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<BagRule> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(7);
+    aoc_core::run::<Day07>(&input);
 }
 
 #[cfg(test)]
@@ -103,9 +112,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(7);
-        let answer = solve_p1(&input);
+        let bag_rules = parse_input(&input);
+        let answer = solve_p1(&bag_rules);
         assert_eq!(answer, 4);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&bag_rules);
         assert_eq!(answer, 32);
     }
 }
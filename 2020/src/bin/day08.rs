@@ -1,94 +1,96 @@
-use std::collections::HashSet;
-
 use aoc2020::*;
+use aoc_core::vm::{Flow, Instruction, Machine, Registers, Stop};
+use aoc_core::Solution;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Acc(i64),
+    Jmp(i64),
+    Nop(i64),
+}
+
+impl Instruction for Op {
+    type Registers = Registers<i64, 1>;
+    type Output = ();
+
+    fn execute(&self, regs: &mut Self::Registers, _out: &mut Vec<()>) -> Flow {
+        match *self {
+            Op::Acc(value) => regs[0] += value,
+            Op::Jmp(offset) => return Flow::Jump(offset),
+            Op::Nop(_) => {}
+        }
+        Flow::Next
+    }
+}
 
-fn parse_input(input: &str) -> Vec<(&str, i32)> {
+fn parse_input(input: &str) -> Vec<Op> {
     input
         .lines()
         .map(|line| {
             let mut parts = line.split_whitespace();
             let command = parts.next().unwrap();
-            let value = parts.next().unwrap().parse::<i32>().unwrap();
-            (command, value)
+            let value = parts.next().unwrap().parse::<i64>().unwrap();
+            match command {
+                "acc" => Op::Acc(value),
+                "jmp" => Op::Jmp(value),
+                "nop" => Op::Nop(value),
+                _ => panic!("Unknown command: {}", command),
+            }
         })
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let program = parse_input(input);
-    let mut accumulator = 0;
-    let mut pc: usize = 0;
-    let mut executed = HashSet::new();
-    while !executed.contains(&pc) {
-        executed.insert(pc);
-        let (command, value) = &program[pc];
-        match *command {
-            "acc" => {
-                accumulator += *value;
-                pc += 1;
-            }
-            "jmp" => {
-                pc = (pc as i32 + *value) as usize;
-            }
-            "nop" => {
-                pc += 1;
-            }
-            _ => panic!("Unknown command: {}", command),
-        }
-    }
+/// Run the program until it repeats an instruction or exits, returning how it stopped and the
+/// accumulator.
+fn run(program: Vec<Op>) -> (Stop, i64) {
+    let mut machine = Machine::new(program, Registers::default()).detect_loops();
+    let stop = machine.run();
+    (stop, machine.regs()[0])
+}
+
+fn solve_p1(program: &[Op]) -> usize {
+    let (stop, accumulator) = run(program.to_vec());
+    assert!(matches!(stop, Stop::Loop(_)));
     accumulator as usize
 }
 
-fn solve_p2(input: &str) -> usize {
-    let program = parse_input(input);
+fn solve_p2(program: &[Op]) -> usize {
     for i in 0..program.len() {
-        let mut accumulator = 0;
-        let mut pc: usize = 0;
-        let mut executed = HashSet::new();
-        while !executed.contains(&pc) {
-            if pc == program.len() {
-                return accumulator as usize;
-            }
-            executed.insert(pc);
-            let (command, value) = &program[pc];
-            match *command {
-                "acc" => {
-                    accumulator += *value;
-                    pc += 1;
-                }
-                "jmp" => {
-                    if i == pc {
-                        pc += 1;
-                    } else {
-                        pc = (pc as i32 + *value) as usize;
-                    }
-                }
-                "nop" => {
-                    if i == pc {
-                        pc = (pc as i32 + *value) as usize;
-                    } else {
-                        pc += 1;
-                    }
-                }
-                _ => panic!("Unknown command: {}", command),
-            }
+        let mut patched = program.to_vec();
+        patched[i] = match program[i] {
+            Op::Jmp(value) => Op::Nop(value),
+            Op::Nop(value) => Op::Jmp(value),
+            Op::Acc(_) => continue,
+        };
+        if let (Stop::Exited, accumulator) = run(patched) {
+            return accumulator as usize;
         }
     }
     0
 }
 
-fn main() {
-    let input = read_input(8);
+struct Day08;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day08 {
+    type Input<'a> = Vec<Op>;
+
+    fn parse(input: &str) -> Vec<Op> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(8);
+    aoc_core::run::<Day08>(&input);
 }
 
 #[cfg(test)]
@@ -98,9 +100,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(8);
-        let answer = solve_p1(&input);
+        let program = parse_input(&input);
+        let answer = solve_p1(&program);
         assert_eq!(answer, 5);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&program);
         assert_eq!(answer, 8);
     }
 }
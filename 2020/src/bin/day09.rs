@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<usize> {
     parse_lines(input)
@@ -23,14 +25,12 @@ fn find_first_invalid_number(numbers: &[usize], window_size: usize) -> Option<us
     None
 }
 
-fn solve_p1(input: &str, window_size: usize) -> usize {
-    let numbers = parse_input(input);
-    find_first_invalid_number(&numbers, window_size).unwrap()
+fn solve_p1(numbers: &[usize], window_size: usize) -> usize {
+    find_first_invalid_number(numbers, window_size).unwrap()
 }
 
-fn solve_p2(input: &str, window_size: usize) -> usize {
-    let numbers = parse_input(input);
-    find_first_invalid_number(&numbers, window_size)
+fn solve_p2(numbers: &[usize], window_size: usize) -> usize {
+    find_first_invalid_number(numbers, window_size)
         .and_then(|invalid_number| {
             for start in 0..numbers.len() {
                 let mut sum = 0;
@@ -51,18 +51,27 @@ fn solve_p2(input: &str, window_size: usize) -> usize {
         .unwrap()
 }
 
-fn main() {
-    let input = read_input(9);
+struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<usize>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input, 25);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, 25)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input, 25);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input, 25)
+    }
+}
+
+fn main() {
+    let input = read_input(9);
+    aoc_core::run::<Day09>(&input);
 }
 
 #[cfg(test)]
@@ -72,9 +81,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(9);
-        let answer = solve_p1(&input, 5);
+        let numbers = parse_input(&input);
+        let answer = solve_p1(&numbers, 5);
         assert_eq!(answer, 127);
-        let answer = solve_p2(&input, 5);
+        let answer = solve_p2(&numbers, 5);
         assert_eq!(answer, 62);
     }
 }
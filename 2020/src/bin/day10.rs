@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn solve_p1(input: &str) -> usize {
     let mut adapters: Vec<usize> = parse_lines(input);
@@ -37,18 +39,27 @@ fn solve_p2(input: &str) -> usize {
     *ways.last().unwrap_or(&0) as usize // Total ways to reach the device's built-in adapter
 }
 
-fn main() {
-    let input = read_input(10);
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = &'a str;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(10);
+    aoc_core::run::<Day10>(&input);
 }
 
 #[cfg(test)]
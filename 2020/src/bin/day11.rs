@@ -1,125 +1,63 @@
 use aoc2020::*;
-
-fn step_seatmap(
-    seatmap: &[Vec<char>],
-    occupied_threshold: usize,
-    use_visibility: bool,
-) -> (Vec<Vec<char>>, bool) {
-    let mut new_seatmap = seatmap.to_vec();
-    let mut changed = false;
-
-    for pos in grid::positions(seatmap) {
-        let seat = seatmap[pos.0][pos.1];
-        if seat == '.' {
-            continue;
-        }
-
-        let occupied_count = if use_visibility {
-            count_visible_occupied(seatmap, pos)
-        } else {
-            count_adjacent_occupied(seatmap, pos)
-        };
-
-        if seat == 'L' && occupied_count == 0 {
-            new_seatmap[pos.0][pos.1] = '#';
-            changed = true;
-        } else if seat == '#' && occupied_count >= occupied_threshold {
-            new_seatmap[pos.0][pos.1] = 'L';
-            changed = true;
-        }
-    }
-
-    (new_seatmap, changed)
+use aoc_core::automaton::{Automaton, Bits, Cell, Dense, Neighborhood, Rule};
+use aoc_core::{interval, Solution};
+use std::fmt::Display;
+
+/// The seating area's bounds and its floor, where no one sits
+fn parse_seatmap(input: &str) -> (interval::Box<i32, 2>, Vec<Cell<2>>) {
+    let seatmap = parse_grid(input);
+    let bounds = interval::Box::new([0, 0], [seatmap.len() as i32, seatmap[0].len() as i32]);
+    let floor = grid::find_all(&seatmap, |&c| c == '.')
+        .into_iter()
+        .map(|(row, col)| [row as i32, col as i32])
+        .collect();
+    (bounds, floor)
 }
 
-fn count_adjacent_occupied(seatmap: &[Vec<char>], pos: (usize, usize)) -> usize {
-    let (row, col) = pos;
-    grid::valid_neighbors8(seatmap, row as isize, col as isize)
-        .iter()
-        .map(|(r, c)| Some(&seatmap[*r][*c]))
-        .filter(|&c| c == Some(&'#'))
-        .count()
+/// Someone takes an empty seat when no occupied seats are in view, and leaves an occupied
+/// one when `threshold` or more are
+fn seating_rule(threshold: usize) -> Rule {
+    Rule::life_like(&[0], &(0..threshold).collect::<Vec<_>>())
 }
 
-fn count_visible_occupied(seatmap: &[Vec<char>], pos: (usize, usize)) -> usize {
-    let directions = [
-        (-1, -1),
-        (-1, 0),
-        (-1, 1),
-        (0, -1),
-        (0, 1),
-        (1, -1),
-        (1, 0),
-        (1, 1),
-    ];
+fn solve_p1(input: &str) -> usize {
+    let (bounds, floor) = parse_seatmap(input);
+    let mut seating = Bits::new(Neighborhood::moore(1), seating_rule(4), bounds, [])
+        .expect("counting rule over adjacent seats")
+        .with_voids(floor);
+    seating.run_until_stable();
+    seating.population().unwrap()
+}
 
-    let (row, col) = pos;
-    let rows = seatmap.len() as isize;
-    let cols = seatmap[0].len() as isize;
+fn solve_p2(input: &str) -> usize {
+    let (bounds, floor) = parse_seatmap(input);
+    let neighborhood = Neighborhood::moore(1).line_of_sight();
+    let mut seating = Dense::bounded(neighborhood, seating_rule(5), bounds, []).with_voids(floor);
+    seating.run_until_stable();
+    seating.population().unwrap()
+}
 
-    directions
-        .iter()
-        .filter(|(dr, dc)| {
-            let mut r = row as isize + dr;
-            let mut c = col as isize + dc;
+struct Day11;
 
-            while r >= 0 && r < rows && c >= 0 && c < cols {
-                let seat = seatmap[r as usize][c as usize];
-                if seat == '#' {
-                    return true;
-                } else if seat == 'L' {
-                    return false;
-                }
-                // Continue looking if it's floor ('.')
-                r += dr;
-                c += dc;
-            }
-            false
-        })
-        .count()
-}
+impl Solution for Day11 {
+    type Input<'a> = &'a str;
 
-fn count_occupied_seats(seatmap: &[Vec<char>]) -> usize {
-    seatmap
-        .iter()
-        .map(|row| row.iter().filter(|&&c| c == '#').count())
-        .sum()
-}
+    fn parse(input: &str) -> &str {
+        input
+    }
 
-fn solve_p1(input: &str) -> usize {
-    let mut seatmap = parse_grid(input);
-    loop {
-        let (new_seatmap, changed) = step_seatmap(&seatmap, 4, false);
-        if !changed {
-            return count_occupied_seats(&new_seatmap);
-        }
-        seatmap = new_seatmap;
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
     }
-}
 
-fn solve_p2(input: &str) -> usize {
-    let mut seatmap = parse_grid(input);
-    loop {
-        let (new_seatmap, changed) = step_seatmap(&seatmap, 5, true);
-        if !changed {
-            return count_occupied_seats(&new_seatmap);
-        }
-        seatmap = new_seatmap;
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
     }
 }
 
 fn main() {
     let input = read_input(11);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    aoc_core::run::<Day11>(&input);
 }
 
 #[cfg(test)]
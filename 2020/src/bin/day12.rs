@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 struct Instruction {
     action: char,
@@ -23,8 +25,7 @@ fn parse_input(input: &str) -> Vec<Instruction> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let instructions = parse_input(input);
+fn solve_p1(instructions: &[Instruction]) -> usize {
     let start = (0, 0);
     let mut position = start;
     let mut direction = Direction::East;
@@ -71,8 +72,7 @@ fn solve_p1(input: &str) -> usize {
     (position.0.abs() + position.1.abs()) as usize
 }
 
-fn solve_p2(input: &str) -> usize {
-    let instructions = parse_input(input);
+fn solve_p2(instructions: &[Instruction]) -> usize {
     let mut ship = (0, 0);
     let mut waypoint = (10, 1);
 
@@ -103,18 +103,27 @@ fn solve_p2(input: &str) -> usize {
     (ship.0.abs() + ship.1.abs()) as usize
 }
 
-fn main() {
-    let input = read_input(12);
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Instruction>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<Instruction> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(12);
+    aoc_core::run::<Day12>(&input);
 }
 
 #[cfg(test)]
@@ -124,9 +133,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(12);
-        let answer = solve_p1(&input);
+        let instructions = parse_input(&input);
+        let answer = solve_p1(&instructions);
         assert_eq!(answer, 25);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&instructions);
         assert_eq!(answer, 286);
     }
 }
@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> (usize, Vec<&str>) {
     let earliest_departure_time = input.lines().next().unwrap().parse().unwrap();
@@ -6,8 +8,7 @@ fn parse_input(input: &str) -> (usize, Vec<&str>) {
     (earliest_departure_time, buses)
 }
 
-fn solve_p1(input: &str) -> usize {
-    let (edt, buses) = parse_input(input);
+fn solve_p1((edt, buses): &(usize, Vec<&str>)) -> usize {
     let min_next_bus = buses
         .iter()
         .filter_map(|&bus| {
@@ -24,8 +25,7 @@ fn solve_p1(input: &str) -> usize {
     min_next_bus.0 * min_next_bus.1
 }
 
-fn solve_p2(input: &str) -> usize {
-    let (_, buses) = parse_input(input);
+fn solve_p2((_, buses): &(usize, Vec<&str>)) -> usize {
     let mut t = 0;
 
     let mut step = 1;
@@ -43,18 +43,27 @@ fn solve_p2(input: &str) -> usize {
     t
 }
 
-fn main() {
-    let input = read_input(13);
+struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = (usize, Vec<&'a str>);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (usize, Vec<&str>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(13);
+    aoc_core::run::<Day13>(&input);
 }
 
 #[cfg(test)]
@@ -64,9 +73,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(13);
-        let answer = solve_p1(&input);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 295);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 1068781); // For buses: 7,13,x,x,59,x,31,19
     }
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 struct Instruction {
     mask: String,
@@ -29,8 +31,7 @@ fn parse_input(input: &str) -> Vec<Instruction> {
     instructions
 }
 
-fn solve_p1(input: &str) -> usize {
-    let instructions = parse_input(input);
+fn solve_p1(instructions: &[Instruction]) -> usize {
     let mut memory = HashMap::<usize, usize>::new();
 
     for instr in instructions {
@@ -49,8 +50,7 @@ fn solve_p1(input: &str) -> usize {
     memory.iter().map(|(_, &v)| v).sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let instructions = parse_input(input);
+fn solve_p2(instructions: &[Instruction]) -> usize {
     let mut memory = HashMap::<usize, usize>::new();
 
     for instr in instructions {
@@ -82,18 +82,27 @@ fn solve_p2(input: &str) -> usize {
     memory.iter().map(|(_, &v)| v).sum()
 }
 
-fn main() {
-    let input = read_input(14);
+struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Instruction>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<Instruction> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(14);
+    aoc_core::run::<Day14>(&input);
 }
 
 #[cfg(test)]
@@ -103,14 +112,14 @@ mod tests {
     #[test]
     fn test_p1() {
         let input = read_test_input(14);
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 165);
     }
 
     #[test]
     fn test_p2() {
         let input = std::fs::read_to_string("inputs/day14_p2_test.txt").unwrap();
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&parse_input(&input));
         assert_eq!(answer, 208);
     }
 }
@@ -1,9 +1,14 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<usize> {
     input.split(',').map(|s| s.parse().unwrap()).collect()
 }
 
+/// Plays every turn up to `target_turn`. The game is a Van Eck sequence, which never starts
+/// repeating: the turns each number was last spoken keep changing, so there's no cycle to skip
+/// ahead with.
 fn play_game(starting_numbers: &[usize], target_turn: usize) -> usize {
     let mut last_seen: HashMap<usize, usize> = HashMap::new();
 
@@ -31,30 +36,37 @@ fn play_game(starting_numbers: &[usize], target_turn: usize) -> usize {
     current
 }
 
-fn solve_p1(input: &str) -> usize {
-    let starting_numbers = parse_input(input);
+fn solve_p1(starting_numbers: &[usize]) -> usize {
     let target_turn = 2020;
-    play_game(&starting_numbers, target_turn)
+    play_game(starting_numbers, target_turn)
 }
 
-fn solve_p2(input: &str) -> usize {
-    let starting_numbers = parse_input(input);
+fn solve_p2(starting_numbers: &[usize]) -> usize {
     let target_turn = 30_000_000;
-    play_game(&starting_numbers, target_turn)
+    play_game(starting_numbers, target_turn)
 }
 
-fn main() {
-    let input = "1,20,8,12,0,14";
+struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Vec<usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+fn main() {
+    let input = "1,20,8,12,0,14";
+    aoc_core::run::<Day15>(input);
 }
 
 #[cfg(test)]
@@ -63,20 +75,20 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        assert_eq!(solve_p1("0,3,6"), 436);
-        assert_eq!(solve_p1("1,3,2"), 1);
-        assert_eq!(solve_p1("2,1,3"), 10);
-        assert_eq!(solve_p1("1,2,3"), 27);
-        assert_eq!(solve_p1("2,3,1"), 78);
-        assert_eq!(solve_p1("3,2,1"), 438);
-        assert_eq!(solve_p1("3,1,2"), 1836);
-
-        assert_eq!(solve_p2("0,3,6"), 175594);
-        assert_eq!(solve_p2("1,3,2"), 2578);
-        assert_eq!(solve_p2("2,1,3"), 3544142);
-        assert_eq!(solve_p2("1,2,3"), 261214);
-        assert_eq!(solve_p2("2,3,1"), 6895259);
-        assert_eq!(solve_p2("3,2,1"), 18);
-        assert_eq!(solve_p2("3,1,2"), 362);
+        assert_eq!(solve_p1(&parse_input("0,3,6")), 436);
+        assert_eq!(solve_p1(&parse_input("1,3,2")), 1);
+        assert_eq!(solve_p1(&parse_input("2,1,3")), 10);
+        assert_eq!(solve_p1(&parse_input("1,2,3")), 27);
+        assert_eq!(solve_p1(&parse_input("2,3,1")), 78);
+        assert_eq!(solve_p1(&parse_input("3,2,1")), 438);
+        assert_eq!(solve_p1(&parse_input("3,1,2")), 1836);
+
+        assert_eq!(solve_p2(&parse_input("0,3,6")), 175594);
+        assert_eq!(solve_p2(&parse_input("1,3,2")), 2578);
+        assert_eq!(solve_p2(&parse_input("2,1,3")), 3544142);
+        assert_eq!(solve_p2(&parse_input("1,2,3")), 261214);
+        assert_eq!(solve_p2(&parse_input("2,3,1")), 6895259);
+        assert_eq!(solve_p2(&parse_input("3,2,1")), 18);
+        assert_eq!(solve_p2(&parse_input("3,1,2")), 362);
     }
 }
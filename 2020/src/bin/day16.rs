@@ -1,4 +1,6 @@
 use aoc2020::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 struct Field {
     name: String,
@@ -46,12 +48,12 @@ fn parse_input(input: &str) -> (Vec<Field>, Vec<usize>, Vec<Vec<usize>>) {
     (fields, your_ticket, nearby_tickets)
 }
 
-fn solve_p1(input: &str) -> usize {
-    let (fields, _your_ticket, nearby_tickets) = parse_input(input);
-
+fn solve_p1(
+    (fields, _your_ticket, nearby_tickets): &(Vec<Field>, Vec<usize>, Vec<Vec<usize>>),
+) -> usize {
     let mut error_rate = 0;
     for ticket in nearby_tickets {
-        for &value in &ticket {
+        for &value in ticket {
             if !fields.iter().any(|field| {
                 field
                     .ranges
@@ -65,9 +67,9 @@ fn solve_p1(input: &str) -> usize {
     error_rate
 }
 
-fn solve_p2(input: &str) -> usize {
-    let (fields, your_ticket, nearby_tickets) = parse_input(input);
-
+fn solve_p2(
+    (fields, your_ticket, nearby_tickets): &(Vec<Field>, Vec<usize>, Vec<Vec<usize>>),
+) -> usize {
     let valid_tickets: Vec<&Vec<usize>> = nearby_tickets
         .iter()
         .filter(|ticket| {
@@ -127,18 +129,27 @@ fn solve_p2(input: &str) -> usize {
     product
 }
 
-fn main() {
-    let input = read_input(16);
+struct Day16;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day16 {
+    type Input<'a> = (Vec<Field>, Vec<usize>, Vec<Vec<usize>>);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (Vec<Field>, Vec<usize>, Vec<Vec<usize>>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(16);
+    aoc_core::run::<Day16>(&input);
 }
 
 #[cfg(test)]
@@ -148,7 +159,7 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(16);
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 71);
     }
 }
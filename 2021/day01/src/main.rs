@@ -1,9 +1,11 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<i32> {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let data = parse_input(input);
+fn solve_p1(data: &[i32]) -> i32 {
     let mut count = 0;
     for window in data.windows(2) {
         if window[1] > window[0] {
@@ -13,8 +15,7 @@ fn solve_p1(input: &str) -> i32 {
     count
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let data = parse_input(input);
+fn solve_p2(data: &[i32]) -> i32 {
     let mut count = 0;
     for window in data.windows(4) {
         let sum1: i32 = window[0..3].iter().sum();
@@ -26,20 +27,27 @@ fn solve_p2(input: &str) -> i32 {
     count
 }
 
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Vec<i32> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
-    let input_path = std::path::Path::new(manifest_dir).join("input.txt");
-    let input = std::fs::read_to_string(input_path).unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2021, 1);
+    aoc_core::run::<Day01>(&input);
 }
 
 #[cfg(test)]
@@ -51,9 +59,10 @@ mod tests {
         let manifest_dir = env!("CARGO_MANIFEST_DIR");
         let test_input_path = std::path::Path::new(manifest_dir).join("test_input.txt");
         let input = std::fs::read_to_string(test_input_path).unwrap();
-        let answer = solve_p1(&input);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 7);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 5);
     }
 }
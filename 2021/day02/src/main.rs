@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 enum Direction {
     Forward,
     Down,
@@ -26,8 +29,7 @@ fn parse_input(input: &str) -> Vec<Command> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let commands = parse_input(input);
+fn solve_p1(commands: &[Command]) -> i32 {
     let mut horizontal = 0;
     let mut depth = 0;
     for command in commands {
@@ -40,8 +42,7 @@ fn solve_p1(input: &str) -> i32 {
     horizontal * depth
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let commands = parse_input(input);
+fn solve_p2(commands: &[Command]) -> i32 {
     let mut horizontal = 0;
     let mut depth = 0;
     let mut aim = 0;
@@ -58,18 +59,27 @@ fn solve_p2(input: &str) -> i32 {
     horizontal * depth
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> Vec<Command> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 2);
+    aoc_core::run::<Day02>(&input);
 }
 
 #[cfg(test)]
@@ -85,9 +95,10 @@ forward 2";
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(TEST_INPUT);
+        let commands = parse_input(TEST_INPUT);
+        let answer = solve_p1(&commands);
         assert_eq!(answer, 150);
-        let answer = solve_p2(TEST_INPUT);
+        let answer = solve_p2(&commands);
         assert_eq!(answer, 900);
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<Vec<bool>> {
     input
         .lines()
@@ -5,14 +8,13 @@ fn parse_input(input: &str) -> Vec<Vec<bool>> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let data = parse_input(input);
+fn solve_p1(data: &[Vec<bool>]) -> usize {
     let mut gamma = 0;
     let mut epsilon = 0;
     for i in 0..data[0].len() {
         let mask = 1 << (data[0].len() - 1 - i);
         let mut count_ones = 0;
-        for num in &data {
+        for num in data {
             if num[i] {
                 count_ones += 1;
             }
@@ -26,10 +28,9 @@ fn solve_p1(input: &str) -> usize {
     gamma * epsilon
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let data = parse_input(input);
-    let mut oxygen_candidates = data.clone();
-    let mut co2_candidates = data.clone();
+fn solve_p2(data: &[Vec<bool>]) -> i32 {
+    let mut oxygen_candidates = data.to_vec();
+    let mut co2_candidates = data.to_vec();
     let bit_length = data[0].len();
     for i in 0..bit_length {
         if oxygen_candidates.len() > 1 {
@@ -62,18 +63,27 @@ fn solve_p2(input: &str) -> i32 {
     oxygen * co2
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Vec<bool>>;
+
+    fn parse(input: &str) -> Vec<Vec<bool>> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 3);
+    aoc_core::run::<Day03>(&input);
 }
 
 #[cfg(test)]
@@ -96,9 +106,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = TEST_INPUT;
-        let answer = solve_p1(&input);
+        let data = parse_input(input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 198);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 230);
     }
 }
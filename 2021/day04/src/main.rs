@@ -1,10 +1,14 @@
+use aoc_core::Solution;
 use core::str;
+use std::fmt::Display;
 
+#[derive(Clone)]
 struct Game {
     numbers: Vec<i32>,
     boards: Vec<Board>,
 }
 
+#[derive(Clone)]
 struct Board {
     // Add fields as necessary
     numbers: Vec<Vec<i32>>,
@@ -78,8 +82,8 @@ fn get_unmarked_sum(board: &Board) -> i32 {
         .sum()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let mut game = parse_input(input);
+fn solve_p1(game: &Game) -> i32 {
+    let mut game = game.clone();
     for &number in &game.numbers {
         for board in &mut game.boards {
             for i in 0..5 {
@@ -99,8 +103,8 @@ fn solve_p1(input: &str) -> i32 {
     0
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let mut game = parse_input(input);
+fn solve_p2(game: &Game) -> i32 {
+    let mut game = game.clone();
     let mut winning_boards = vec![false; game.boards.len()];
     let mut last_score = 0;
     for &number in &game.numbers {
@@ -125,18 +129,27 @@ fn solve_p2(input: &str) -> i32 {
     last_score
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Game;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Game {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 4);
+    aoc_core::run::<Day04>(&input);
 }
 
 #[cfg(test)]
@@ -146,9 +159,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let game = parse_input(&input);
+        let answer = solve_p1(&game);
         assert_eq!(answer, 4512);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&game);
         assert_eq!(answer, 1924);
     }
 }
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 struct Point {
     x: i32,
@@ -69,8 +71,7 @@ fn is_vertical(line: &Line) -> bool {
     line.start.x == line.end.x
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let lines = parse_input(input);
+fn solve_p1(lines: &[Line]) -> i32 {
     // Build a grid and count overlaps
     let mut grid = HashMap::new();
     for line in lines.iter() {
@@ -87,8 +88,7 @@ fn solve_p1(input: &str) -> i32 {
     grid.values().filter(|&&count| count > 1).count() as i32
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let lines = parse_input(input);
+fn solve_p2(lines: &[Line]) -> i32 {
     // Build a grid and count overlaps for all lines - horizontal, vertical, and diagonal
     let mut grid = HashMap::new();
     for line in lines.iter() {
@@ -103,18 +103,27 @@ fn solve_p2(input: &str) -> i32 {
     grid.values().filter(|&&count| count > 1).count() as i32
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<Line>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<Line> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 5);
+    aoc_core::run::<Day05>(&input);
 }
 
 #[cfg(test)]
@@ -136,9 +145,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = TEST_INPUT;
-        let answer = solve_p1(&input);
+        let lines = parse_input(input);
+        let answer = solve_p1(&lines);
         assert_eq!(answer, 5);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&lines);
         assert_eq!(answer, 12);
     }
 }
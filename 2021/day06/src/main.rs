@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<usize> {
     input.split(',').map(|num| num.parse().unwrap()).collect()
 }
@@ -14,27 +17,34 @@ fn fish_count(days: usize, initial_fish: &[usize]) -> usize {
     fish_counts.iter().sum()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let fish = parse_input(input);
-    fish_count(80, &fish)
+fn solve_p1(fish: &[usize]) -> usize {
+    fish_count(80, fish)
 }
-fn solve_p2(input: &str) -> usize {
-    let fish = parse_input(input);
-    fish_count(256, &fish)
+fn solve_p2(fish: &[usize]) -> usize {
+    fish_count(256, fish)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Vec<usize> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 6);
+    aoc_core::run::<Day06>(&input);
 }
 
 #[cfg(test)]
@@ -46,9 +56,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = TEST_INPUT;
-        let answer = solve_p1(&input);
+        let fish = parse_input(input);
+        let answer = solve_p1(&fish);
         assert_eq!(answer, 5934);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&fish);
         assert_eq!(answer, 26984457539);
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<i32> {
     input
         .trim()
@@ -10,21 +13,19 @@ fn find_median(crabs: &[i32]) -> i32 {
     let mut sorted = crabs.to_vec();
     sorted.sort_unstable();
     let mid = sorted.len() / 2;
-    if sorted.len() % 2 == 0 {
+    if sorted.len().is_multiple_of(2) {
         (sorted[mid - 1] + sorted[mid]) / 2
     } else {
         sorted[mid]
     }
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let crabs = parse_input(input);
-    let median = find_median(&crabs);
+fn solve_p1(crabs: &[i32]) -> i32 {
+    let median = find_median(crabs);
     crabs.iter().map(|&pos| (pos - median).abs()).sum()
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let crabs = parse_input(input);
+fn solve_p2(crabs: &[i32]) -> i32 {
     let mean = crabs.iter().copied().sum::<i32>() as f32 / crabs.len() as f32;
     println!("Mean position: {}", mean);
     let mean_floor = mean.floor() as i32;
@@ -46,18 +47,27 @@ fn solve_p2(input: &str) -> i32 {
     cost_floor.min(cost_ceil)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Vec<i32> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 7);
+    aoc_core::run::<Day07>(&input);
 }
 
 #[cfg(test)]
@@ -69,9 +79,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = TEST_INPUT;
-        let answer = solve_p1(&input);
+        let crabs = parse_input(input);
+        let answer = solve_p1(&crabs);
         assert_eq!(answer, 37);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&crabs);
         assert_eq!(answer, 168);
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 #[derive(Debug)]
 struct Entry {
     patterns: Vec<String>,
@@ -165,11 +168,10 @@ fn decode_five_segment_digits(
     }
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let entries = parse_input(input);
+fn solve_p1(entries: &[Entry]) -> i32 {
     let mut count = 0;
     for entry in entries {
-        for digit in entry.output {
+        for digit in &entry.output {
             let len = digit.len();
             // Digits 1, 7, 4, 8 have unique segment counts
             if len == 2 || len == 3 || len == 4 || len == 7 {
@@ -180,14 +182,13 @@ fn solve_p1(input: &str) -> i32 {
     count
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let entries = parse_input(input);
+fn solve_p2(entries: &[Entry]) -> i32 {
     let mut total = 0;
     for entry in entries {
         let pattern_map = decode(&entry.patterns);
         let mut value = 0;
-        for digit in entry.output {
-            let digit_value = match pattern_map.get(&digit) {
+        for digit in &entry.output {
+            let digit_value = match pattern_map.get(digit) {
                 Some(&decoded_digit) => decoded_digit,
                 None => 0,
             };
@@ -198,20 +199,27 @@ fn solve_p2(input: &str) -> i32 {
     total
 }
 
+struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<Entry>;
+
+    fn parse(input: &str) -> Vec<Entry> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
-    let input_path = std::path::Path::new(manifest_dir).join("input.txt");
-    let input = std::fs::read_to_string(input_path).unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2021, 8);
+    aoc_core::run::<Day08>(&input);
 }
 
 #[cfg(test)]
@@ -252,10 +260,11 @@ mod tests {
         let test_input_path = std::path::Path::new(manifest_dir).join("test_input.txt");
         let input = std::fs::read_to_string(test_input_path).unwrap();
 
-        let answer = solve_p1(&input);
+        let entries = parse_input(&input);
+        let answer = solve_p1(&entries);
         assert_eq!(answer, 26);
 
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&entries);
         assert_eq!(answer, 61229);
     }
 }
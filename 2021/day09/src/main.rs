@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use pathfinding::matrix::Matrix;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Matrix<usize> {
     Matrix::from_rows(input.lines().map(|line| {
@@ -9,8 +11,7 @@ fn parse_input(input: &str) -> Matrix<usize> {
     .unwrap()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let grid = parse_input(input);
+fn solve_p1(grid: &Matrix<usize>) -> i32 {
     // Find low points
     let low_points = grid
         .keys()
@@ -24,8 +25,7 @@ fn solve_p1(input: &str) -> i32 {
     risk_level
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let grid = parse_input(input);
+fn solve_p2(grid: &Matrix<usize>) -> i32 {
     let mut basin_sizes = Vec::new();
     let mut visited = std::collections::HashSet::new();
     for idx in grid.keys() {
@@ -54,18 +54,27 @@ fn solve_p2(input: &str) -> i32 {
     largest_three_product
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Matrix<usize>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Matrix<usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 9);
+    aoc_core::run::<Day09>(&input);
 }
 
 #[cfg(test)]
@@ -75,9 +84,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let grid = parse_input(&input);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 15);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 1134);
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<String> {
     input.lines().map(|line| line.to_string()).collect()
 }
@@ -32,13 +35,11 @@ fn syntax_error_score(line: &str) -> usize {
     }
     0
 }
-fn solve_p1(input: &str) -> usize {
-    let nav = parse_input(input);
+fn solve_p1(nav: &[String]) -> usize {
     nav.iter().map(|line| syntax_error_score(line)).sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let nav = parse_input(input);
+fn solve_p2(nav: &[String]) -> usize {
     let mut scores = Vec::new();
     let valid_lines = nav.iter().filter(|line| syntax_error_score(line) == 0);
     for line in valid_lines {
@@ -78,18 +79,27 @@ fn solve_p2(input: &str) -> usize {
     scores[scores.len() / 2] as usize
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<String>;
+
+    fn parse(input: &str) -> Vec<String> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 10);
+    aoc_core::run::<Day10>(&input);
 }
 
 #[cfg(test)]
@@ -99,9 +109,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let nav = parse_input(&input);
+        let answer = solve_p1(&nav);
         assert_eq!(answer, 26397);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&nav);
         assert_eq!(answer, 288957);
     }
 }
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use pathfinding::matrix::Matrix;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Matrix<usize> {
     Matrix::from_rows(input.lines().map(|line| {
@@ -49,8 +51,8 @@ fn process(octos: &mut Matrix<usize>) -> usize {
     flashes
 }
 
-fn solve_p1(input: &str) -> usize {
-    let mut octos = parse_input(input);
+fn solve_p1(octos: &Matrix<usize>) -> usize {
+    let mut octos = octos.clone();
     let mut flashes = 0;
     for _ in 0..100 {
         flashes += process(&mut octos);
@@ -58,8 +60,8 @@ fn solve_p1(input: &str) -> usize {
     flashes
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut octos = parse_input(input);
+fn solve_p2(octos: &Matrix<usize>) -> usize {
+    let mut octos = octos.clone();
     let mut flashes = 0;
     let mut step = 0;
     let total_octos = octos.rows * octos.columns;
@@ -70,18 +72,27 @@ fn solve_p2(input: &str) -> usize {
     step
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Matrix<usize>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Matrix<usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 11);
+    aoc_core::run::<Day11>(&input);
 }
 
 #[cfg(test)]
@@ -91,9 +102,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let octos = parse_input(&input);
+        let answer = solve_p1(&octos);
         assert_eq!(answer, 1656);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&octos);
         assert_eq!(answer, 195);
     }
 }
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> HashMap<String, Vec<String>> {
     let mut graph = HashMap::new();
@@ -68,15 +70,14 @@ fn dfs_with_path(
     count
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let graph = parse_input(input);
+fn solve_p1(graph: &HashMap<String, Vec<String>>) -> i32 {
     let mut visited = HashSet::new();
     let mut path = Vec::new();
     let mut all_paths = Vec::new();
 
     visited.insert("start".to_string());
     dfs_with_path(
-        &graph,
+        graph,
         "start",
         "end",
         &mut visited,
@@ -147,28 +148,34 @@ fn dfs_with_path_p2(
     count
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let graph = parse_input(input);
+fn solve_p2(graph: &HashMap<String, Vec<String>>) -> i32 {
     let mut visited = HashMap::new();
     let mut path = Vec::new();
 
-    dfs_with_path_p2(&graph, "start", "end", &mut visited, false, &mut path)
+    dfs_with_path_p2(graph, "start", "end", &mut visited, false, &mut path)
+}
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = HashMap<String, Vec<String>>;
+
+    fn parse(input: &str) -> HashMap<String, Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
 }
 
 fn main() {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
-    let input_path = std::path::Path::new(manifest_dir).join("input.txt");
-    let input = std::fs::read_to_string(input_path).unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2021, 12);
+    aoc_core::run::<Day12>(&input);
 }
 
 #[cfg(test)]
@@ -181,26 +188,29 @@ mod tests {
 
         let test_input_path = std::path::Path::new(manifest_dir).join("test_input.txt");
         let input = std::fs::read_to_string(test_input_path).unwrap();
-        let answer = solve_p1(&input);
+        let graph = parse_input(&input);
+        let answer = solve_p1(&graph);
         assert_eq!(answer, 10);
 
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&graph);
         assert_eq!(answer, 36);
 
         let test_input1_path = std::path::Path::new(manifest_dir).join("test_input1.txt");
         let input = std::fs::read_to_string(test_input1_path).unwrap();
-        let answer = solve_p1(&input);
+        let graph = parse_input(&input);
+        let answer = solve_p1(&graph);
         assert_eq!(answer, 19);
 
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&graph);
         assert_eq!(answer, 103);
 
         let test_input2_path = std::path::Path::new(manifest_dir).join("test_input2.txt");
         let input = std::fs::read_to_string(test_input2_path).unwrap();
-        let answer = solve_p1(&input);
+        let graph = parse_input(&input);
+        let answer = solve_p1(&graph);
         assert_eq!(answer, 226);
 
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&graph);
         assert_eq!(answer, 3509);
     }
 }
@@ -1,5 +1,7 @@
+use aoc_core::Solution;
 use core::str;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 struct FoldInstruction {
     axis: String,
@@ -86,12 +88,11 @@ fn print_paper(paper: &HashSet<Coord>) {
     }
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let (points, folds) = parse_input(input);
+fn solve_p1((points, folds): &(Vec<Coord>, Vec<FoldInstruction>)) -> i32 {
     let mut paper = HashSet::new();
 
     for point in points {
-        paper.insert(point);
+        paper.insert(*point);
     }
 
     let fold = folds.first().unwrap();
@@ -99,16 +100,15 @@ fn solve_p1(input: &str) -> i32 {
     paper.len() as i32
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let (points, folds) = parse_input(input);
+fn solve_p2((points, folds): &(Vec<Coord>, Vec<FoldInstruction>)) -> i32 {
     let mut paper = HashSet::new();
 
     for point in points {
-        paper.insert(point);
+        paper.insert(*point);
     }
 
     for fold in folds {
-        paper = fold_paper(&paper, &fold);
+        paper = fold_paper(&paper, fold);
     }
 
     print_paper(&paper);
@@ -116,18 +116,27 @@ fn solve_p2(input: &str) -> i32 {
     0
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = (Vec<Coord>, Vec<FoldInstruction>);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (Vec<Coord>, Vec<FoldInstruction>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 13);
+    aoc_core::run::<Day13>(&input);
 }
 
 #[cfg(test)]
@@ -137,9 +146,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 17);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 0);
     }
 }
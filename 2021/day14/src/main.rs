@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 type RuleMap = HashMap<String, char>;
 type PairCounts = HashMap<String, i64>;
@@ -70,30 +72,35 @@ fn simulate_polymer_growth(template: &str, rules: &RuleMap, steps: usize) -> i64
     max_count - min_count
 }
 
-fn solve_p1(input: &str) -> i64 {
-    let (template, rules) = parse_input(input);
-    simulate_polymer_growth(&template, &rules, 10)
+fn solve_p1((template, rules): &(String, RuleMap)) -> i64 {
+    simulate_polymer_growth(template, rules, 10)
 }
 
-fn solve_p2(input: &str) -> i64 {
-    let (template, rules) = parse_input(input);
-    simulate_polymer_growth(&template, &rules, 40)
+fn solve_p2((template, rules): &(String, RuleMap)) -> i64 {
+    simulate_polymer_growth(template, rules, 40)
+}
+
+struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = (String, RuleMap);
+
+    fn parse(input: &str) -> (String, RuleMap) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
 }
 
 fn main() {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
-    let input_path = std::path::Path::new(manifest_dir).join("input.txt");
-    let input = std::fs::read_to_string(input_path).unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2021, 14);
+    aoc_core::run::<Day14>(&input);
 }
 
 #[cfg(test)]
@@ -105,9 +112,10 @@ mod tests {
         let manifest_dir = env!("CARGO_MANIFEST_DIR");
         let test_input_path = std::path::Path::new(manifest_dir).join("test_input.txt");
         let input = std::fs::read_to_string(test_input_path).unwrap();
-        let answer = solve_p1(&input);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 1588);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 2188189693529);
     }
 }
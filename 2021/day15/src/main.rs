@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use pathfinding::{matrix::Matrix, prelude::dijkstra};
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Matrix<usize> {
     Matrix::from_rows(input.lines().map(|line| {
@@ -13,11 +15,10 @@ fn successors(pos: (usize, usize), grid: &Matrix<usize>) -> Vec<((usize, usize),
     grid.neighbours(pos, false).map(|p| (p, grid[p])).collect()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let grid = parse_input(input);
+fn solve_p1(grid: &Matrix<usize>) -> i32 {
     let start = (0, 0);
     let end = (grid.rows - 1, grid.columns - 1);
-    let (_path, cost) = dijkstra(&start, |p| successors(*p, &grid), |p| *p == end).unwrap();
+    let (_path, cost) = dijkstra(&start, |p| successors(*p, grid), |p| *p == end).unwrap();
     cost as i32
 }
 
@@ -42,27 +43,35 @@ fn expand_grid(grid: &Matrix<usize>) -> Matrix<usize> {
     Matrix::from_rows(expanded).unwrap()
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let grid = parse_input(input);
-    let grid = expand_grid(&grid);
+fn solve_p2(grid: &Matrix<usize>) -> i32 {
+    let grid = expand_grid(grid);
     let start = (0, 0);
     let end = (grid.rows - 1, grid.columns - 1);
     let (_path, cost) = dijkstra(&start, |p| successors(*p, &grid), |p| *p == end).unwrap();
     cost as i32
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Matrix<usize>;
+
+    fn parse(input: &str) -> Matrix<usize> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 15);
+    aoc_core::run::<Day15>(&input);
 }
 
 #[cfg(test)]
@@ -72,9 +81,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let grid = parse_input(&input);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 40);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 315);
     }
 }
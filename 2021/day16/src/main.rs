@@ -1,6 +1,10 @@
+use aoc_core::Solution;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum PacketType {
     Sum,
     Product,
@@ -30,28 +34,97 @@ impl TryFrom<u8> for PacketType {
     }
 }
 
-#[derive(Debug)]
+/// How an operator packet gives the size of its sub-packets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LengthType {
+    /// Length type 0: the total length of the sub-packets in bits.
+    Bits,
+    /// Length type 1: the number of sub-packets.
+    Count,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 struct Packet {
     version: u8,
     type_id: PacketType,
     value: Option<u64>,
+    /// How the sub-packets were encoded, `None` for literals.
+    length_type: Option<LengthType>,
     sub_packets: Vec<Packet>,
 }
 
+/// Why a transmission couldn't be decoded, and where.
+#[derive(Debug, Clone, PartialEq)]
+struct DecodeError {
+    /// Offset in bits from the start of the transmission.
+    offset: usize,
+    kind: DecodeErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum DecodeErrorKind {
+    InvalidHex(char),
+    UnexpectedEnd,
+    LiteralTooLarge,
+    /// The sub-packets of a length type 0 packet didn't end at the given offset.
+    LengthMismatch {
+        expected_end: usize,
+    },
+}
+
+impl DecodeError {
+    fn new(offset: usize, kind: DecodeErrorKind) -> Self {
+        Self { offset, kind }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bit {}: ", self.offset)?;
+        match self.kind {
+            DecodeErrorKind::InvalidHex(c) => write!(f, "invalid hex digit {c:?}"),
+            DecodeErrorKind::UnexpectedEnd => write!(f, "unexpected end of transmission"),
+            DecodeErrorKind::LiteralTooLarge => write!(f, "literal value doesn't fit in 64 bits"),
+            DecodeErrorKind::LengthMismatch { expected_end } => {
+                write!(f, "sub-packets should have ended at bit {expected_end}")
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
 impl FromStr for Packet {
-    type Err = String;
+    type Err = DecodeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytes = hex::decode(s).map_err(|e| e.to_string())?;
+        let s = s.trim();
+        let mut bytes = vec![0u8; s.len().div_ceil(2)];
+        for (i, c) in s.chars().enumerate() {
+            let nibble = c
+                .to_digit(16)
+                .ok_or(DecodeError::new(4 * i, DecodeErrorKind::InvalidHex(c)))?;
+            bytes[i / 2] |= (nibble as u8) << if i % 2 == 0 { 4 } else { 0 };
+        }
         let mut bit_offset = 0;
         Packet::decode(&bytes[..], &mut bit_offset)
     }
 }
+
+/// Prints the expression tree as an S-expression, e.g. `(+ 1 (* 2 3))`. The alternate form
+/// (`{:#}`) puts the operands of nested expressions on their own lines.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_expression(f, 0)
+    }
+}
+
 impl Packet {
-    fn decode(bytes: &[u8], bit_offset: &mut usize) -> Result<Self, String> {
+    fn decode(bytes: &[u8], bit_offset: &mut usize) -> Result<Self, DecodeError> {
         // Get version and type_id
-        let version = extract_field(bytes, bit_offset, 3) as u8;
-        let type_id = PacketType::try_from(extract_field(bytes, bit_offset, 3) as u8)?;
+        let version = extract_field(bytes, bit_offset, 3)? as u8;
+        let type_id = PacketType::try_from(extract_field(bytes, bit_offset, 3)? as u8)
+            .expect("every 3-bit type ID is valid");
 
         match type_id {
             PacketType::Literal => {
@@ -61,27 +134,37 @@ impl Packet {
                     version,
                     type_id,
                     value: Some(value),
+                    length_type: None,
                     sub_packets: Vec::new(),
                 })
             }
             _ => {
-                let length_type_id = extract_field(bytes, bit_offset, 1);
+                let length_type_id = extract_field(bytes, bit_offset, 1)?;
                 if length_type_id == 0 {
-                    let total_length = extract_field(bytes, bit_offset, 15) as usize;
+                    let total_length = extract_field(bytes, bit_offset, 15)? as usize;
                     let target_offset = *bit_offset + total_length;
                     let mut sub_packets = Vec::new();
                     while *bit_offset < target_offset {
                         let sub_packet = Packet::decode(bytes, bit_offset)?;
                         sub_packets.push(sub_packet);
                     }
+                    if *bit_offset != target_offset {
+                        return Err(DecodeError::new(
+                            *bit_offset,
+                            DecodeErrorKind::LengthMismatch {
+                                expected_end: target_offset,
+                            },
+                        ));
+                    }
                     Ok(Packet {
                         version,
                         type_id,
                         value: None,
+                        length_type: Some(LengthType::Bits),
                         sub_packets,
                     })
                 } else {
-                    let num_sub_packets = extract_field(bytes, bit_offset, 11) as usize;
+                    let num_sub_packets = extract_field(bytes, bit_offset, 11)? as usize;
                     let mut sub_packets = Vec::new();
                     for _ in 0..num_sub_packets {
                         let sub_packet = Packet::decode(bytes, bit_offset)?;
@@ -91,19 +174,132 @@ impl Packet {
                         version,
                         type_id,
                         value: None,
+                        length_type: Some(LengthType::Count),
                         sub_packets,
                     })
                 }
             }
         }
     }
+
+    /// Encode the packet as an upper-case hex transmission, padded with zero bits.
+    ///
+    /// Literals use as few groups as possible. Operators keep their length type, defaulting
+    /// to length type 0. Panics if a field doesn't fit: a version over 7, more than 2047
+    /// sub-packets, or sub-packets longer than 32767 bits.
+    fn encode(&self) -> String {
+        let mut writer = BitWriter::default();
+        self.write(&mut writer);
+        hex::encode_upper(writer.bytes)
+    }
+
+    fn write(&self, out: &mut BitWriter) {
+        assert!(self.version < 8, "version {} doesn't fit", self.version);
+        out.push(self.version as u64, 3);
+        out.push(self.type_id as u64, 3);
+        if self.type_id == PacketType::Literal {
+            let value = self.value.expect("literal packets have a value");
+            let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+            for i in (0..groups).rev() {
+                out.push((i > 0) as u64, 1);
+                out.push((value >> (4 * i)) & 0xF, 4);
+            }
+            return;
+        }
+
+        match self.length_type.unwrap_or(LengthType::Bits) {
+            LengthType::Bits => {
+                let mut body = BitWriter::default();
+                for sub_packet in &self.sub_packets {
+                    sub_packet.write(&mut body);
+                }
+                assert!(body.len < 1 << 15, "sub-packets are too long");
+                out.push(0, 1);
+                out.push(body.len as u64, 15);
+                out.append(&body);
+            }
+            LengthType::Count => {
+                assert!(self.sub_packets.len() < 1 << 11, "too many sub-packets");
+                out.push(1, 1);
+                out.push(self.sub_packets.len() as u64, 11);
+                for sub_packet in &self.sub_packets {
+                    sub_packet.write(out);
+                }
+            }
+        }
+    }
+
+    fn write_expression(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        let op = match self.type_id {
+            PacketType::Literal => return write!(f, "{}", self.value.unwrap_or_default()),
+            PacketType::Sum => "+",
+            PacketType::Product => "*",
+            PacketType::Minimum => "min",
+            PacketType::Maximum => "max",
+            PacketType::GreaterThan => ">",
+            PacketType::LessThan => "<",
+            PacketType::EqualTo => "=",
+        };
+        write!(f, "({op}")?;
+        let nested = f.alternate()
+            && self
+                .sub_packets
+                .iter()
+                .any(|p| p.type_id != PacketType::Literal);
+        for sub_packet in &self.sub_packets {
+            if nested {
+                write!(f, "\n{:width$}", "", width = indent + 2)?;
+            } else {
+                write!(f, " ")?;
+            }
+            sub_packet.write_expression(f, indent + 2)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Collects bits most significant first, as they appear in a transmission.
+#[derive(Debug, Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
 }
 
-fn parse_literal_value(bytes: &[u8], bit_offset: &mut usize) -> Result<u64, String> {
+impl BitWriter {
+    /// Append the low `width` bits of `value`.
+    fn push(&mut self, value: u64, width: usize) {
+        for i in (0..width).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 == 1 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+
+    fn append(&mut self, other: &BitWriter) {
+        let mut offset = 0;
+        while offset < other.len {
+            let bit = extract_field(&other.bytes, &mut offset, 1).unwrap();
+            self.push(bit, 1);
+        }
+    }
+}
+
+fn parse_literal_value(bytes: &[u8], bit_offset: &mut usize) -> Result<u64, DecodeError> {
     let mut full_value = 0u64;
     loop {
-        let prefix = extract_field(bytes, bit_offset, 1);
-        let val = extract_field(bytes, bit_offset, 4);
+        let group_offset = *bit_offset;
+        let prefix = extract_field(bytes, bit_offset, 1)?;
+        let val = extract_field(bytes, bit_offset, 4)?;
+        if full_value >> 60 != 0 {
+            return Err(DecodeError::new(
+                group_offset,
+                DecodeErrorKind::LiteralTooLarge,
+            ));
+        }
         full_value = (full_value << 4) | val;
         if prefix == 0 {
             break;
@@ -112,7 +308,13 @@ fn parse_literal_value(bytes: &[u8], bit_offset: &mut usize) -> Result<u64, Stri
     Ok(full_value)
 }
 
-fn extract_field(bytes: &[u8], bit_offset: &mut usize, length: usize) -> u64 {
+fn extract_field(bytes: &[u8], bit_offset: &mut usize, length: usize) -> Result<u64, DecodeError> {
+    if *bit_offset + length > bytes.len() * 8 {
+        return Err(DecodeError::new(
+            *bit_offset,
+            DecodeErrorKind::UnexpectedEnd,
+        ));
+    }
     let mut value = 0u64;
     for _ in 0..length {
         let byte_offset = *bit_offset / 8;
@@ -120,7 +322,7 @@ fn extract_field(bytes: &[u8], bit_offset: &mut usize, length: usize) -> u64 {
         value = (value << 1) | (((bytes[byte_offset] >> (7 - bit_in_byte)) & 1) as u64);
         *bit_offset += 1;
     }
-    value
+    Ok(value)
 }
 
 fn sum_versions(packet: &Packet, version_sum: &mut u64) {
@@ -177,18 +379,38 @@ fn solve_p2(input: &str) -> u64 {
     evaluate_expression(&packet)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day16;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day16 {
+    type Input<'a> = &'a str;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 16);
+    match std::env::args().nth(1).as_deref() {
+        Some("--print") => {
+            let packet: Packet = input.parse().unwrap_or_else(|e| panic!("{e}"));
+            println!("{packet:#}");
+        }
+        Some("--encode") => {
+            // Round trip the transmission through the decoder and the encoder
+            let packet: Packet = input.parse().unwrap_or_else(|e| panic!("{e}"));
+            println!("{}", packet.encode());
+        }
+        _ => aoc_core::run::<Day16>(&input),
+    }
 }
 
 #[cfg(test)]
@@ -226,10 +448,10 @@ mod tests {
         let bytes = hex::decode(hex_string).unwrap();
         let mut bit_offset = 0;
 
-        let version = extract_field(&bytes, &mut bit_offset, 3);
+        let version = extract_field(&bytes, &mut bit_offset, 3).unwrap();
         assert_eq!(version, 6);
 
-        let type_id = extract_field(&bytes, &mut bit_offset, 3);
+        let type_id = extract_field(&bytes, &mut bit_offset, 3).unwrap();
         assert_eq!(type_id, 4);
 
         let value = parse_literal_value(&bytes, &mut bit_offset).unwrap();
@@ -256,4 +478,113 @@ mod tests {
         assert_eq!(packet.sub_packets[1].value.unwrap(), 2);
         assert_eq!(packet.sub_packets[2].value.unwrap(), 3);
     }
+
+    #[test]
+    fn test_decode_errors() {
+        let err = |s: &str| s.parse::<Packet>().unwrap_err();
+        assert_eq!(
+            err("D2FE"),
+            DecodeError::new(16, DecodeErrorKind::UnexpectedEnd)
+        );
+        assert_eq!(
+            err("D2XE28"),
+            DecodeError::new(8, DecodeErrorKind::InvalidHex('X'))
+        );
+        // 38006F45291200 with the length of its sub-packets changed from 27 to 26.
+        assert_eq!(
+            err("38006B45291200"),
+            DecodeError::new(49, DecodeErrorKind::LengthMismatch { expected_end: 48 })
+        );
+        // A literal with 17 groups of 0xF.
+        let mut writer = BitWriter::default();
+        writer.push(0b000100, 6);
+        for _ in 0..17 {
+            writer.push(0b11111, 5);
+        }
+        writer.push(0b01111, 5);
+        assert_eq!(
+            hex::encode(&writer.bytes).parse::<Packet>().unwrap_err(),
+            DecodeError::new(86, DecodeErrorKind::LiteralTooLarge)
+        );
+        assert_eq!(
+            err("D2FE").to_string(),
+            "bit 16: unexpected end of transmission"
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!("D2FE28".parse::<Packet>().unwrap().encode(), "D2FE28");
+        assert_eq!(
+            "38006F45291200".parse::<Packet>().unwrap().encode(),
+            "38006F45291200"
+        );
+        assert_eq!(
+            "EE00D40C823060".parse::<Packet>().unwrap().encode(),
+            "EE00D40C823060"
+        );
+
+        let literal = Packet {
+            version: 0,
+            type_id: PacketType::Literal,
+            value: Some(u64::MAX),
+            length_type: None,
+            sub_packets: Vec::new(),
+        };
+        assert_eq!(literal.encode().parse::<Packet>().unwrap(), literal);
+    }
+
+    #[test]
+    fn test_display() {
+        let packet = "9C0141080250320F1802104A08".parse::<Packet>().unwrap();
+        assert_eq!(packet.to_string(), "(= (+ 1 3) (* 2 2))");
+        assert_eq!(format!("{packet:#}"), "(=\n  (+ 1 3)\n  (* 2 2))");
+        assert_eq!("D2FE28".parse::<Packet>().unwrap().to_string(), "2021");
+    }
+
+    mod props {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn packet(max_value: u64) -> impl Strategy<Value = Packet> {
+            let literal = (0u8..8, 0..=max_value).prop_map(|(version, value)| Packet {
+                version,
+                type_id: PacketType::Literal,
+                value: Some(value),
+                length_type: None,
+                sub_packets: Vec::new(),
+            });
+            literal.prop_recursive(3, 27, 3, |inner| {
+                let length_type = prop_oneof![Just(LengthType::Bits), Just(LengthType::Count)];
+                let operator = prop_oneof![
+                    (0u8..4, prop::collection::vec(inner.clone(), 1..=3)),
+                    (5u8..8, prop::collection::vec(inner, 2..=2)),
+                ];
+                (0u8..8, length_type, operator).prop_map(
+                    |(version, length_type, (type_id, sub_packets))| Packet {
+                        version,
+                        type_id: PacketType::try_from(type_id).unwrap(),
+                        value: None,
+                        length_type: Some(length_type),
+                        sub_packets,
+                    },
+                )
+            })
+        }
+
+        proptest! {
+            #[test]
+            fn decode_inverts_encode(packet in packet(u64::MAX)) {
+                let decoded: Packet = packet.encode().parse().unwrap();
+                prop_assert_eq!(decoded, packet);
+            }
+
+            // Small literals keep the products from overflowing.
+            #[test]
+            fn round_trip_keeps_value(packet in packet(3)) {
+                let decoded: Packet = packet.encode().parse().unwrap();
+                prop_assert_eq!(evaluate_expression(&decoded), evaluate_expression(&packet));
+            }
+        }
+    }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 struct TargetArea {
     xmin: i32,
     xmax: i32,
@@ -91,9 +94,8 @@ fn find_valid_trajectories(target: &TargetArea) -> Vec<(i32, i32, i32)> {
     results
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let target = parse_input(input);
-    let trajectories = find_valid_trajectories(&target);
+fn solve_p1(target: &TargetArea) -> i32 {
+    let trajectories = find_valid_trajectories(target);
     trajectories
         .iter()
         .map(|(_, _, max_y)| *max_y)
@@ -101,24 +103,32 @@ fn solve_p1(input: &str) -> i32 {
         .unwrap_or(0)
 }
 
-fn solve_p2(input: &str) -> usize {
-    let target = parse_input(input);
-    let trajectories = find_valid_trajectories(&target);
+fn solve_p2(target: &TargetArea) -> usize {
+    let trajectories = find_valid_trajectories(target);
     trajectories.len()
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = TargetArea;
+
+    fn parse(input: &str) -> TargetArea {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 17);
+    aoc_core::run::<Day17>(&input);
 }
 
 #[cfg(test)]
@@ -130,9 +140,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = TEST_INPUT;
-        let answer = solve_p1(&input);
+        let target = parse_input(input);
+        let answer = solve_p1(&target);
         assert_eq!(answer, 45);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&target);
         assert_eq!(answer, 112);
     }
 }
@@ -0,0 +1,83 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use day18::{FlatSnailfish, SnailfishNumber, largest_sum_of_two};
+
+fn read_numbers<T: std::str::FromStr>() -> Vec<T>
+where
+    T::Err: std::fmt::Debug,
+{
+    std::fs::read_to_string("test_input.txt")
+        .unwrap()
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect()
+}
+
+/// Part 2 as it was first written: add every ordered pair of trees in turn
+fn tree_largest_sum_of_two(numbers: &[SnailfishNumber]) -> Option<i32> {
+    let mut largest = None;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                largest = largest.max(Some((a + b).magnitude()));
+            }
+        }
+    }
+    largest
+}
+
+fn flat_serial_largest_sum_of_two(numbers: &[FlatSnailfish]) -> Option<i32> {
+    let mut largest = None;
+    for (i, &a) in numbers.iter().enumerate() {
+        for (j, &b) in numbers.iter().enumerate() {
+            if i != j {
+                largest = largest.max(Some((a + b).magnitude()));
+            }
+        }
+    }
+    largest
+}
+
+fn bench_sum(c: &mut Criterion) {
+    let trees: Vec<SnailfishNumber> = read_numbers();
+    let flats: Vec<FlatSnailfish> = read_numbers();
+
+    let mut group = c.benchmark_group("sum");
+    group.bench_function("tree", |b| {
+        b.iter(|| {
+            black_box(&trees)
+                .iter()
+                .sum::<SnailfishNumber>()
+                .magnitude()
+        })
+    });
+    group.bench_function("flat", |b| {
+        b.iter(|| {
+            black_box(&flats)
+                .iter()
+                .copied()
+                .sum::<FlatSnailfish>()
+                .magnitude()
+        })
+    });
+    group.finish();
+}
+
+fn bench_largest_sum_of_two(c: &mut Criterion) {
+    let trees: Vec<SnailfishNumber> = read_numbers();
+    let flats: Vec<FlatSnailfish> = read_numbers();
+
+    let mut group = c.benchmark_group("largest_sum_of_two");
+    group.bench_function("tree", |b| {
+        b.iter(|| tree_largest_sum_of_two(black_box(&trees)))
+    });
+    group.bench_function("flat_serial", |b| {
+        b.iter(|| flat_serial_largest_sum_of_two(black_box(&flats)))
+    });
+    group.bench_function("flat_rayon", |b| {
+        b.iter(|| largest_sum_of_two(black_box(&flats)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_sum, bench_largest_sum_of_two);
+criterion_main!(benches);
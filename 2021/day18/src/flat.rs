@@ -0,0 +1,279 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::{Magnitude, SnailfishNumber, SnailfishPart};
+
+/// Pairs nested this deep or deeper explode.
+const EXPLODE_DEPTH: u8 = 5;
+
+/// Most leaves a number can have: the sum of two reduced numbers, before it is reduced.
+const MAX_LEAVES: usize = 32;
+
+/// A regular number and how many pairs it is nested in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Leaf {
+    pub depth: u8,
+    pub value: i32,
+}
+
+/// A reduced snailfish number stored as its regular numbers from left to right, each with
+/// its depth. The pairs are implied by the depths, so the number fits in a fixed-size array
+/// and adding two numbers doesn't allocate.
+#[derive(Debug, Clone, Copy)]
+pub struct FlatSnailfish {
+    leaves: [Leaf; MAX_LEAVES],
+    len: usize,
+}
+
+impl FlatSnailfish {
+    pub fn leaves(&self) -> &[Leaf] {
+        &self.leaves[..self.len]
+    }
+
+    pub fn magnitude(&self) -> Magnitude {
+        fn magnitude(leaves: &[Leaf], i: &mut usize, depth: u8) -> Magnitude {
+            let leaf = leaves[*i];
+            if leaf.depth == depth {
+                *i += 1;
+                return leaf.value;
+            }
+            3 * magnitude(leaves, i, depth + 1) + 2 * magnitude(leaves, i, depth + 1)
+        }
+        magnitude(self.leaves(), &mut 0, 0)
+    }
+
+    fn empty() -> Self {
+        Self {
+            leaves: [Leaf::default(); MAX_LEAVES],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, leaf: Leaf) {
+        self.leaves[self.len] = leaf;
+        self.len += 1;
+    }
+
+    /// Explode and split until neither applies, always acting on the leftmost candidate.
+    fn reduce(&mut self) {
+        loop {
+            if let Some(i) = self.leaves().iter().position(|l| l.depth >= EXPLODE_DEPTH) {
+                self.explode(i);
+            } else if let Some(i) = self.leaves().iter().position(|l| l.value >= 10) {
+                self.split(i);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Explode the pair whose left number is at `i`. The deepest pairs always hold two
+    /// regular numbers.
+    fn explode(&mut self, i: usize) {
+        let (left, right) = (self.leaves[i], self.leaves[i + 1]);
+        if i > 0 {
+            self.leaves[i - 1].value += left.value;
+        }
+        if i + 2 < self.len {
+            self.leaves[i + 2].value += right.value;
+        }
+        self.leaves[i] = Leaf {
+            depth: left.depth - 1,
+            value: 0,
+        };
+        self.leaves.copy_within(i + 2..self.len, i + 1);
+        self.len -= 1;
+    }
+
+    fn split(&mut self, i: usize) {
+        let Leaf { depth, value } = self.leaves[i];
+        self.leaves.copy_within(i + 1..self.len, i + 2);
+        self.len += 1;
+        self.leaves[i] = Leaf {
+            depth: depth + 1,
+            value: value / 2,
+        };
+        self.leaves[i + 1] = Leaf {
+            depth: depth + 1,
+            value: (value + 1) / 2,
+        };
+    }
+}
+
+impl Add for FlatSnailfish {
+    type Output = FlatSnailfish;
+
+    fn add(self, rhs: FlatSnailfish) -> FlatSnailfish {
+        let mut result = FlatSnailfish::empty();
+        for leaf in self.leaves().iter().chain(rhs.leaves()) {
+            result.push(Leaf {
+                depth: leaf.depth + 1,
+                value: leaf.value,
+            });
+        }
+        result.reduce();
+        result
+    }
+}
+
+/// Adds up the numbers in order. Panics if there are none, since there is no zero.
+impl Sum for FlatSnailfish {
+    fn sum<I: Iterator<Item = FlatSnailfish>>(iter: I) -> FlatSnailfish {
+        iter.reduce(Add::add)
+            .expect("Can't sum an empty list of snailfish numbers")
+    }
+}
+
+impl PartialEq for FlatSnailfish {
+    fn eq(&self, other: &Self) -> bool {
+        self.leaves() == other.leaves()
+    }
+}
+
+impl Eq for FlatSnailfish {}
+
+/// Orders by magnitude, then by the leaves so that only equal numbers compare as equal.
+impl Ord for FlatSnailfish {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.magnitude()
+            .cmp(&other.magnitude())
+            .then_with(|| self.leaves().cmp(other.leaves()))
+    }
+}
+
+impl PartialOrd for FlatSnailfish {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<&SnailfishNumber> for FlatSnailfish {
+    fn from(number: &SnailfishNumber) -> Self {
+        fn flatten(part: &SnailfishPart, depth: u8, flat: &mut FlatSnailfish) {
+            match part {
+                SnailfishPart::Regular(value) => flat.push(Leaf {
+                    depth,
+                    value: *value,
+                }),
+                SnailfishPart::Snailfish(pair) => {
+                    flatten(&pair.left, depth + 1, flat);
+                    flatten(&pair.right, depth + 1, flat);
+                }
+            }
+        }
+        let mut flat = FlatSnailfish::empty();
+        flatten(&number.left, 1, &mut flat);
+        flatten(&number.right, 1, &mut flat);
+        flat
+    }
+}
+
+impl From<&FlatSnailfish> for SnailfishNumber {
+    fn from(flat: &FlatSnailfish) -> Self {
+        fn build(leaves: &[Leaf], i: &mut usize, depth: u8) -> SnailfishPart {
+            let leaf = leaves[*i];
+            if leaf.depth == depth {
+                *i += 1;
+                return SnailfishPart::Regular(leaf.value);
+            }
+            let left = build(leaves, i, depth + 1);
+            let right = build(leaves, i, depth + 1);
+            SnailfishPart::Snailfish(Box::new(SnailfishNumber {
+                left: Box::new(left),
+                right: Box::new(right),
+            }))
+        }
+        match build(flat.leaves(), &mut 0, 0) {
+            SnailfishPart::Snailfish(number) => *number,
+            SnailfishPart::Regular(_) => unreachable!("a snailfish number is always a pair"),
+        }
+    }
+}
+
+/// Parses a snailfish number nested at most four pairs deep.
+impl FromStr for FlatSnailfish {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number: SnailfishNumber = s.parse()?;
+        let mut depth = 0;
+        for c in s.chars() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                _ => {}
+            }
+            if depth >= EXPLODE_DEPTH {
+                return Err(format!("Snailfish number is nested too deeply: {s}"));
+            }
+        }
+        Ok(FlatSnailfish::from(&number))
+    }
+}
+
+impl fmt::Display for FlatSnailfish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", SnailfishNumber::from(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> FlatSnailfish {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let number = parse("[[[[1,2],[3,4]],[[5,6],[7,8]]],9]");
+        assert_eq!(number.leaves().len(), 9);
+        assert_eq!(number.leaves()[0], Leaf { depth: 4, value: 1 });
+        assert_eq!(number.leaves()[8], Leaf { depth: 1, value: 9 });
+        assert_eq!(number.to_string(), "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]");
+        assert!("[[[[[9,8],1],2],3],4]".parse::<FlatSnailfish>().is_err());
+    }
+
+    #[test]
+    fn test_add() {
+        let sum = parse("[[[[4,3],4],4],[7,[[8,4],9]]]") + parse("[1,1]");
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+        let sum: FlatSnailfish = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"]
+            .into_iter()
+            .map(parse)
+            .sum();
+        assert_eq!(sum.to_string(), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(parse("[[9,1],[1,9]]").magnitude(), 129);
+        assert_eq!(
+            parse("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(),
+            3488
+        );
+    }
+
+    #[test]
+    fn test_matches_tree() {
+        let input = std::fs::read_to_string("test_input.txt").unwrap();
+        let trees: Vec<SnailfishNumber> = input.lines().map(|l| l.parse().unwrap()).collect();
+        let flats: Vec<FlatSnailfish> = input.lines().map(parse).collect();
+        for (tree, flat) in trees.iter().zip(&flats) {
+            assert_eq!(FlatSnailfish::from(tree), *flat);
+        }
+        let tree_sum: SnailfishNumber = trees.iter().sum();
+        let flat_sum: FlatSnailfish = flats.iter().copied().sum();
+        assert_eq!(FlatSnailfish::from(&tree_sum), flat_sum);
+        assert_eq!(SnailfishNumber::from(&flat_sum), tree_sum);
+        assert_eq!(
+            trees.iter().max().map(FlatSnailfish::from),
+            flats.iter().max().copied()
+        );
+    }
+}
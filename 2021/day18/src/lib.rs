@@ -0,0 +1,27 @@
+//! Snailfish numbers, as a tree of boxed pairs ([`SnailfishNumber`]) and as a flat list of
+//! regular numbers with their depths ([`FlatSnailfish`]). Both add with `+`, can be summed, and
+//! are ordered by magnitude. The flat form is the fast one; the tree is kept for comparison.
+
+mod flat;
+mod tree;
+
+use rayon::prelude::*;
+
+pub use flat::{FlatSnailfish, Leaf};
+pub use tree::{Magnitude, SnailfishNumber, SnailfishPart};
+
+/// The largest magnitude of the sum of two different numbers from the list, trying the pairs
+/// in parallel. Addition isn't commutative, so both orders are tried.
+pub fn largest_sum_of_two(numbers: &[FlatSnailfish]) -> Option<Magnitude> {
+    numbers
+        .par_iter()
+        .enumerate()
+        .flat_map_iter(|(i, &left)| {
+            numbers
+                .iter()
+                .enumerate()
+                .filter(move |&(j, _)| i != j)
+                .map(move |(_, &right)| (left + right).magnitude())
+        })
+        .max()
+}
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 type Point3D = (i32, i32, i32);
 
@@ -132,8 +134,8 @@ fn parse_input(input: &str) -> Vec<Scanner> {
     scanners
 }
 
-fn solve_both_parts(input: &str) -> (usize, i32) {
-    let mut scanners = parse_input(input);
+fn solve_both_parts(scanners: &[Scanner]) -> (usize, i32) {
+    let mut scanners = scanners.to_vec();
 
     if scanners.is_empty() {
         return (0, 0);
@@ -199,26 +201,35 @@ fn solve_both_parts(input: &str) -> (usize, i32) {
     (unique_beacons.len(), max_distance)
 }
 
-fn solve_p1(input: &str) -> usize {
-    solve_both_parts(input).0
+fn solve_p1(scanners: &[Scanner]) -> usize {
+    solve_both_parts(scanners).0
 }
 
-fn solve_p2(input: &str) -> i32 {
-    solve_both_parts(input).1
+fn solve_p2(scanners: &[Scanner]) -> i32 {
+    solve_both_parts(scanners).1
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Vec<Scanner>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<Scanner> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 19);
+    aoc_core::run::<Day19>(&input);
 }
 
 #[cfg(test)]
@@ -228,9 +239,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let scanners = parse_input(&input);
+        let answer = solve_p1(&scanners);
         assert_eq!(answer, 79);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&scanners);
         assert_eq!(answer, 3621);
     }
 }
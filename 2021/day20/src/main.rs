@@ -1,6 +1,10 @@
-use pathfinding::matrix::Matrix;
+use aoc_core::Solution;
+use aoc_core::automaton::{Automaton, Cell, Dense, Neighborhood, Rule};
+use std::fmt::Display;
 
-fn parse_input(input: &str) -> (String, Matrix<char>) {
+/// The enhancement algorithm, as a rule that looks up each pixel's next state from the 3×3
+/// block around it, and the lit pixels of the image
+fn parse_input(input: &str) -> (Rule, Vec<Cell<2>>) {
     let mut lines = input.lines();
 
     // Read algorithm (may span multiple lines until we hit an empty line)
@@ -11,110 +15,60 @@ fn parse_input(input: &str) -> (String, Matrix<char>) {
         }
         algorithm.push_str(line);
     }
+    let rule = Rule::Lookup(algorithm.chars().map(|c| c == '#').collect());
 
     // Read image lines
-    let image_lines: Vec<Vec<char>> = lines
+    let lit = lines
         .filter(|line| !line.trim().is_empty())
-        .map(|line| line.chars().collect())
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(col, _)| [row as i32, col as i32])
+        })
         .collect();
-
-    let image = Matrix::from_rows(image_lines).unwrap();
-    (algorithm, image)
+    (rule, lit)
 }
 
-fn enhance_image(algorithm: &str, image: &Matrix<char>, default_char: char) -> Matrix<char> {
-    let mut enhanced = Matrix::new(image.rows + 2, image.columns + 2, '.');
-
-    for r in 0..enhanced.rows {
-        for c in 0..enhanced.columns {
-            let mut index = 0;
-            for dr in -1..=1 {
-                for dc in -1..=1 {
-                    index <<= 1;
-                    let nr = r as isize + dr - 1;
-                    let nc = c as isize + dc - 1;
-                    let pixel = if nr >= 0
-                        && nr < image.rows as isize
-                        && nc >= 0
-                        && nc < image.columns as isize
-                    {
-                        image[(nr as usize, nc as usize)]
-                    } else {
-                        default_char
-                    };
-
-                    if pixel == '#' {
-                        index |= 1;
-                    }
-                }
-            }
-            enhanced[(r, c)] = algorithm.chars().nth(index).unwrap();
-        }
-    }
-
-    enhanced
+/// Enhances the image `times` times and counts the lit pixels. If the algorithm lights a
+/// pixel surrounded by dark ones, the infinite background flips between lit and dark, so
+/// this only has an answer after an even number of enhancements.
+fn enhance((rule, lit): &(Rule, Vec<Cell<2>>), times: usize) -> usize {
+    let mut image = Dense::new(Neighborhood::block(1), rule.clone(), lit.iter().copied());
+    image.run(times);
+    image.population().expect("infinitely many lit pixels")
 }
 
-fn count_lit_pixels(image: &Matrix<char>) -> i32 {
-    image.items().filter(|(_, c)| **c == '#').count() as i32
+fn solve_p1(image: &(Rule, Vec<Cell<2>>)) -> usize {
+    enhance(image, 2)
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let (algorithm, mut image) = parse_input(input);
-
-    // The background starts as '.' (dark)
-    let mut background = '.';
-
-    // Enhance twice
-    for _ in 0..2 {
-        image = enhance_image(&algorithm, &image, background);
-
-        // After enhancement, the background might flip
-        // If algorithm[0] is '#', then all-dark pixels become lit
-        // If algorithm[511] is '.', then all-lit pixels become dark
-        background = if background == '.' {
-            algorithm.chars().next().unwrap()
-        } else {
-            algorithm.chars().nth(511).unwrap()
-        };
-    }
-
-    count_lit_pixels(&image)
+fn solve_p2(image: &(Rule, Vec<Cell<2>>)) -> usize {
+    enhance(image, 50)
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let (algorithm, mut image) = parse_input(input);
+struct Day20;
 
-    // The background starts as '.' (dark)
-    let mut background = '.';
+impl Solution for Day20 {
+    type Input<'a> = (Rule, Vec<Cell<2>>);
 
-    // Enhance 50 times for part 2
-    for _ in 0..50 {
-        image = enhance_image(&algorithm, &image, background);
+    fn parse(input: &str) -> (Rule, Vec<Cell<2>>) {
+        parse_input(input)
+    }
 
-        // After enhancement, the background might flip
-        background = if background == '.' {
-            algorithm.chars().next().unwrap()
-        } else {
-            algorithm.chars().nth(511).unwrap()
-        };
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
     }
 
-    count_lit_pixels(&image)
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
 }
 
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2021, 20);
+    aoc_core::run::<Day20>(&input);
 }
 
 #[cfg(test)]
@@ -124,9 +78,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let image = parse_input(&input);
+        let answer = solve_p1(&image);
         assert_eq!(answer, 35);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&image);
         assert_eq!(answer, 3351);
     }
 }
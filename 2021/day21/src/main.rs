@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<usize> {
     input
         .lines()
@@ -9,10 +12,9 @@ fn parse_input(input: &str) -> Vec<usize> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let starting_positions = parse_input(input);
+fn solve_p1(starting_positions: &[usize]) -> usize {
     let num_players = starting_positions.len();
-    let mut positions = starting_positions.clone();
+    let mut positions = starting_positions.to_vec();
     let mut scores = vec![0; num_players];
     let mut die_value = 1;
     let mut total_rolls = 0;
@@ -45,15 +47,13 @@ fn solve_p1(input: &str) -> usize {
     }
 }
 
-fn solve_p2(input: &str) -> usize {
+fn solve_p2(starting_positions: &[usize]) -> usize {
     use std::collections::HashMap;
 
     type GameState = (usize, usize, usize, usize, usize);
     type WinCounts = (usize, usize);
     type Cache = HashMap<GameState, WinCounts>;
 
-    let starting_positions = parse_input(input);
-
     // Frequencies of sums when rolling 3-sided die 3 times
     // Sum: (3,4,5,6,7,8,9) appears with frequency: (1,3,6,7,6,3,1)
     let roll_frequencies = vec![(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
@@ -113,18 +113,27 @@ fn solve_p2(input: &str) -> usize {
     wins1.max(wins2)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day21;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day21 {
+    type Input<'a> = Vec<usize>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 21);
+    aoc_core::run::<Day21>(&input);
 }
 
 #[cfg(test)]
@@ -137,9 +146,10 @@ Player 2 starting position: 8";
     #[test]
     fn test_solve_with_test_input() {
         let input = TEST_INPUT;
-        let answer = solve_p1(&input);
+        let starting_positions = parse_input(input);
+        let answer = solve_p1(&starting_positions);
         assert_eq!(answer, 739785);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&starting_positions);
         assert_eq!(answer, 444356092776315);
     }
 }
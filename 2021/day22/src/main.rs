@@ -1,14 +1,18 @@
+use aoc_core::Solution;
+use aoc_core::interval;
+use std::fmt::Display;
 use std::str::FromStr;
 
+type Region = interval::Box<i32, 3>;
+
+#[derive(Clone, Copy)]
 enum State {
     On,
     Off,
 }
 
 struct Cuboid {
-    x_range: (i32, i32),
-    y_range: (i32, i32),
-    z_range: (i32, i32),
+    region: Region,
     state: State,
 }
 
@@ -24,24 +28,16 @@ impl FromStr for Cuboid {
             _ => return Err(()),
         };
 
-        let ranges: Vec<&str> = parts[1].split(',').collect();
-        let x_range: Vec<i32> = ranges[0][2..]
-            .split("..")
-            .map(|n| n.parse().unwrap())
-            .collect();
-        let y_range: Vec<i32> = ranges[1][2..]
-            .split("..")
-            .map(|n| n.parse().unwrap())
-            .collect();
-        let z_range: Vec<i32> = ranges[2][2..]
-            .split("..")
-            .map(|n| n.parse().unwrap())
-            .collect();
+        let mut min = [0; 3];
+        let mut max = [0; 3];
+        for (axis, range) in parts[1].split(',').enumerate() {
+            let (lo, hi) = range[2..].split_once("..").ok_or(())?;
+            min[axis] = lo.parse().map_err(|_| ())?;
+            max[axis] = hi.parse().map_err(|_| ())?;
+        }
 
         Ok(Cuboid {
-            x_range: (x_range[0], x_range[1]),
-            y_range: (y_range[0], y_range[1]),
-            z_range: (z_range[0], z_range[1]),
+            region: Region::from_inclusive(min, max),
             state,
         })
     }
@@ -51,142 +47,61 @@ fn parse_input(input: &str) -> Vec<Cuboid> {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
 
-#[allow(clippy::needless_range_loop)]
-fn solve_p1(input: &str) -> usize {
-    let cuboids = parse_input(input);
-    let mut reactor: Vec<Vec<Vec<bool>>> = vec![vec![vec![false; 101]; 101]; 101];
+/// Runs the reboot steps, keeping the lit cubes as a list of disjoint regions, and counts
+/// the cubes that end up on.
+fn reboot<'a>(cuboids: impl IntoIterator<Item = &'a Cuboid>) -> u64 {
+    let mut lit: Vec<Region> = Vec::new();
     for cuboid in cuboids {
-        // Only clamp the start to 0, and end to 100
-        // If completely outside, the range will be invalid (start > end) and loop won't execute
-        let x_start = (cuboid.x_range.0 + 50).max(0);
-        let x_end = (cuboid.x_range.1 + 50).min(100);
-        let y_start = (cuboid.y_range.0 + 50).max(0);
-        let y_end = (cuboid.y_range.1 + 50).min(100);
-        let z_start = (cuboid.z_range.0 + 50).max(0);
-        let z_end = (cuboid.z_range.1 + 50).min(100);
-
-        // Skip if cuboid doesn't overlap with initialization area
-        if x_start > x_end || y_start > y_end || z_start > z_end {
-            continue;
-        }
-
-        let x_start = x_start as usize;
-        let x_end = x_end as usize;
-        let y_start = y_start as usize;
-        let y_end = y_end as usize;
-        let z_start = z_start as usize;
-        let z_end = z_end as usize;
-
-        for x in x_start..=x_end {
-            for y in y_start..=y_end {
-                for z in z_start..=z_end {
-                    match cuboid.state {
-                        State::On => reactor[x][y][z] = true,
-                        State::Off => reactor[x][y][z] = false,
-                    }
-                }
-            }
-        }
-    }
-    let mut count = 0;
-    for x in 0..=100 {
-        for y in 0..=100 {
-            for z in 0..=100 {
-                if reactor[x][y][z] {
-                    count += 1;
-                }
-            }
+        lit = lit
+            .iter()
+            .flat_map(|region| region.difference(&cuboid.region))
+            .collect();
+        if matches!(cuboid.state, State::On) {
+            lit.push(cuboid.region);
         }
     }
-    count
+    lit.iter().map(Region::volume).sum()
 }
 
-fn solve_p2(input: &str) -> i64 {
-    let cuboids = parse_input(input);
-
-    #[derive(Clone, Copy, Debug)]
-    struct Region {
-        x: (i32, i32),
-        y: (i32, i32),
-        z: (i32, i32),
-    }
-
-    impl Region {
-        fn volume(&self) -> i64 {
-            let x_len = (self.x.1 - self.x.0 + 1) as i64;
-            let y_len = (self.y.1 - self.y.0 + 1) as i64;
-            let z_len = (self.z.1 - self.z.0 + 1) as i64;
-            x_len * y_len * z_len
-        }
-
-        fn intersect(&self, other: &Region) -> Option<Region> {
-            let x_start = self.x.0.max(other.x.0);
-            let x_end = self.x.1.min(other.x.1);
-            let y_start = self.y.0.max(other.y.0);
-            let y_end = self.y.1.min(other.y.1);
-            let z_start = self.z.0.max(other.z.0);
-            let z_end = self.z.1.min(other.z.1);
-
-            if x_start <= x_end && y_start <= y_end && z_start <= z_end {
-                Some(Region {
-                    x: (x_start, x_end),
-                    y: (y_start, y_end),
-                    z: (z_start, z_end),
-                })
-            } else {
-                None
-            }
-        }
-    }
-
-    // Track regions with their sign (positive for on, negative for compensation)
-    let mut regions: Vec<(Region, i64)> = Vec::new();
+fn solve_p1(cuboids: &[Cuboid]) -> u64 {
+    let initialization = Region::from_inclusive([-50; 3], [50; 3]);
+    let clipped: Vec<Cuboid> = cuboids
+        .iter()
+        .filter_map(|cuboid| {
+            Some(Cuboid {
+                region: cuboid.region.intersection(&initialization)?,
+                state: cuboid.state,
+            })
+        })
+        .collect();
+    reboot(&clipped)
+}
 
-    for cuboid in cuboids {
-        let new_region = Region {
-            x: cuboid.x_range,
-            y: cuboid.y_range,
-            z: cuboid.z_range,
-        };
+fn solve_p2(cuboids: &[Cuboid]) -> u64 {
+    reboot(cuboids)
+}
 
-        let mut to_add = Vec::new();
+struct Day22;
 
-        // For each existing region, compute intersection with new region
-        for &(region, sign) in &regions {
-            if let Some(intersection) = region.intersect(&new_region) {
-                // Add the intersection with opposite sign to cancel out overlap
-                to_add.push((intersection, -sign));
-            }
-        }
+impl Solution for Day22 {
+    type Input<'a> = Vec<Cuboid>;
 
-        // Add the compensation regions
-        regions.extend(to_add);
+    fn parse(input: &str) -> Vec<Cuboid> {
+        parse_input(input)
+    }
 
-        // If turning on, add the new region with positive sign
-        if matches!(cuboid.state, State::On) {
-            regions.push((new_region, 1));
-        }
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
     }
 
-    // Sum all regions weighted by their sign
-    regions
-        .iter()
-        .map(|(region, sign)| region.volume() * sign)
-        .sum()
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
 }
 
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2021, 22);
+    aoc_core::run::<Day22>(&input);
 }
 
 #[cfg(test)]
@@ -196,13 +111,14 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let cuboids = parse_input(&input);
+        let answer = solve_p1(&cuboids);
         assert_eq!(answer, 39);
         let input = std::fs::read_to_string("test_input1.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 590784);
         let input = std::fs::read_to_string("test_input2.txt").unwrap();
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&parse_input(&input));
         assert_eq!(answer, 2758514936282235);
     }
 }
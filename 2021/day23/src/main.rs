@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use pathfinding::prelude::dijkstra;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 enum Amphipod {
@@ -226,6 +228,13 @@ fn solve_p1(input: &str) -> usize {
     result.map(|(_, cost)| cost).unwrap_or(0)
 }
 
+/// Insert the two extra amphipod rows that part 2 adds to the diagram.
+fn unfold(input: &str) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    lines.splice(3..3, ["  #D#C#B#A#", "  #D#B#A#C#"]);
+    lines.join("\n") + "\n"
+}
+
 fn solve_p2(input: &str) -> usize {
     let initial_state = parse_input::<4>(input);
 
@@ -238,19 +247,27 @@ fn solve_p2(input: &str) -> usize {
     result.map(|(_, cost)| cost).unwrap_or(0)
 }
 
+struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(&unfold(input))
+    }
+}
+
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let input_p2 = std::fs::read_to_string("input_p2.txt").unwrap();
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input_p2);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2021, 23);
+    aoc_core::run::<Day23>(&input);
 }
 
 #[cfg(test)]
@@ -266,4 +283,14 @@ mod tests {
         let answer = solve_p2(&input);
         assert_eq!(answer, 44169);
     }
+
+    #[test]
+    fn test_unfold() {
+        let input = std::fs::read_to_string("test_input.txt").unwrap();
+        let unfolded = std::fs::read_to_string("test_input1.txt").unwrap();
+        assert_eq!(
+            unfold(&input).lines().collect::<Vec<_>>(),
+            unfolded.lines().collect::<Vec<_>>()
+        );
+    }
 }
@@ -1,119 +1,644 @@
-fn parse_input(input: &str) -> Vec<(i64, i64, i64)> {
-    // The MONAD program consists of 14 blocks, each processing one digit
-    // Each block has the structure with 3 key parameters:
-    // - div_z: divides z (either 1 or 26)
-    // - add_x: added to x after mod
-    // - add_y: added to y (and then to z)
-
-    let lines: Vec<&str> = input.lines().collect();
-    let mut params = Vec::new();
-
-    for chunk in lines.chunks(18) {
-        if chunk.len() < 18 {
-            break;
+use aoc_core::Solution;
+use aoc_core::vm::{Flow, Instruction, Machine, Registers, Stop};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+
+/// Second operand of an ALU instruction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operand {
+    Var(usize),
+    Num(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Inp(usize),
+    Add(usize, Operand),
+    Mul(usize, Operand),
+    Div(usize, Operand),
+    Mod(usize, Operand),
+    Eql(usize, Operand),
+}
+
+/// The ALU's variables `w`, `x`, `y` and `z`, and the digits still to be read by `inp`.
+#[derive(Debug, Clone)]
+struct Alu {
+    vars: Registers<i64, 4>,
+    input: VecDeque<i64>,
+}
+
+impl Instruction for Op {
+    type Registers = Alu;
+    type Output = ();
+
+    /// Halts on the operations the puzzle says crash the ALU, or when the input runs out.
+    fn execute(&self, alu: &mut Alu, _out: &mut Vec<()>) -> Flow {
+        let value = |b: Operand, alu: &Alu| match b {
+            Operand::Var(v) => alu.vars[v],
+            Operand::Num(n) => n,
+        };
+        match *self {
+            Op::Inp(a) => match alu.input.pop_front() {
+                Some(digit) => alu.vars[a] = digit,
+                None => return Flow::Halt,
+            },
+            Op::Add(a, b) => alu.vars[a] += value(b, alu),
+            Op::Mul(a, b) => alu.vars[a] *= value(b, alu),
+            Op::Div(a, b) => match value(b, alu) {
+                0 => return Flow::Halt,
+                b => alu.vars[a] /= b,
+            },
+            Op::Mod(a, b) => match (alu.vars[a], value(b, alu)) {
+                (a, b) if a < 0 || b <= 0 => return Flow::Halt,
+                (_, b) => alu.vars[a] %= b,
+            },
+            Op::Eql(a, b) => alu.vars[a] = (alu.vars[a] == value(b, alu)) as i64,
         }
+        Flow::Next
+    }
+}
+
+fn parse_var(s: &str) -> usize {
+    match s {
+        "w" => 0,
+        "x" => 1,
+        "y" => 2,
+        "z" => 3,
+        _ => panic!("Unknown variable: {s}"),
+    }
+}
 
-        // Extract the 3 key parameters from each block
-        let div_z: i64 = chunk[4].split_whitespace().nth(2).unwrap().parse().unwrap();
-        let add_x: i64 = chunk[5].split_whitespace().nth(2).unwrap().parse().unwrap();
-        let add_y: i64 = chunk[15]
-            .split_whitespace()
-            .nth(2)
-            .unwrap()
-            .parse()
-            .unwrap();
+fn parse_program(input: &str) -> Vec<Op> {
+    input
+        .lines()
+        .map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let a = parse_var(parts[1]);
+            let b = || match parts[2].parse() {
+                Ok(n) => Operand::Num(n),
+                Err(_) => Operand::Var(parse_var(parts[2])),
+            };
+            match parts[0] {
+                "inp" => Op::Inp(a),
+                "add" => Op::Add(a, b()),
+                "mul" => Op::Mul(a, b()),
+                "div" => Op::Div(a, b()),
+                "mod" => Op::Mod(a, b()),
+                "eql" => Op::Eql(a, b()),
+                op => panic!("Unknown instruction: {op}"),
+            }
+        })
+        .collect()
+}
 
-        params.push((div_z, add_x, add_y));
+/// Run the program on the given input, returning the variables `[w, x, y, z]` if it ran to
+/// the end without crashing.
+fn run_alu(program: &[Op], input: &[i64]) -> Option<[i64; 4]> {
+    let alu = Alu {
+        vars: Registers::default(),
+        input: input.iter().copied().collect(),
+    };
+    let mut machine = Machine::new(program.to_vec(), alu);
+    match machine.run() {
+        Stop::Exited => Some(machine.regs().vars.0),
+        _ => None,
     }
+}
 
-    params
+/// Whether MONAD accepts a model number.
+fn is_valid(program: &[Op], model_number: i64) -> bool {
+    let digits: Vec<i64> = model_number
+        .to_string()
+        .bytes()
+        .map(|b| (b - b'0') as i64)
+        .collect();
+    !digits.contains(&0) && run_alu(program, &digits).is_some_and(|vars| vars[3] == 0)
 }
 
-fn find_model_number(params: &[(i64, i64, i64)], largest: bool) -> i64 {
-    // The MONAD program implements a stack-like operation
-    // When div_z = 1, we push (digit + add_y) onto stack
-    // When div_z = 26, we pop and check constraint: popped + add_x == current digit
+/// Range of values a variable can take.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interval {
+    lo: i64,
+    hi: i64,
+}
+
+impl Interval {
+    fn new(lo: i64, hi: i64) -> Self {
+        Self { lo, hi }
+    }
+
+    fn exact(n: i64) -> Self {
+        Self::new(n, n)
+    }
+
+    fn contains(&self, n: i64) -> bool {
+        (self.lo..=self.hi).contains(&n)
+    }
 
-    let mut stack = Vec::new();
-    let mut constraints = Vec::new();
+    fn bounds(values: impl IntoIterator<Item = i64>) -> Self {
+        values
+            .into_iter()
+            .fold(Self::new(i64::MAX, i64::MIN), |acc, v| {
+                Self::new(acc.lo.min(v), acc.hi.max(v))
+            })
+    }
 
-    for (i, &(div_z, add_x, add_y)) in params.iter().enumerate() {
-        if div_z == 1 {
-            // Push operation
-            stack.push((i, add_y));
-        } else {
-            // Pop operation - creates a constraint between two digits
-            let (j, prev_add_y) = stack.pop().unwrap();
-            // Constraint: digit[j] + prev_add_y + add_x == digit[i]
-            // Or: digit[i] = digit[j] + prev_add_y + add_x
-            constraints.push((j, i, prev_add_y + add_x));
+    /// Apply one instruction, or return `None` if it is sure to crash. `inp` reads a digit.
+    fn execute(vars: &mut [Interval; 4], op: &Op) -> Option<()> {
+        let value = |b: Operand, vars: &[Interval; 4]| match b {
+            Operand::Var(v) => vars[v],
+            Operand::Num(n) => Interval::exact(n),
+        };
+        match *op {
+            Op::Inp(a) => vars[a] = Interval::new(1, 9),
+            Op::Add(a, b) => {
+                let (x, y) = (vars[a], value(b, vars));
+                vars[a] = Interval::new(x.lo.saturating_add(y.lo), x.hi.saturating_add(y.hi));
+            }
+            Op::Mul(a, b) => {
+                let (x, y) = (vars[a], value(b, vars));
+                vars[a] = Interval::bounds(
+                    [x.lo, x.hi]
+                        .into_iter()
+                        .flat_map(|m| [y.lo, y.hi].map(|n| m.saturating_mul(n))),
+                );
+            }
+            Op::Div(a, b) => {
+                let (x, y) = (vars[a], value(b, vars));
+                // Division is monotonic in the divisor on either side of zero, so the
+                // extremes are at the ends of its range or at -1 and 1.
+                let divisors = [y.lo, y.hi, -1, 1]
+                    .into_iter()
+                    .filter(|&d| d != 0 && y.contains(d));
+                let quotients = Interval::bounds(
+                    divisors.flat_map(|d| [x.lo.saturating_div(d), x.hi.saturating_div(d)]),
+                );
+                if quotients.lo > quotients.hi {
+                    return None;
+                }
+                vars[a] = quotients;
+            }
+            Op::Mod(a, b) => {
+                let (x, y) = (vars[a], value(b, vars));
+                if x.hi < 0 || y.hi <= 0 {
+                    return None;
+                }
+                vars[a] = if x.lo >= 0 && x.hi < y.lo.max(1) {
+                    x
+                } else {
+                    Interval::new(0, x.hi.min(y.hi - 1))
+                };
+            }
+            Op::Eql(a, b) => {
+                let (x, y) = (vars[a], value(b, vars));
+                vars[a] = if x.lo == x.hi && x == y {
+                    Interval::exact(1)
+                } else if x.hi < y.lo || y.hi < x.lo {
+                    Interval::exact(0)
+                } else {
+                    Interval::new(0, 1)
+                };
+            }
         }
+        Some(())
     }
+}
 
-    // Initialize with the target values based on largest/smallest
-    let mut digits = if largest { vec![9; 14] } else { vec![1; 14] };
+/// Whether running `program` from `vars` can end with `z == 0` for some input digits.
+fn may_accept(program: &[Op], vars: [i64; 4]) -> bool {
+    let mut vars = vars.map(Interval::exact);
+    program
+        .iter()
+        .all(|op| Interval::execute(&mut vars, op).is_some())
+        && vars[3].contains(0)
+}
 
-    // Apply constraints
-    for (j, i, diff) in constraints {
-        if largest {
-            // For largest: maximize both digits
-            if diff >= 0 {
-                // digit[i] = digit[j] + diff
-                // To maximize, set digit[j] = 9 - diff (if positive diff)
-                digits[j] = (9 - diff).max(1);
-                digits[i] = digits[j] + diff;
-            } else {
-                // digit[j] = digit[i] - diff
-                digits[i] = (9 + diff).max(1);
-                digits[j] = digits[i] - diff;
+/// Variables read before they are written at the start of each instruction, with only `z`
+/// needed at the end.
+fn live_variables(program: &[Op]) -> Vec<[bool; 4]> {
+    let mut live = vec![[false; 4]; program.len() + 1];
+    live[program.len()][3] = true;
+    for (i, op) in program.iter().enumerate().rev() {
+        let mut vars = live[i + 1];
+        match *op {
+            Op::Inp(a) | Op::Mul(a, Operand::Num(0)) => vars[a] = false,
+            Op::Add(a, b) | Op::Mul(a, b) | Op::Div(a, b) | Op::Mod(a, b) | Op::Eql(a, b) => {
+                vars[a] = true;
+                if let Operand::Var(v) = b {
+                    vars[v] = true;
+                }
+            }
+        }
+        live[i] = vars;
+    }
+    live
+}
+
+/// Digits to try in each position, best first.
+fn digit_order(largest: bool) -> Vec<i64> {
+    if largest {
+        (1..=9).rev().collect()
+    } else {
+        (1..=9).collect()
+    }
+}
+
+/// Depth-first search for the largest or smallest model number, one digit at a time. This
+/// works for any program, but can take a while.
+///
+/// The program is split before each `inp`. A state is the block about to run and the
+/// variables it starts with, ignoring any it overwrites before reading. States are pruned by
+/// interval analysis of the rest of the program, and remembered once they are known to lead
+/// nowhere.
+struct DepthFirst<'a> {
+    program: &'a [Op],
+    /// Start of each block in the program, and a machine loaded with it.
+    blocks: Vec<(usize, Machine<Op>)>,
+    live: Vec<[bool; 4]>,
+    dead: HashSet<(usize, [i64; 4])>,
+}
+
+impl<'a> DepthFirst<'a> {
+    fn new(program: &'a [Op]) -> Self {
+        let starts: Vec<usize> = (0..program.len())
+            .filter(|&i| matches!(program[i], Op::Inp(_)))
+            .collect();
+        let ends = starts.iter().skip(1).copied().chain([program.len()]);
+        let blocks = starts
+            .iter()
+            .zip(ends)
+            .map(|(&start, end)| {
+                let alu = Alu {
+                    vars: Registers::default(),
+                    input: VecDeque::new(),
+                };
+                (start, Machine::new(program[start..end].to_vec(), alu))
+            })
+            .collect();
+        Self {
+            program,
+            blocks,
+            live: live_variables(program),
+            dead: HashSet::new(),
+        }
+    }
+
+    fn find(&mut self, largest: bool) -> Option<i64> {
+        // Anything before the first `inp` only sees zeroed variables.
+        let first = self.blocks.first().map_or(self.program.len(), |b| b.0);
+        let vars = run_alu(&self.program[..first], &[])?;
+        self.dead.clear();
+        self.search(0, vars, 0, &digit_order(largest))
+    }
+
+    fn search(&mut self, block: usize, vars: [i64; 4], number: i64, digits: &[i64]) -> Option<i64> {
+        let Some(&(start, _)) = self.blocks.get(block) else {
+            return (vars[3] == 0).then_some(number);
+        };
+        let live = self.live[start];
+        let key = (
+            block,
+            std::array::from_fn(|v| if live[v] { vars[v] } else { 0 }),
+        );
+        if self.dead.contains(&key) || !may_accept(&self.program[start..], vars) {
+            return None;
+        }
+        for &digit in digits {
+            let machine = &mut self.blocks[block].1;
+            machine.reset(Alu {
+                vars: Registers::new(vars),
+                input: VecDeque::from([digit]),
+            });
+            if machine.run() != Stop::Exited {
+                continue;
+            }
+            let next = machine.regs().vars.0;
+            if let Some(found) = self.search(block + 1, next, number * 10 + digit, digits) {
+                return Some(found);
+            }
+        }
+        self.dead.insert(key);
+        None
+    }
+}
+
+/// An affine combination of the input digits: `constant + Σ coefs[i] * digit[i]`.
+#[derive(Debug, Clone, PartialEq)]
+struct Affine {
+    constant: i64,
+    coefs: Vec<i64>,
+}
+
+impl Affine {
+    fn constant(n: i64, digits: usize) -> Self {
+        Self {
+            constant: n,
+            coefs: vec![0; digits],
+        }
+    }
+
+    fn digit(i: usize, digits: usize) -> Self {
+        let mut e = Self::constant(0, digits);
+        e.coefs[i] = 1;
+        e
+    }
+
+    fn as_constant(&self) -> Option<i64> {
+        self.coefs.iter().all(|&c| c == 0).then_some(self.constant)
+    }
+
+    /// Range of the expression with the first `known.len()` digits fixed and the rest free.
+    fn range(&self, known: &[i64]) -> Interval {
+        self.coefs
+            .iter()
+            .enumerate()
+            .fold(Interval::exact(self.constant), |acc, (i, &c)| {
+                let digit = known
+                    .get(i)
+                    .map_or(Interval::new(1, 9), |&d| Interval::exact(d));
+                let (a, b) = (c.saturating_mul(digit.lo), c.saturating_mul(digit.hi));
+                Interval::new(
+                    acc.lo.saturating_add(a.min(b)),
+                    acc.hi.saturating_add(a.max(b)),
+                )
+            })
+    }
+
+    fn zip(&self, other: &Self, f: impl Fn(i64, i64) -> Option<i64>) -> Option<Self> {
+        let coefs = self
+            .coefs
+            .iter()
+            .zip(&other.coefs)
+            .map(|(&a, &b)| f(a, b))
+            .collect::<Option<_>>()?;
+        Some(Self {
+            constant: f(self.constant, other.constant)?,
+            coefs,
+        })
+    }
+
+    fn map(&self, f: impl Fn(i64) -> Option<i64>) -> Option<Self> {
+        self.zip(self, |a, _| f(a))
+    }
+
+    /// Split a non-negative expression into `m * quotient + remainder`, if the remainder is
+    /// always in `0..m`.
+    fn div_rem(&self, m: i64) -> Option<(Self, Self)> {
+        let quotient = self.map(|c| Some(c.div_euclid(m)))?;
+        let remainder = self.map(|c| Some(c.rem_euclid(m)))?;
+        let range = remainder.range(&[]);
+        (self.range(&[]).lo >= 0 && range.lo >= 0 && range.hi < m).then_some((quotient, remainder))
+    }
+}
+
+/// One path through the program in symbolic execution: the variables as expressions of the
+/// digits, and the outcome of each `eql` that could have gone either way.
+#[derive(Debug, Clone)]
+struct World {
+    vars: [Affine; 4],
+    next_digit: usize,
+    /// Expressions that must be zero (`true`) or non-zero (`false`).
+    constraints: Vec<(Affine, bool)>,
+}
+
+impl World {
+    /// Apply one instruction. Returns the worlds that follow, or `None` if the result isn't
+    /// an affine expression that can be tracked.
+    fn execute(mut self, op: &Op) -> Option<Vec<World>> {
+        let digits = self.vars[0].coefs.len();
+        let value = |b: Operand, vars: &[Affine; 4]| match b {
+            Operand::Var(v) => vars[v].clone(),
+            Operand::Num(n) => Affine::constant(n, digits),
+        };
+        match *op {
+            Op::Inp(a) => {
+                self.vars[a] = Affine::digit(self.next_digit, digits);
+                self.next_digit += 1;
+            }
+            Op::Add(a, b) => {
+                let b = value(b, &self.vars);
+                self.vars[a] = self.vars[a].zip(&b, i64::checked_add)?;
+            }
+            Op::Mul(a, b) => {
+                let b = value(b, &self.vars);
+                self.vars[a] = match (self.vars[a].as_constant(), b.as_constant()) {
+                    (_, Some(k)) => self.vars[a].map(|c| c.checked_mul(k))?,
+                    (Some(k), _) => b.map(|c| c.checked_mul(k))?,
+                    _ => return None,
+                };
+            }
+            Op::Div(a, b) => match value(b, &self.vars).as_constant()? {
+                0 => return Some(vec![]),
+                1 => {}
+                m if m > 0 => self.vars[a] = self.vars[a].div_rem(m)?.0,
+                _ => return None,
+            },
+            Op::Mod(a, b) => match value(b, &self.vars).as_constant()? {
+                m if m <= 0 || self.vars[a].range(&[]).hi < 0 => return Some(vec![]),
+                m => self.vars[a] = self.vars[a].div_rem(m)?.1,
+            },
+            Op::Eql(a, b) => {
+                let diff = self.vars[a].zip(&value(b, &self.vars), i64::checked_sub)?;
+                if let Some(c) = diff.as_constant() {
+                    self.vars[a] = Affine::constant((c == 0) as i64, digits);
+                } else if !diff.range(&[]).contains(0) {
+                    self.vars[a] = Affine::constant(0, digits);
+                } else {
+                    let mut equal = self.clone();
+                    equal.vars[a] = Affine::constant(1, digits);
+                    equal.constraints.push((diff.clone(), true));
+                    self.vars[a] = Affine::constant(0, digits);
+                    self.constraints.push((diff, false));
+                    return Some(vec![equal, self]);
+                }
             }
-        } else {
-            // For smallest: minimize both digits
-            if diff >= 0 {
-                // digit[i] = digit[j] + diff
-                digits[j] = 1;
-                digits[i] = (digits[j] + diff).min(9);
+        }
+        Some(vec![self])
+    }
+
+    /// Whether the constraints can still hold with the first digits fixed.
+    fn feasible(&self, known: &[i64]) -> bool {
+        self.constraints.iter().all(|(e, zero)| {
+            let range = e.range(known);
+            if *zero {
+                range.contains(0)
             } else {
-                // digit[j] = digit[i] - diff
-                digits[i] = 1;
-                digits[j] = (digits[i] - diff).min(9);
+                range != Interval::exact(0)
+            }
+        })
+    }
+
+    /// The largest or smallest digits that satisfy the constraints.
+    fn best_digits(&self, digits: &mut Vec<i64>, order: &[i64]) -> bool {
+        if !self.feasible(digits) {
+            return false;
+        }
+        if digits.len() == self.next_digit {
+            return true;
+        }
+        for &d in order {
+            digits.push(d);
+            if self.best_digits(digits, order) {
+                return true;
             }
+            digits.pop();
         }
+        false
     }
+}
+
+/// Execute the program symbolically, tracking each variable as an affine expression of the
+/// digits. An `eql` whose outcome depends on the digits splits the execution in two, each
+/// with a constraint on the digits. MONAD stays affine as long as `mod` and `div` act on a
+/// value whose low part is a single small term, so this finds the constraints between the
+/// digits without knowing how the program is structured. Returns `None` for programs outside
+/// that fragment.
+fn symbolic_worlds(program: &[Op]) -> Option<Vec<World>> {
+    const MAX_WORLDS: usize = 1 << 12;
 
-    // Convert digits to number
-    digits.iter().fold(0, |acc, &d| acc * 10 + d)
+    let digits = program.iter().filter(|op| matches!(op, Op::Inp(_))).count();
+    let mut worlds = vec![World {
+        vars: std::array::from_fn(|_| Affine::constant(0, digits)),
+        next_digit: 0,
+        constraints: Vec::new(),
+    }];
+    for op in program {
+        let mut next = Vec::new();
+        for world in worlds {
+            next.extend(world.execute(op)?.into_iter().filter(|w| w.feasible(&[])));
+        }
+        if next.len() > MAX_WORLDS {
+            return None;
+        }
+        worlds = next;
+    }
+    for world in &mut worlds {
+        world.constraints.push((world.vars[3].clone(), true));
+    }
+    Some(worlds)
+}
+
+/// The largest or smallest model number MONAD accepts, if any.
+fn find_model_number(program: &[Op], largest: bool) -> Option<i64> {
+    let Some(worlds) = symbolic_worlds(program) else {
+        return DepthFirst::new(program).find(largest);
+    };
+    let order = digit_order(largest);
+    let candidates = worlds.iter().filter_map(|world| {
+        let mut digits = Vec::new();
+        world
+            .best_digits(&mut digits, &order)
+            .then(|| digits.iter().fold(0, |acc, &d| acc * 10 + d))
+    });
+    if largest {
+        candidates.max()
+    } else {
+        candidates.min()
+    }
+}
+
+fn solve(input: &str, largest: bool) -> i64 {
+    let program = parse_program(input);
+    let model_number = find_model_number(&program, largest).expect("MONAD accepts no model number");
+    assert!(is_valid(&program, model_number));
+    model_number
 }
 
 fn solve_p1(input: &str) -> i64 {
-    let params = parse_input(input);
-    find_model_number(&params, true)
+    solve(input, true)
 }
 
 fn solve_p2(input: &str) -> i64 {
-    let params = parse_input(input);
-    find_model_number(&params, false)
+    solve(input, false)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = &'a str;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> &str {
+        input
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 24);
+    aoc_core::run::<Day24>(&input);
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alu_examples() {
+        let negate = parse_program("inp x\nmul x -1");
+        assert_eq!(run_alu(&negate, &[7]), Some([0, -7, 0, 0]));
+
+        let triple = parse_program("inp z\ninp x\nmul z 3\neql z x");
+        assert_eq!(run_alu(&triple, &[2, 6]).unwrap()[3], 1);
+        assert_eq!(run_alu(&triple, &[2, 5]).unwrap()[3], 0);
+        assert_eq!(run_alu(&triple, &[2]), None);
+
+        let bits = parse_program(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\n\
+             div w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2",
+        );
+        assert_eq!(run_alu(&bits, &[13]), Some([1, 1, 0, 1]));
+    }
+
     #[test]
-    fn test_solve_with_test_input() {
-        // Day 24 doesn't have a test input - the solution is specific to the input
-        // This test is a placeholder
+    fn test_alu_crashes() {
+        assert_eq!(run_alu(&parse_program("div x 0"), &[]), None);
+        assert_eq!(run_alu(&parse_program("inp x\nmod x 5"), &[-1]), None);
+        assert_eq!(run_alu(&parse_program("inp x\nmod x y"), &[1]), None);
+    }
+
+    /// One MONAD-style block, as found in the puzzle inputs.
+    fn monad_block(div_z: i64, add_x: i64, add_y: i64) -> String {
+        format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {div_z}\nadd x {add_x}\neql x w\n\
+             eql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\n\
+             add y {add_y}\nmul y x\nadd z y\n"
+        )
+    }
+
+    fn brute_force(program: &[Op]) -> Vec<i64> {
+        (1111..=9999).filter(|&n| is_valid(program, n)).collect()
+    }
+
+    #[test]
+    fn test_find_model_number() {
+        let input = [(1, 12, 7), (1, 11, 3), (26, -5, 2), (26, -9, 8)]
+            .map(|(d, x, y)| monad_block(d, x, y))
+            .concat();
+        let program = parse_program(&input);
+        assert!(symbolic_worlds(&program).is_some());
+        let valid = brute_force(&program);
+        assert_eq!(find_model_number(&program, true), valid.last().copied());
+        assert_eq!(find_model_number(&program, false), valid.first().copied());
+        assert_eq!(DepthFirst::new(&program).find(true), Some(9977));
+        assert_eq!(DepthFirst::new(&program).find(false), Some(3311));
+    }
+
+    #[test]
+    fn test_find_model_number_without_affine_form() {
+        // Accepts two digits whose product is 12.
+        let program = parse_program("inp w\ninp x\nmul w x\nadd z w\nadd z -12");
+        assert!(symbolic_worlds(&program).is_none());
+        assert_eq!(find_model_number(&program, true), Some(62));
+        assert_eq!(find_model_number(&program, false), Some(26));
+
+        let program = parse_program("inp w\nmul w w\nadd z w\nadd z -2");
+        assert_eq!(find_model_number(&program, true), None);
     }
 }
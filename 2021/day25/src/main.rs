@@ -1,82 +1,73 @@
-use pathfinding::matrix::Matrix;
+use aoc_core::Solution;
+use aoc_core::automaton::{BitPlane, Cell};
+use std::fmt::Display;
 
-fn parse_input(input: &str) -> Matrix<char> {
-    Matrix::from_rows(
-        input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>(),
-    )
-    .unwrap()
-}
-
-fn solve_p1(input: &str) -> usize {
-    let mut grid = parse_input(input);
-    let rows = grid.rows;
-    let cols = grid.columns;
-    let mut step = 0;
-
-    loop {
-        step += 1;
-        let mut moved = false;
-
-        // Move east-facing sea cucumbers (>)
-        let mut new_grid = grid.clone();
-        for r in 0..rows {
-            for c in 0..cols {
-                if grid[(r, c)] == '>' {
-                    let next_c = (c + 1) % cols;
-                    if grid[(r, next_c)] == '.' {
-                        new_grid[(r, c)] = '.';
-                        new_grid[(r, next_c)] = '>';
-                        moved = true;
-                    }
-                }
+/// The east-facing and south-facing herds, on a seafloor that wraps around
+fn parse_input(input: &str) -> (BitPlane, BitPlane) {
+    let rows = input.lines().count();
+    let cols = input.lines().next().map_or(0, str::len);
+    let mut east = BitPlane::torus(rows, cols);
+    let mut south = BitPlane::torus(rows, cols);
+    for (row, line) in input.lines().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            let cell = [row as i32, col as i32];
+            match c {
+                '>' => east.set(cell, true),
+                'v' => south.set(cell, true),
+                _ => {}
             }
         }
-        grid = new_grid;
+    }
+    (east, south)
+}
 
-        // Move south-facing sea cucumbers (v)
-        let mut new_grid = grid.clone();
-        for r in 0..rows {
-            for c in 0..cols {
-                if grid[(r, c)] == 'v' {
-                    let next_r = (r + 1) % rows;
-                    if grid[(next_r, c)] == '.' {
-                        new_grid[(r, c)] = '.';
-                        new_grid[(next_r, c)] = 'v';
-                        moved = true;
-                    }
-                }
-            }
-        }
-        grid = new_grid;
+/// Moves every sea cucumber in `herd` that has a free cell one `step` ahead, returning
+/// whether any did
+fn move_herd(herd: &mut BitPlane, other: &BitPlane, [row, col]: Cell<2>) -> bool {
+    let free = !(&*herd | other);
+    // The sea cucumbers with a free cell ahead of them
+    let moving = &*herd & &free.shifted([-row, -col]);
+    *herd ^= &moving;
+    *herd |= moving.shifted([row, col]);
+    !moving.is_empty()
+}
 
-        if !moved {
-            break;
-        }
+fn solve_p1((east, south): &(BitPlane, BitPlane)) -> usize {
+    let (mut east, mut south) = (east.clone(), south.clone());
+    let mut step = 1;
+    // Not `||`: the south-facing herd moves even when the east-facing one is stuck
+    while move_herd(&mut east, &south, [0, 1]) | move_herd(&mut south, &east, [1, 0]) {
+        step += 1;
     }
-
     step
 }
 
-fn solve_p2(_input: &str) -> &str {
+fn solve_p2(_herds: &(BitPlane, BitPlane)) -> &'static str {
     // Day 25 Part 2 is always a freebie - no implementation needed
     "Merry Christmas!"
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day25;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day25 {
+    type Input<'a> = (BitPlane, BitPlane);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (BitPlane, BitPlane) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2021, 25);
+    aoc_core::run::<Day25>(&input);
 }
 
 #[cfg(test)]
@@ -86,7 +77,7 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 58);
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<Vec<usize>> {
     input
         .split("\n\n")
@@ -11,24 +14,37 @@ fn parse_input(input: &str) -> Vec<Vec<usize>> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let list = parse_input(input);
+fn solve_p1(list: &[Vec<usize>]) -> usize {
     list.iter().map(|inner| inner.iter().sum()).max().unwrap()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let list = parse_input(input);
+fn solve_p2(list: &[Vec<usize>]) -> usize {
     let mut totals: Vec<usize> = list.iter().map(|inner| inner.iter().sum()).collect();
     totals.sort();
     totals.iter().rev().take(3).sum()
 }
 
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> Vec<Vec<usize>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 1);
+    aoc_core::run::<Day01>(&input);
 }
 
 #[cfg(test)]
@@ -62,18 +78,20 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let list = parse_input(INPUT);
+        let answer = solve_p1(&list);
         assert_eq!(answer, 24000);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&list);
         assert_eq!(answer, 45000);
     }
 
     #[test]
     fn test_solve() {
         let input = include_str!("../input.txt");
-        let answer = solve_p1(input);
+        let list = parse_input(input);
+        let answer = solve_p1(&list);
         assert_eq!(answer, 70720);
-        let answer = solve_p2(input);
+        let answer = solve_p2(&list);
         assert_eq!(answer, 207148);
     }
 }
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn solve_p1(input: &str) -> usize {
     let strategy = HashMap::from([
@@ -40,12 +42,27 @@ fn solve_p2(input: &str) -> usize {
         .sum()
 }
 
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 2);
+    aoc_core::run::<Day02>(&input);
 }
 
 #[cfg(test)]
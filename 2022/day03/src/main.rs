@@ -1,5 +1,7 @@
+use aoc_core::Solution;
 use itertools::Itertools;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 fn solve_p1(input: &str) -> i32 {
     let mut misplaced = Vec::new();
@@ -45,12 +47,27 @@ fn priority(c: char) -> i32 {
     }
 }
 
+struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 3);
+    aoc_core::run::<Day03>(&input);
 }
 
 #[cfg(test)]
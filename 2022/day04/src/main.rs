@@ -1,17 +1,16 @@
+use aoc_core::Solution;
+use std::fmt::Display;
 use std::ops::RangeInclusive;
 
-fn solve_p1(input: &str) -> i32 {
-    parse_input(input)
+fn solve_p1(pairs: &[(RangeInclusive<i32>, RangeInclusive<i32>)]) -> i32 {
+    pairs
         .iter()
         .filter(|(a, b)| a.start() == b.start() || b.end() <= a.end())
         .count() as i32
 }
 
-fn solve_p2(input: &str) -> i32 {
-    parse_input(input)
-        .iter()
-        .filter(|(a, b)| b.start() <= a.end())
-        .count() as i32
+fn solve_p2(pairs: &[(RangeInclusive<i32>, RangeInclusive<i32>)]) -> i32 {
+    pairs.iter().filter(|(a, b)| b.start() <= a.end()).count() as i32
 }
 
 fn parse_input(input: &str) -> Vec<(RangeInclusive<i32>, RangeInclusive<i32>)> {
@@ -34,12 +33,27 @@ fn parse_input(input: &str) -> Vec<(RangeInclusive<i32>, RangeInclusive<i32>)> {
     ranges
 }
 
+struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<(RangeInclusive<i32>, RangeInclusive<i32>)>;
+
+    fn parse(input: &str) -> Vec<(RangeInclusive<i32>, RangeInclusive<i32>)> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 4);
+    aoc_core::run::<Day04>(&input);
 }
 
 #[cfg(test)]
@@ -55,9 +69,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let pairs = parse_input(INPUT);
+        let answer = solve_p1(&pairs);
         assert_eq!(answer, 2);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&pairs);
         assert_eq!(answer, 4);
     }
 }
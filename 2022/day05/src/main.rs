@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::VecDeque;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Move {
@@ -7,9 +9,8 @@ struct Move {
     to: i32,
 }
 
-fn solve_p1(input: &str) -> String {
-    let (mut stacks, moves) = parse_input(input);
-
+fn solve_p1((stacks, moves): &(Vec<VecDeque<char>>, Vec<Move>)) -> String {
+    let mut stacks = stacks.clone();
     for m in moves {
         let from = m.from as usize - 1;
         let to = m.to as usize - 1;
@@ -22,9 +23,8 @@ fn solve_p1(input: &str) -> String {
     stacks.iter().map(|s| s.back().unwrap()).collect::<String>()
 }
 
-fn solve_p2(input: &str) -> String {
-    let (mut stacks, moves) = parse_input(input);
-
+fn solve_p2((stacks, moves): &(Vec<VecDeque<char>>, Vec<Move>)) -> String {
+    let mut stacks = stacks.clone();
     for m in moves {
         let from = m.from as usize - 1;
         let to = m.to as usize - 1;
@@ -72,12 +72,27 @@ fn parse_input(input: &str) -> (Vec<VecDeque<char>>, Vec<Move>) {
     (stacks, moves)
 }
 
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = (Vec<VecDeque<char>>, Vec<Move>);
+
+    fn parse(input: &str) -> (Vec<VecDeque<char>>, Vec<Move>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 5);
+    aoc_core::run::<Day05>(&input);
 }
 
 #[cfg(test)]
@@ -96,9 +111,10 @@ move 1 from 1 to 2";
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let data = parse_input(INPUT);
+        let answer = solve_p1(&data);
         assert_eq!(answer, "CMZ");
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&data);
         assert_eq!(answer, "MCD");
     }
 }
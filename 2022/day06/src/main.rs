@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 fn solve_p1(input: &str) -> usize {
     find_substring(input, 4)
@@ -19,12 +21,27 @@ fn find_substring(input: &str, length: usize) -> usize {
     0
 }
 
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 6);
+    aoc_core::run::<Day06>(&input);
 }
 
 #[cfg(test)]
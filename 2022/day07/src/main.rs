@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> HashMap<String, usize> {
     let mut fs = HashMap::new();
@@ -37,16 +39,14 @@ fn parse_input(input: &str) -> HashMap<String, usize> {
     fs
 }
 
-fn solve_p1(input: &str) -> usize {
-    let fs = parse_input(input);
+fn solve_p1(fs: &HashMap<String, usize>) -> usize {
     fs.iter()
         .filter(|(_, &v)| v <= 100_000)
         .map(|(_, &v)| v)
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let fs = parse_input(input);
+fn solve_p2(fs: &HashMap<String, usize>) -> usize {
     let target_free_space = 30_000_000;
     let curr_free_space = 70_000_000 - fs.values().max().unwrap();
     let min_size_to_delete = target_free_space - curr_free_space;
@@ -58,12 +58,27 @@ fn solve_p2(input: &str) -> usize {
         .unwrap()
 }
 
+struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = HashMap<String, usize>;
+
+    fn parse(input: &str) -> HashMap<String, usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 7);
+    aoc_core::run::<Day07>(&input);
 }
 
 #[cfg(test)]
@@ -97,9 +112,10 @@ $ ls
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let fs = parse_input(INPUT);
+        let answer = solve_p1(&fs);
         assert_eq!(answer, 95437);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&fs);
         assert_eq!(answer, 24_933_642);
     }
 }
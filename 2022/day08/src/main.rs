@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use pathfinding::prelude::Matrix;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Matrix<u32> {
     Matrix::from_rows(input.lines().map(|line| {
@@ -10,8 +12,7 @@ fn parse_input(input: &str) -> Matrix<u32> {
     .unwrap()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p1(grid: &Matrix<u32>) -> usize {
     let nrows = grid.rows;
     let ncols = grid.columns;
 
@@ -71,8 +72,7 @@ fn solve_p1(input: &str) -> usize {
     count
 }
 
-fn solve_p2(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p2(grid: &Matrix<u32>) -> usize {
     let nrows = grid.rows;
     let ncols = grid.columns;
 
@@ -80,7 +80,7 @@ fn solve_p2(input: &str) -> usize {
 
     for row in 0..nrows {
         for col in 0..ncols {
-            let score = calc_score(&grid, (row, col));
+            let score = calc_score(grid, (row, col));
             if score > max_score {
                 max_score = score;
             }
@@ -138,12 +138,27 @@ fn calc_score(grid: &Matrix<u32>, (row, col): (usize, usize)) -> usize {
     scenic_score
 }
 
+struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Matrix<u32>;
+
+    fn parse(input: &str) -> Matrix<u32> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 8);
+    aoc_core::run::<Day08>(&input);
 }
 
 #[cfg(test)]
@@ -158,9 +173,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let grid = parse_input(INPUT);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 21);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 8);
     }
 }
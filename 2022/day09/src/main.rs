@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 fn solve_p1(input: &str) -> i32 {
     let mut rope = vec![(0, 0); 2];
@@ -93,12 +95,27 @@ fn move_rope_one_step(rope: &mut [(i32, i32)], dist: (i32, i32)) -> HashSet<(i32
     visited
 }
 
+struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 9);
+    aoc_core::run::<Day09>(&input);
 }
 
 #[cfg(test)]
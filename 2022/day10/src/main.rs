@@ -1,3 +1,52 @@
+use aoc_core::vm::{Flow, Instruction, Machine, Registers};
+use aoc_core::Solution;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Addx(i32),
+    Noop,
+}
+
+impl Instruction for Op {
+    type Registers = Registers<i32, 1>;
+    type Output = ();
+
+    fn execute(&self, regs: &mut Self::Registers, _out: &mut Vec<()>) -> Flow {
+        if let Op::Addx(arg) = self {
+            regs[0] += arg;
+        }
+        Flow::Next
+    }
+
+    fn cycles(&self) -> u64 {
+        match self {
+            Op::Addx(_) => 2,
+            Op::Noop => 1,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Op> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            match parts.next().unwrap() {
+                "addx" => Op::Addx(parts.next().unwrap().parse().unwrap()),
+                "noop" => Op::Noop,
+                op => panic!("Unknown op: {op}"),
+            }
+        })
+        .collect()
+}
+
+/// Run the program, calling `f` with the cycle number and the value of X during each cycle.
+fn run(program: &[Op], mut f: impl FnMut(i32, i32)) {
+    let mut cpu = Machine::new(program.to_vec(), Registers::new([1]));
+    cpu.run_with(|cycle, regs| f(cycle as i32, regs[0]));
+}
+
 fn sig_strength_adder(x: i32, cycle: i32) -> i32 {
     if cycle >= 20 && (cycle - 20) % 40 == 0 {
         return x * cycle;
@@ -5,31 +54,11 @@ fn sig_strength_adder(x: i32, cycle: i32) -> i32 {
     0
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let mut x = 1;
-    let mut cycle = 0;
+fn solve_p1(program: &[Op]) -> i32 {
     let mut signal_strength = 0;
-
-    for line in input.lines() {
-        let mut parts = line.split_whitespace();
-        let op = parts.next().unwrap();
-
-        match op {
-            "addx" => {
-                let arg = parts.next().unwrap().parse::<i32>().unwrap();
-                cycle += 1;
-                signal_strength += sig_strength_adder(x, cycle);
-                cycle += 1;
-                signal_strength += sig_strength_adder(x, cycle);
-                x += arg;
-            }
-            "noop" => {
-                cycle += 1;
-                signal_strength += sig_strength_adder(x, cycle);
-            }
-            _ => panic!("Unknown op: {op}"),
-        }
-    }
+    run(program, |cycle, x| {
+        signal_strength += sig_strength_adder(x, cycle)
+    });
     signal_strength
 }
 
@@ -43,32 +72,9 @@ fn pixel_value(x: i32, cycle: i32) -> char {
     '.'
 }
 
-fn solve_p2(input: &str) -> String {
-    let mut x = 1;
-    let mut cycle = 0;
-    //let mut screen = vec![vec!['.'; 40]; 6];
+fn solve_p2(program: &[Op]) -> String {
     let mut screen = vec![];
-
-    for line in input.lines() {
-        let mut parts = line.split_whitespace();
-        let op = parts.next().unwrap();
-
-        match op {
-            "addx" => {
-                let arg = parts.next().unwrap().parse::<i32>().unwrap();
-                cycle += 1;
-                screen.push(pixel_value(x, cycle));
-                cycle += 1;
-                screen.push(pixel_value(x, cycle));
-                x += arg;
-            }
-            "noop" => {
-                cycle += 1;
-                screen.push(pixel_value(x, cycle));
-            }
-            _ => panic!("Unknown op: {op}"),
-        }
-    }
+    run(program, |cycle, x| screen.push(pixel_value(x, cycle)));
     let result = screen
         .chunks(40)
         .map(|row| row.iter().collect::<String>())
@@ -78,12 +84,27 @@ fn solve_p2(input: &str) -> String {
     result
 }
 
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Op>;
+
+    fn parse(input: &str) -> Vec<Op> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2:\n{answer}");
+    let input = aoc_core::puzzle_input(2022, 10);
+    aoc_core::run::<Day10>(&input);
 }
 
 #[cfg(test)]
@@ -239,9 +260,10 @@ noop";
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let program = parse_input(INPUT);
+        let answer = solve_p1(&program);
         assert_eq!(answer, 13140);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&program);
         let expected = "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
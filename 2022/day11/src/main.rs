@@ -1,10 +1,16 @@
+use aoc_core::Solution;
 use regex::Regex;
-use std::{collections::VecDeque, fmt};
+use std::{
+    collections::VecDeque,
+    rc::Rc,
+    fmt::{self, Display},
+};
 
+#[derive(Clone)]
 struct Monkey {
     id: i32,
     starting_items: VecDeque<usize>,
-    operation: Box<dyn Fn(usize) -> usize>,
+    operation: Rc<dyn Fn(usize) -> usize>,
     divisible: usize,
     true_monkey: i32,
     false_monkey: i32,
@@ -16,7 +22,7 @@ impl Monkey {
         Monkey {
             id: 0,
             starting_items: VecDeque::new(),
-            operation: Box::new(|x| x),
+            operation: Rc::new(|x| x),
             divisible: 0,
             true_monkey: 0,
             false_monkey: 0,
@@ -106,7 +112,7 @@ fn parse_monkey(text: &str) -> Monkey {
     monkey
 }
 
-fn parse_operation(operation: &str) -> Box<dyn Fn(usize) -> usize> {
+fn parse_operation(operation: &str) -> Rc<dyn Fn(usize) -> usize> {
     let re1 = Regex::new(r"new = old (.) (\d+)").unwrap();
     let re2 = Regex::new(r"new = old (.) old").unwrap();
 
@@ -115,24 +121,24 @@ fn parse_operation(operation: &str) -> Box<dyn Fn(usize) -> usize> {
         let op = cap.get(1).unwrap().as_str();
         let x: usize = cap.get(2).unwrap().as_str().parse().unwrap();
         match op {
-            "*" => return Box::new(move |y| y * x),
-            "+" => return Box::new(move |y| y + x),
-            _ => return Box::new(move |y| y),
+            "*" => return Rc::new(move |y| y * x),
+            "+" => return Rc::new(move |y| y + x),
+            _ => return Rc::new(move |y| y),
         }
     } else if re2.is_match(operation) {
         let cap = re2.captures(operation).unwrap();
         let op = cap.get(1).unwrap().as_str();
         match op {
-            "*" => return Box::new(move |y| y * y),
-            "+" => return Box::new(move |y| y + y),
-            _ => return Box::new(move |y| y),
+            "*" => return Rc::new(move |y| y * y),
+            "+" => return Rc::new(move |y| y + y),
+            _ => return Rc::new(move |y| y),
         }
     }
-    Box::new(move |y| y)
+    Rc::new(move |y| y)
 }
 
-fn solve_p1(input: &str) -> usize {
-    let mut monkeys = parse_input(input);
+fn solve_p1(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
     (0..20).for_each(|_| {
         (0..monkeys.len()).for_each(|i| {
             while !monkeys[i].starting_items.is_empty() {
@@ -157,8 +163,8 @@ fn solve_p1(input: &str) -> usize {
     monkeys[len - 1].inspected_count * monkeys[len - 2].inspected_count
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut monkeys = parse_input(input);
+fn solve_p2(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
     let divisor_product = monkeys.iter().fold(1, |acc, x| acc * x.divisible);
     (0..10_000).for_each(|_| {
         (0..monkeys.len()).for_each(|i| {
@@ -183,12 +189,27 @@ fn solve_p2(input: &str) -> usize {
     monkeys[len - 1].inspected_count * monkeys[len - 2].inspected_count
 }
 
+struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Vec<Monkey> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 11);
+    aoc_core::run::<Day11>(&input);
 }
 
 #[cfg(test)]
@@ -226,9 +247,10 @@ Monkey 3:
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let monkeys = parse_input(INPUT);
+        let answer = solve_p1(&monkeys);
         assert_eq!(answer, 10605);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&monkeys);
         assert_eq!(answer, 2713310158);
     }
 }
@@ -1,6 +1,8 @@
+use aoc_core::Solution;
 use itertools::Itertools;
 use pathfinding::prelude::dijkstra;
 use pathfinding::prelude::Matrix;
+use std::fmt::Display;
 
 fn parse_grid_input(input: &str) -> Matrix<char> {
     Matrix::from_rows(input.lines().map(|line| line.chars().collect::<Vec<_>>())).unwrap()
@@ -60,12 +62,27 @@ fn neighbors(grid: &Matrix<char>, row: usize, col: usize) -> Vec<(usize, usize)>
         .collect()
 }
 
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 12);
+    aoc_core::run::<Day12>(&input);
 }
 
 #[cfg(test)]
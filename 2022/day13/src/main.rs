@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::cmp::Ordering;
+use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum PacketData {
@@ -23,16 +25,15 @@ impl Ord for PacketData {
     }
 }
 
-fn solve_p1(input: &str) -> usize {
-    let pkts = parse_input(input);
+fn solve_p1(pkts: &[Vec<PacketData>]) -> usize {
     pkts.chunks_exact(2)
         .enumerate()
         .map(|(i, chunk)| if chunk[0] <= chunk[1] { i + 1 } else { 0 })
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut pkts = parse_input(input);
+fn solve_p2(pkts: &[Vec<PacketData>]) -> usize {
+    let mut pkts = pkts.to_vec();
     let divider1 = parse_packet("[[2]]");
     let divider2 = parse_packet("[[6]]");
     pkts.push(divider1.clone());
@@ -93,12 +94,27 @@ fn parse_number(s: &str, i: usize) -> (i32, usize) {
     (s[i..j].parse().unwrap(), j)
 }
 
+struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Vec<PacketData>>;
+
+    fn parse(input: &str) -> Vec<Vec<PacketData>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 13);
+    aoc_core::run::<Day13>(&input);
 }
 
 #[cfg(test)]
@@ -131,9 +147,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let pkts = parse_input(INPUT);
+        let answer = solve_p1(&pkts);
         assert_eq!(answer, 13);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&pkts);
         assert_eq!(answer, 140);
     }
 
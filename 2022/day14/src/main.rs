@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use itertools::Itertools;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<Vec<(usize, usize)>> {
     input
@@ -67,8 +69,7 @@ fn fill_sand(grid: &mut [Vec<char>], source: (usize, usize)) -> i32 {
     count
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let paths = parse_input(input);
+fn solve_p1(paths: &[Vec<(usize, usize)>]) -> i32 {
     let sand_source = (500, 0);
     let bottom_row = paths
         .iter()
@@ -96,8 +97,7 @@ fn solve_p1(input: &str) -> i32 {
     fill_sand(&mut grid, sand_source)
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let paths = parse_input(input);
+fn solve_p2(paths: &[Vec<(usize, usize)>]) -> i32 {
     let sand_source = (500, 0);
     let bottom_row = paths
         .iter()
@@ -131,12 +131,27 @@ fn solve_p2(input: &str) -> i32 {
     fill_sand(&mut grid, sand_source)
 }
 
+struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Vec<(usize, usize)>>;
+
+    fn parse(input: &str) -> Vec<Vec<(usize, usize)>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 14);
+    aoc_core::run::<Day14>(&input);
 }
 
 #[cfg(test)]
@@ -148,9 +163,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let paths = parse_input(INPUT);
+        let answer = solve_p1(&paths);
         assert_eq!(answer, 24);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&paths);
         assert_eq!(answer, 93);
     }
 }
@@ -1,5 +1,7 @@
+use aoc_core::Solution;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 use std::ops::Range;
 
 #[derive(Debug)]
@@ -85,8 +87,7 @@ fn parse_input(input: &str) -> (Vec<Sensor>, Vec<(i32, i32)>) {
     (sensors, beacons)
 }
 
-fn solve_p1(input: &str, target_row: i32) -> i32 {
-    let (sensors, beacons) = parse_input(input);
+fn solve_p1((sensors, beacons): &(Vec<Sensor>, Vec<(i32, i32)>), target_row: i32) -> i32 {
     let mut covered = Vec::new();
     sensors.iter().for_each(|sensor| {
         covered.push(sensor.coverage_on_row(target_row));
@@ -101,8 +102,11 @@ fn solve_p1(input: &str, target_row: i32) -> i32 {
     covered.len() as i32
 }
 
-fn solve_p2(input: &str, x_bound: usize, y_bound: usize) -> usize {
-    let (sensors, _) = parse_input(input);
+fn solve_p2(
+    (sensors, _): &(Vec<Sensor>, Vec<(i32, i32)>),
+    x_bound: usize,
+    y_bound: usize,
+) -> usize {
     let lines = sensors
         .iter()
         .flat_map(|sensor| sensor.perimiter())
@@ -140,19 +144,34 @@ fn solve_p2(input: &str, x_bound: usize, y_bound: usize) -> usize {
         .collect::<Vec<_>>();
 
     for point in &intersection_points {
-        if is_uncovered(*point, &sensors) {
+        if is_uncovered(*point, sensors) {
             return point.0 as usize * 4_000_000 + point.1 as usize;
         }
     }
     0
 }
 
+struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = (Vec<Sensor>, Vec<(i32, i32)>);
+
+    fn parse(input: &str) -> (Vec<Sensor>, Vec<(i32, i32)>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, 2_000_000)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input, 4_000_000, 4_000_000)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input, 2_000_000);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input, 4_000_000, 4_000_000);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 15);
+    aoc_core::run::<Day15>(&input);
 }
 
 #[cfg(test)]
@@ -176,9 +195,10 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT, 10);
+        let data = parse_input(INPUT);
+        let answer = solve_p1(&data, 10);
         assert_eq!(answer, 26);
-        let answer = solve_p2(INPUT, 20, 20);
+        let answer = solve_p2(&data, 20, 20);
         assert_eq!(answer, 56000011);
     }
 }
@@ -1,5 +1,6 @@
+use aoc_core::Solution;
 use regex::Regex;
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 #[derive(Debug, Clone)]
 struct Valve {
@@ -79,176 +80,361 @@ fn parse(input: &str) -> Vec<SimpleValve> {
 }
 
 /*
- * Convert the valves into a weighted graph and generate a min distance matrix by using floyd warshall
+ * Convert the valves into a weighted graph and generate a min distance matrix by using floyd warshall,
+ * then keep only the valves worth opening (and the start) as a `Network`
  *
  *
  * How we keep track of open valves
- * Let's say that we have a list of 8 valves, and the starting valve is at index 3
- * The starting bitmask in this case would look like this
- * 0b11110111
+ * Each valve worth opening gets a bit in a u64 set, a 1 meaning that it's been opened. With 8
+ * valves worth opening, the set of every valve is
+ * 2^8 - 1 = 0b11111111
  *
- * Open valves are represented by 0s and closed ones are 1s
+ * Any number of agents can work the valves at once, each with their own time budget (you alone
+ * for 30 minutes in part 1, you and the elephant for 26 minutes each in part 2). There are two
+ * ways of finding the best plan.
  *
- * the size of the bitmask is based on how many valves we want to keep track of
- * in our case the size is 2^8 - 1
- * 2^8     = 0b100000000
- * 2^8 - 1 = 0b011111111
+ * # Pruning::None
  *
- * As perform the simulation we modify the bitmask according to what valves are opened, and we
- * keep track of the highest possible flow rate out of each path combination
- * We also use a hashmap that keeps track the highest flow rate that we can get out of
- * different valve combinations (This will be important for part2)
+ * For each time budget we simulate a single agent, trying every route, and keep track of the
+ * highest flow rate (and the route) out of each set of opened valves. A table indexed by set then
+ * gets the best of each set or any of its subsets.
  *
- * # Part 1
+ * The agents are added one at a time. The most pressure the first k agents can release opening
+ * only valves in a set is the best split of that set between the k-th agent and the k - 1 before
+ * it, which is found by walking every subset of the set:
  *
- * This part is pretty straightforward, we can just use the highest flow rate returned by the
- * simulation
+ * sub = (sub - 1) & set
  *
+ * Valves can only be opened once, so the splits never overlap. After the last agent, the split of
+ * every valve is walked back to find the set (and so the route) of each agent.
  *
- * # Part 2
+ * # Pruning::UpperBound
  *
- * Here, we simulate the paths of the elf and the elpehant separately which results in 2 path
- * hashmaps
- *
- * After that we loop over each of the elf's paths and find paths that the elephant took
- * that don't overlap (using some bitmasks magic) with the elf path being checked;
- * Overlap here means that both of them opened the same valves, so we basically discard all
- * paths where both of them have at least 1 valve in common because a valve can only be opened
- * once.
- *
- * (! elf_mask) & (! elephant_mask) & init_mask == 0
- * The reason why we flip elf_mask's and elephant_mask's bits is because open valves are represented by 0s
- * instead of 1s (not sure why I thought this was a good idea) and then we have to AND the result
- * with the initial mask because when we flip the bits of a number, all of the bits are flipped (depending on the integer size being used)
- * and not only the ones being used, for example if we have 0b0011 that is stored within a u16 and
- * we flip the bits we'll end up with 0b1111111111111100, as you can see the flipping will happen
- * across all 16 bits, and that's why we AND the result with the initial_mask.
- *
- * If the result of the above bitmask expression results in a number that's bigger than 0 that
- * means that we have overlaps and we have to skip the current pair.
- * For pairs that don't have overlaps we add their highest flow rates together and compare them
- * with the rest.
+ * Here all agents are simulated together, the one with the most time left choosing where to go
+ * next or to stop. A branch is dropped as soon as it can't beat the best plan so far, even if
+ * every closed valve was opened by whichever agent could get to it first.
  */
 
-fn solve_p1(input: &str) -> u32 {
-    let valves = parse(input);
-    let graph = init_graph(&valves, |x| &x.links);
-    let dist = floyd_warshall(graph);
-
-    let start_idx = valves.iter().position(|x| x.name == "AA").unwrap();
-    let len = dist.len();
+/// The valves worth opening, the travel times between them, and where the agents start
+struct Network {
+    names: Vec<String>,
+    rates: Vec<u32>,
+    // Also has the start as the last row and column
+    dist: Vec<Vec<u32>>,
+}
 
-    // I spent 20 hours trying to find a bug that later appeared to be happening because of this line
-    // APPARENTLY THE STARTING VALVE IS ALWAYS "AA" AND NOT THE FIRST VALVE IN THE INPUT
-    let init_mask: u64 = (1 << len) - 1;
+impl Network {
+    fn new(valves: &[SimpleValve], start: &str) -> Self {
+        let graph = init_graph(valves, |x| &x.links);
+        let dist = floyd_warshall(graph);
 
-    let (flow, _) = simulate(&valves, &dist, init_mask, start_idx, 30);
+        let start_idx = valves.iter().position(|x| x.name == start).unwrap();
+        let mut nodes: Vec<usize> = (0..valves.len()).filter(|&i| valves[i].rate > 0).collect();
+        assert!(nodes.len() < 64, "too many valves for a u64 set");
+        nodes.push(start_idx);
 
-    flow
-}
+        Self {
+            names: nodes.iter().map(|&i| valves[i].name.clone()).collect(),
+            rates: nodes.iter().map(|&i| valves[i].rate).collect(),
+            dist: nodes
+                .iter()
+                .map(|&i| {
+                    nodes
+                        .iter()
+                        .map(|&j| if i == j { 0 } else { dist[i][j] })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
 
-fn solve_p2(input: &str) -> u32 {
-    let valves = parse(input);
-    let graph = init_graph(&valves, |x| &x.links);
-    let dist = floyd_warshall(graph);
+    /// How many valves are worth opening
+    fn valves(&self) -> usize {
+        self.rates.len() - 1
+    }
 
-    let start_idx = valves.iter().position(|x| x.name == "AA").unwrap();
-    let init_mask: u64 = (1 << dist.len()) - 1;
+    fn start(&self) -> usize {
+        self.valves()
+    }
 
-    let (_, elf_memo) = simulate(&valves, &dist, init_mask, start_idx, 26);
-    let (_, elephant_memo) = simulate(&valves, &dist, init_mask, start_idx, 26);
+    /// The minutes left after going from `i` to `j` and opening it, if there's time to
+    fn arrive(&self, i: usize, j: usize, minutes: u32) -> Option<u32> {
+        minutes.checked_sub(self.dist[i][j] + 1).filter(|&m| m > 0)
+    }
 
-    let max_flow = elf_memo.iter().fold(0, |max, (&elf_mask, &elf_flow)| {
-        elephant_memo
+    /// The most pressure that agents with the given time budgets can release together, and
+    /// the valves each of them opens
+    fn optimise(&self, budgets: &[u32], pruning: Pruning) -> Plan {
+        let routes = match pruning {
+            Pruning::None => self.combine_routes(budgets),
+            Pruning::UpperBound => self.branch_and_bound(budgets),
+        };
+        let schedules: Vec<Schedule> = routes
             .iter()
-            .fold(max, |max, (&mask, &elephant_flow)| {
-                // Check that there's no overlap between the 2 paths
-                if (!mask) & (!elf_mask) & init_mask == 0 {
-                    return max.max(elephant_flow + elf_flow);
+            .zip(budgets)
+            .map(|(route, &minutes)| self.schedule(route, minutes))
+            .collect();
+
+        Plan {
+            released: schedules.iter().map(|s| s.released).sum(),
+            schedules,
+        }
+    }
+
+    fn schedule(&self, route: &[usize], minutes: u32) -> Schedule {
+        let mut openings = Vec::new();
+        let mut released = 0;
+        let mut i = self.start();
+        let mut left = minutes;
+        for &j in route {
+            left = self.arrive(i, j, left).unwrap();
+            openings.push(Opening {
+                valve: self.names[j].clone(),
+                minute: minutes - left,
+            });
+            released += left * self.rates[j];
+            i = j;
+        }
+
+        Schedule { openings, released }
+    }
+
+    fn combine_routes(&self, budgets: &[u32]) -> Vec<Vec<usize>> {
+        let Some((&first, rest)) = budgets.split_first() else {
+            return Vec::new();
+        };
+        assert!(self.valves() <= 24, "too many valves to try every split");
+
+        let mut tables: HashMap<u32, Routes> = HashMap::new();
+        for &minutes in budgets {
+            tables
+                .entry(minutes)
+                .or_insert_with(|| self.simulate(minutes));
+        }
+
+        // The most the agents so far release opening only valves in each set, and for every
+        // agent after the first, the part of each set that it opens
+        let mut released: Vec<u32> = tables[&first].best.iter().map(|&(f, _)| f).collect();
+        let mut splits: Vec<Vec<usize>> = Vec::new();
+        for minutes in rest {
+            let table = &tables[minutes];
+            let mut next = vec![0; released.len()];
+            let mut split = vec![0; released.len()];
+            for set in 0..released.len() {
+                let mut sub = set;
+                loop {
+                    let flow = table.best[sub].0 + released[set ^ sub];
+                    if flow > next[set] {
+                        next[set] = flow;
+                        split[set] = sub;
+                    }
+                    if sub == 0 {
+                        break;
+                    }
+                    sub = (sub - 1) & set;
                 }
+            }
+            released = next;
+            splits.push(split);
+        }
 
-                max
-            })
-    });
+        let mut set = released.len() - 1;
+        let mut routes = vec![Vec::new(); budgets.len()];
+        for (k, minutes) in budgets.iter().enumerate().rev() {
+            let sub = if k == 0 { set } else { splits[k - 1][set] };
+            let table = &tables[minutes];
+            routes[k] = table.routes[&table.best[sub].1].clone();
+            set ^= sub;
+        }
+
+        routes
+    }
+
+    fn simulate(&self, minutes: u32) -> Routes {
+        let mut mask_flow: HashMap<u64, (u32, Vec<usize>)> = HashMap::new();
+        self.traveling_salesman(&mut mask_flow, &mut Vec::new(), 0, minutes, 0, self.start());
+
+        let mut best = vec![(0, 0); 1 << self.valves()];
+        for (&mask, &(flow, _)) in &mask_flow {
+            best[mask as usize] = (flow, mask as usize);
+        }
+        for bit in 0..self.valves() {
+            for set in 0..best.len() {
+                if set & (1 << bit) != 0 && best[set ^ (1 << bit)].0 > best[set].0 {
+                    best[set] = best[set ^ (1 << bit)];
+                }
+            }
+        }
+
+        Routes {
+            best,
+            routes: mask_flow
+                .into_iter()
+                .map(|(mask, (_, route))| (mask as usize, route))
+                .collect(),
+        }
+    }
 
-    max_flow
+    fn traveling_salesman(
+        &self,
+        memo: &mut HashMap<u64, (u32, Vec<usize>)>,
+        route: &mut Vec<usize>,
+        mask: u64,
+        minutes: u32,
+        flow: u32,
+        i: usize,
+    ) {
+        let best = memo.entry(mask).or_insert_with(|| (0, route.clone()));
+        if flow > best.0 {
+            *best = (flow, route.clone());
+        }
+
+        for j in 0..self.valves() {
+            if mask & (1 << j) != 0 {
+                continue;
+            }
+            let Some(cur_minutes) = self.arrive(i, j, minutes) else {
+                continue;
+            };
+
+            route.push(j);
+            self.traveling_salesman(
+                memo,
+                route,
+                mask | (1 << j),
+                cur_minutes,
+                flow + cur_minutes * self.rates[j],
+                j,
+            );
+            route.pop();
+        }
+    }
+
+    fn branch_and_bound(&self, budgets: &[u32]) -> Vec<Vec<usize>> {
+        let mut search = Search {
+            network: self,
+            agents: budgets.iter().map(|&m| (self.start(), m)).collect(),
+            routes: vec![Vec::new(); budgets.len()],
+            best: 0,
+            best_routes: vec![Vec::new(); budgets.len()],
+        };
+        search.visit(0, 0);
+
+        search.best_routes
+    }
 }
 
-fn simulate(
-    valves: &Vec<SimpleValve>,
-    dist: &Vec<Vec<u32>>,
-    init_mask: u64,
-    start_idx: usize,
-    minutes: u32,
-) -> (u32, HashMap<u64, u32>) {
-    let non_zero_valves: Vec<usize> = valves
-        .iter()
-        .enumerate()
-        .filter(|(_, x)| x.rate > 0)
-        .map(|(i, _)| i)
-        .collect();
+/// The best single agent routes for a time budget
+struct Routes {
+    // The most released by opening each set of valves or any subset of it, and that subset
+    best: Vec<(u32, usize)>,
+    routes: HashMap<usize, Vec<usize>>,
+}
 
-    let flow = 0;
-    let mut mask_flow: HashMap<u64, u32> = HashMap::new();
-
-    let flow = traveling_salesman(
-        valves,
-        &mut mask_flow,
-        &non_zero_valves,
-        dist,
-        init_mask,
-        minutes,
-        flow,
-        start_idx,
-        0,
-    );
-
-    (flow, mask_flow)
+struct Search<'a> {
+    network: &'a Network,
+    // Where each agent is and the minutes it has left
+    agents: Vec<(usize, u32)>,
+    routes: Vec<Vec<usize>>,
+    best: u32,
+    best_routes: Vec<Vec<usize>>,
 }
 
-fn traveling_salesman(
-    valves: &Vec<SimpleValve>,
-    memo: &mut HashMap<u64, u32>,
-    non_zero_valves: &Vec<usize>,
-    dist: &Vec<Vec<u32>>,
-    mask: u64,
-    minutes: u32,
-    flow: u32,
-    i: usize,
-    _depth: u32,
-) -> u32 {
-    let mut max_flow = flow;
-
-    memo.insert(mask, *memo.get(&mask).unwrap_or(&0).max(&flow));
-
-    for &j in non_zero_valves.iter() {
-        let cur_minutes = minutes
-            .checked_sub(dist[i][j])
-            .and_then(|x| x.checked_sub(1))
-            .unwrap_or(0);
-
-        if (mask & (1 << j)) == 0 {
-            continue;
+impl Search<'_> {
+    fn visit(&mut self, mask: u64, flow: u32) {
+        if flow > self.best {
+            self.best = flow;
+            self.best_routes = self.routes.clone();
+        }
+
+        let closed = || (0..self.network.valves()).filter(|&j| mask & (1 << j) == 0);
+        let bound: u32 = closed()
+            .map(|j| {
+                self.agents
+                    .iter()
+                    .filter_map(|&(i, minutes)| self.network.arrive(i, j, minutes))
+                    .max()
+                    .map_or(0, |m| m * self.network.rates[j])
+            })
+            .sum();
+        if flow + bound <= self.best {
+            return;
+        }
+
+        let Some(agent) = (0..self.agents.len())
+            .filter(|&a| self.agents[a].1 > 0)
+            .max_by_key(|&a| (self.agents[a].1, std::cmp::Reverse(a)))
+        else {
+            return;
+        };
+        let (i, minutes) = self.agents[agent];
+
+        // Try the valves that would release the most first, so that good plans are found early
+        let mut next: Vec<(u32, usize, u32)> = closed()
+            .filter_map(|j| {
+                let left = self.network.arrive(i, j, minutes)?;
+                Some((left * self.network.rates[j], j, left))
+            })
+            .collect();
+        next.sort_unstable_by(|a, b| b.cmp(a));
+
+        for (released, j, left) in next {
+            self.agents[agent] = (j, left);
+            self.routes[agent].push(j);
+            self.visit(mask | (1 << j), flow + released);
+            self.routes[agent].pop();
         }
 
-        let cur_mask = mask & !(1 << j);
-
-        let cur_flow = flow + (cur_minutes * valves[j].rate);
-
-        max_flow = max_flow.max(traveling_salesman(
-            valves,
-            memo,
-            non_zero_valves,
-            dist,
-            cur_mask,
-            cur_minutes,
-            cur_flow,
-            j,
-            _depth + 1,
-        ));
+        // Or this agent stops, leaving the rest of the valves to the others
+        self.agents[agent] = (i, 0);
+        self.visit(mask, flow);
+        self.agents[agent] = (i, minutes);
     }
+}
+
+/// How `Network::optimise` searches for the best plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pruning {
+    /// Combine the best single agent route for every set of valves
+    None,
+    /// Search every agent together, dropping branches that can't beat the best plan so far
+    UpperBound,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Opening {
+    valve: String,
+    // The minute spent opening it, releasing pressure from the next one on
+    minute: u32,
+}
+
+/// The valves one agent opens, in order, and the pressure that they release
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Schedule {
+    openings: Vec<Opening>,
+    released: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Plan {
+    released: u32,
+    schedules: Vec<Schedule>,
+}
+
+fn solve_p1(input: &str) -> u32 {
+    let valves = parse(input);
+    // I spent 20 hours trying to find a bug that later appeared to be happening because of this line
+    // APPARENTLY THE STARTING VALVE IS ALWAYS "AA" AND NOT THE FIRST VALVE IN THE INPUT
+    let network = Network::new(&valves, "AA");
 
-    max_flow
+    network.optimise(&[30], Pruning::UpperBound).released
+}
+
+fn solve_p2(input: &str) -> u32 {
+    let valves = parse(input);
+    let network = Network::new(&valves, "AA");
+
+    network.optimise(&[26, 26], Pruning::None).released
 }
 
 fn floyd_warshall(graph: Vec<Vec<u32>>) -> Vec<Vec<u32>> {
@@ -282,17 +468,33 @@ where
     graph
 }
 
+struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 16);
+    aoc_core::run::<Day16>(&input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     const INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 Valve BB has flow rate=13; tunnels lead to valves CC, AA
@@ -306,6 +508,22 @@ Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II
 ";
 
+    fn check(plan: &Plan, budgets: &[u32]) {
+        let mut opened = HashSet::new();
+        for (schedule, &minutes) in plan.schedules.iter().zip(budgets) {
+            assert!(schedule.openings.iter().all(|o| o.minute < minutes));
+            assert!(schedule
+                .openings
+                .iter()
+                .all(|o| opened.insert(o.valve.clone())));
+        }
+        assert_eq!(plan.schedules.len(), budgets.len());
+        assert_eq!(
+            plan.released,
+            plan.schedules.iter().map(|s| s.released).sum::<u32>()
+        );
+    }
+
     #[test]
     fn test_solve_with_test_input() {
         let answer = solve_p1(INPUT);
@@ -313,4 +531,56 @@ Valve JJ has flow rate=21; tunnel leads to valve II
         let answer = solve_p2(INPUT);
         assert_eq!(answer, 1707);
     }
+
+    #[test]
+    fn test_one_agent() {
+        let network = Network::new(&parse(INPUT), "AA");
+        for pruning in [Pruning::None, Pruning::UpperBound] {
+            let plan = network.optimise(&[30], pruning);
+            check(&plan, &[30]);
+            assert_eq!(plan.released, 1651);
+            let openings: Vec<(&str, u32)> = plan.schedules[0]
+                .openings
+                .iter()
+                .map(|o| (o.valve.as_str(), o.minute))
+                .collect();
+            assert_eq!(
+                openings,
+                [
+                    ("DD", 2),
+                    ("BB", 5),
+                    ("JJ", 9),
+                    ("HH", 17),
+                    ("EE", 21),
+                    ("CC", 24)
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_two_agents() {
+        let network = Network::new(&parse(INPUT), "AA");
+        for pruning in [Pruning::None, Pruning::UpperBound] {
+            let plan = network.optimise(&[26, 26], pruning);
+            check(&plan, &[26, 26]);
+            assert_eq!(plan.released, 1707);
+        }
+    }
+
+    #[test]
+    fn test_three_agents() {
+        let network = Network::new(&parse(INPUT), "AA");
+        let plan = network.optimise(&[26, 26, 26], Pruning::None);
+        check(&plan, &[26, 26, 26]);
+        assert_eq!(plan.released, 1794);
+
+        for budgets in [[26, 26, 26], [30, 10, 4], [3, 0, 20]] {
+            let plan = network.optimise(&budgets, Pruning::None);
+            check(&plan, &budgets);
+            let bounded = network.optimise(&budgets, Pruning::UpperBound);
+            check(&bounded, &budgets);
+            assert_eq!(plan.released, bounded.released);
+        }
+    }
 }
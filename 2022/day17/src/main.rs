@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use aoc_core::{cycle, Solution};
+use std::fmt::Display;
 
 // Blocks in the starting position. Lowest 7 bits are used to represent the block.
 const BLOCKS: [&[u8]; 5] = [
@@ -34,18 +35,50 @@ fn display_block(block: &[u8]) {
     block.iter().for_each(|row| println!(">   {:07b}", row));
 }
 
-fn solve(input: &str, num_blocks: usize) -> usize {
-    let jets = parse_input(input);
-    let mut grid: Vec<u8> = vec![0; 3];
+// How many rows from the top of the tower go into a chamber's key. Blocks never fall further
+// than this into the tower in practice, so the rows below it don't affect where they land.
+const SURFACE_ROWS: usize = 32;
+
+/// The chamber after some blocks have come to rest.
+#[derive(Clone)]
+struct Chamber {
+    grid: Vec<u8>,
+    // Number of blocks dropped so far
+    blocks: usize,
+    // Index of the next jet
+    jet: usize,
+}
 
-    let mut block_iter = BLOCKS.iter().cycle();
-    let mut jet_iter = jets.iter().cycle();
+impl Chamber {
+    fn new() -> Chamber {
+        Chamber {
+            grid: vec![0; 3],
+            blocks: 0,
+            jet: 0,
+        }
+    }
 
-    let mut jet_count = 0;
-    let mut seen: HashMap<(usize, usize, u8), (usize, usize)> = HashMap::new();
+    fn height(&self) -> usize {
+        self.grid.iter().filter(|&&row| row != 0).count()
+    }
 
-    (0..num_blocks).for_each(|block_idx| {
-        let block = block_iter.next().unwrap().to_vec();
+    // Everything that decides where the following blocks land: the next block, the next jet
+    // and the shape of the top of the tower.
+    fn key(&self) -> (usize, usize, Vec<u8>) {
+        let surface = self
+            .grid
+            .iter()
+            .rev()
+            .skip_while(|&&row| row == 0)
+            .take(SURFACE_ROWS)
+            .copied()
+            .collect();
+        (self.blocks % BLOCKS.len(), self.jet, surface)
+    }
+
+    fn drop_next(&self, jets: &[Jet]) -> Chamber {
+        let block = BLOCKS[self.blocks % BLOCKS.len()].to_vec();
+        let mut grid = self.grid.clone();
         let empty_rows_at_top = grid.iter().rev().take_while(|&&row| row == 0).count();
         let rows_to_add_or_remove = empty_rows_at_top as i32 - block.len() as i32 - 3;
         if rows_to_add_or_remove < 0 {
@@ -53,61 +86,34 @@ fn solve(input: &str, num_blocks: usize) -> usize {
         } else if rows_to_add_or_remove > 0 {
             grid.truncate(grid.len() - rows_to_add_or_remove as usize);
         }
-        jet_count += drop_block(&mut jet_iter, &mut grid, block);
-
-        let amount = grid.iter().filter(|&&row| row != 0).count();
-
-        let key = (
-            block_idx % BLOCKS.len(),
-            jet_count % jets.len(),
-            grid[grid.len() - 4],
-        );
-        if seen.contains_key(&key) {
-            let (prev_block_idx, prev_amount) = seen[&key];
-            let cycle_length = block_idx - prev_block_idx;
-            let remaining_blocks = num_blocks - block_idx;
-            let cycles = remaining_blocks / cycle_length;
-            let remaining = remaining_blocks % cycle_length;
-            let amount_per_cycle = amount - prev_amount;
-            let total_amount = prev_amount + cycles * amount_per_cycle;
-            println!("Cycle length: {}", cycle_length);
-            // return total_amount;
-        } else {
-            seen.insert(key, (block_idx, amount));
-            // display_grid(&grid);
-        }
-    });
 
-    grid.iter().filter(|&&row| row != 0).count()
+        let mut jet_iter = jets[self.jet..].iter().chain(jets.iter().cycle());
+        let jet_count = drop_block(&mut jet_iter, &mut grid, block);
+        Chamber {
+            grid,
+            blocks: self.blocks + 1,
+            jet: (self.jet + jet_count) % jets.len(),
+        }
+    }
 }
 
-fn solve_p1(input: &str) -> usize {
-    let num_blocks = 2022;
-    let jets = parse_input(input);
-    let mut grid: Vec<u8> = vec![0; 3];
-
-    let mut block_iter = BLOCKS.iter().cycle();
-    let mut jet_iter = jets.iter().cycle();
-
-    (0..num_blocks).for_each(|_| {
-        let block = block_iter.next().unwrap().to_vec();
-        let empty_rows_at_top = grid.iter().rev().take_while(|&&row| row == 0).count();
-        let rows_to_add_or_remove = empty_rows_at_top as i32 - block.len() as i32 - 3;
-        if rows_to_add_or_remove < 0 {
-            grid.extend(vec![0; -rows_to_add_or_remove as usize]);
-        } else if rows_to_add_or_remove > 0 {
-            grid.truncate(grid.len() - rows_to_add_or_remove as usize);
-        }
-        drop_block(&mut jet_iter, &mut grid, block);
-    });
+fn solve(jets: &[Jet], num_blocks: usize) -> usize {
+    // The tower settles into a pattern that repeats with the blocks and jets, growing by the
+    // same height each time round, so only the first repeat needs simulating.
+    let tower = cycle::find_repeat_by_key(
+        Chamber::new(),
+        |chamber| chamber.drop_next(jets),
+        Chamber::key,
+    );
+    tower.extrapolate(num_blocks, |chamber| chamber.height() as i64) as usize
+}
 
-    grid.iter().filter(|&&row| row != 0).count()
+fn solve_p1(jets: &[Jet]) -> usize {
+    solve(jets, 2022)
 }
 
-fn solve_p2(input: &str) -> usize {
-    // TODO: Complete the cycle detection
-    // solve(input, 1_000_000_000_000)
-    0
+fn solve_p2(jets: &[Jet]) -> usize {
+    solve(jets, 1_000_000_000_000)
 }
 
 fn drop_block<'a>(
@@ -212,12 +218,27 @@ fn can_shift_right(block: &[u8]) -> bool {
     block.iter().all(|&row| row & 0b000_0001 == 0)
 }
 
+struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<Jet>;
+
+    fn parse(input: &str) -> Vec<Jet> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 17);
+    aoc_core::run::<Day17>(&input);
 }
 
 fn parse_input(input: &str) -> Vec<Jet> {
@@ -240,9 +261,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let jets = parse_input(INPUT);
+        let answer = solve_p1(&jets);
         assert_eq!(answer, 3068);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&jets);
         assert_eq!(answer, 1514285714288);
     }
 }
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -45,8 +47,7 @@ fn parse_input(input: &str) -> Vec<Cube> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let cubes = parse_input(input);
+fn solve_p1(cubes: &[Cube]) -> i32 {
     let mut exposed = 0;
     for cube in cubes.iter() {
         for n in cube.neighbors() {
@@ -58,8 +59,7 @@ fn solve_p1(input: &str) -> i32 {
     exposed
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let cubes = parse_input(input);
+fn solve_p2(cubes: &[Cube]) -> i32 {
     let max_bound = Cube::new(
         cubes.iter().max_by_key(|c| c.x).unwrap().x + 1,
         cubes.iter().max_by_key(|c| c.y).unwrap().y + 1,
@@ -99,12 +99,27 @@ fn solve_p2(input: &str) -> i32 {
     exposed_outside
 }
 
+struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<Cube>;
+
+    fn parse(input: &str) -> Vec<Cube> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 18);
+    aoc_core::run::<Day18>(&input);
 }
 
 #[cfg(test)]
@@ -127,11 +142,12 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1("1,1,1\n2,1,1");
+        let answer = solve_p1(&parse_input("1,1,1\n2,1,1"));
         assert_eq!(answer, 10);
-        let answer = solve_p1(INPUT);
+        let cubes = parse_input(INPUT);
+        let answer = solve_p1(&cubes);
         assert_eq!(answer, 64);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&cubes);
         assert_eq!(answer, 58);
     }
 }
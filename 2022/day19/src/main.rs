@@ -1,5 +1,7 @@
+use std::fmt::Display;
 use std::ops::{Add, Mul};
 
+use aoc_core::Solution;
 use regex::Regex;
 
 fn parse_input(input: &str) -> Vec<Blueprint> {
@@ -7,8 +9,10 @@ fn parse_input(input: &str) -> Vec<Blueprint> {
     let bp_id_re = Regex::new(r"Blueprint (\d+):").unwrap();
     let ore_robot_re = Regex::new(r"Each ore robot costs (\d+) ore.").unwrap();
     let clay_robot_re = Regex::new(r"Each clay robot costs (\d+) ore.").unwrap();
-    let obsidian_robot_re = Regex::new(r"Each obsidian robot costs (\d+) ore and (\d+) clay.").unwrap();
-    let geode_robot_re = Regex::new(r"Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
+    let obsidian_robot_re =
+        Regex::new(r"Each obsidian robot costs (\d+) ore and (\d+) clay.").unwrap();
+    let geode_robot_re =
+        Regex::new(r"Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
 
     for bp_text in input.lines() {
         // Handle differences in format between test input and real input.
@@ -48,18 +52,14 @@ fn parse_input(input: &str) -> Vec<Blueprint> {
             .unwrap();
         blueprint.clay_robot_cost.ore = clay;
 
-        let captures = obsidian_robot_re
-            .captures(&bp)
-            .unwrap();
+        let captures = obsidian_robot_re.captures(&bp).unwrap();
 
         blueprint.obsidian_robot_cost.ore =
             captures.get(1).unwrap().as_str().parse::<u8>().unwrap();
         blueprint.obsidian_robot_cost.clay =
             captures.get(2).unwrap().as_str().parse::<u8>().unwrap();
 
-        let captures = geode_robot_re
-            .captures(&bp)
-            .unwrap();
+        let captures = geode_robot_re.captures(&bp).unwrap();
 
         blueprint.geode_robot_cost.ore = captures.get(1).unwrap().as_str().parse::<u8>().unwrap();
         blueprint.geode_robot_cost.obsidian =
@@ -277,9 +277,7 @@ impl State {
     }
 }
 
-fn solve_p1(input: &str) -> u32 {
-    let blueprints = parse_input(input);
-
+fn solve_p1(blueprints: &[Blueprint]) -> u32 {
     blueprints
         .iter()
         .map(|blueprint| {
@@ -290,9 +288,9 @@ fn solve_p1(input: &str) -> u32 {
         .sum()
 }
 
-fn solve_p2(input: &str) -> u32 {
-    let blueprints = parse_input(input);
-    blueprints.iter()
+fn solve_p2(blueprints: &[Blueprint]) -> u32 {
+    blueprints
+        .iter()
         .take(3)
         .map(|blueprint| {
             let mut best = 0;
@@ -302,12 +300,27 @@ fn solve_p2(input: &str) -> u32 {
         .product()
 }
 
+struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Vec<Blueprint> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-    let answer = solve_p1(&input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(&input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 19);
+    aoc_core::run::<Day19>(&input);
 }
 
 #[cfg(test)]
@@ -317,9 +330,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let blueprints = parse_input(&input);
+        let answer = solve_p1(&blueprints);
         assert_eq!(answer, 33);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&blueprints);
         assert_eq!(answer, 56 * 62);
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> Vec<isize> {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
@@ -43,19 +46,27 @@ fn solve(data: &[isize], multiple: usize, iterations: usize) -> isize {
     sum
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let data = parse_input(input);
+struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Vec<isize>;
+
+    fn parse(input: &str) -> Vec<isize> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&data);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&data);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2022, 20);
+    aoc_core::run::<Day20>(&input);
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 enum Operator {
@@ -71,27 +73,25 @@ impl Monkey {
     }
 }
 
-pub fn solve_p1(input: &str) -> i64 {
-    let (monkeys, root_index, _) = parse_input(input);
-    monkeys[root_index].get_value(&monkeys)
+fn solve_p1((monkeys, root_index, _): &(Vec<Monkey>, usize, usize)) -> i64 {
+    monkeys[*root_index].get_value(monkeys)
 }
 
-pub fn solve_p2(input: &str) -> i64 {
-    let (monkeys, root_index, humn_index) = parse_input(input);
+fn solve_p2((monkeys, root_index, humn_index): &(Vec<Monkey>, usize, usize)) -> i64 {
     let mut q: VecDeque<(usize, i64)> = VecDeque::new(); //(index, expected value)
-    if let Monkey::Expression(i1, i2, _) = monkeys[root_index] {
-        q.push_back((i2, monkeys[i1].get_value(&monkeys)));
-        q.push_back((i1, monkeys[i2].get_value(&monkeys)));
+    if let Monkey::Expression(i1, i2, _) = monkeys[*root_index] {
+        q.push_back((i2, monkeys[i1].get_value(monkeys)));
+        q.push_back((i1, monkeys[i2].get_value(monkeys)));
     }
 
     while let Some((i, expected)) = q.pop_front() {
-        if i == humn_index {
+        if i == *humn_index {
             return expected;
         }
 
         if let Monkey::Expression(i1, i2, _) = monkeys[i] {
-            q.push_back((i1, monkeys[i].get_expected1(expected, &monkeys)));
-            q.push_back((i2, monkeys[i].get_expected2(expected, &monkeys)));
+            q.push_back((i1, monkeys[i].get_expected1(expected, monkeys)));
+            q.push_back((i2, monkeys[i].get_expected2(expected, monkeys)));
         }
     }
 
@@ -131,18 +131,27 @@ fn parse_input(input: &str) -> (Vec<Monkey>, usize, usize) {
     )
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = (Vec<Monkey>, usize, usize);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (Vec<Monkey>, usize, usize) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2022, 21);
+    aoc_core::run::<Day21>(&input);
 }
 
 #[cfg(test)]
@@ -167,9 +176,10 @@ hmdt: 32";
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let data = parse_input(INPUT);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 152);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 301);
     }
 }
@@ -1,4 +1,8 @@
+use aoc_core::Solution;
 use itertools::iterate;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> (Vec<Vec<u8>>, Vec<Move>) {
     let (map, path) = input.split_once("\n\n").unwrap();
@@ -23,7 +27,7 @@ fn parse_input(input: &str) -> (Vec<Vec<u8>>, Vec<Move>) {
     (grid, path)
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     Up,
     Down,
@@ -47,15 +51,15 @@ enum Move {
 }
 use Move::*;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct State {
     x: usize,
     y: usize,
     direction: Direction,
 }
 
-fn solve((grid, path): (Vec<Vec<u8>>, Vec<Move>), move_one: fn(&State) -> State) -> usize {
-    let State { x, y, direction } = path.into_iter().fold(
+fn solve((grid, path): &(Vec<Vec<u8>>, Vec<Move>), move_one: impl Fn(&State) -> State) -> usize {
+    let State { x, y, direction } = path.iter().fold(
         State {
             y: 0,
             x: grid[0].iter().position(|&c| c == b'.').unwrap(),
@@ -80,7 +84,7 @@ fn solve((grid, path): (Vec<Vec<u8>>, Vec<Move>), move_one: fn(&State) -> State)
                 },
                 ..state
             },
-            Forward(n) => iterate(state, move_one)
+            Forward(n) => iterate(state, &move_one)
                 .filter(|s| *grid[s.y].get(s.x).unwrap_or(&b' ') != b' ')
                 .take(n + 1)
                 .take_while(|s| grid[s.y][s.x] == b'.')
@@ -99,260 +103,251 @@ fn solve((grid, path): (Vec<Vec<u8>>, Vec<Move>), move_one: fn(&State) -> State)
     1000 * row_number + 4 * column_number + facing_number
 }
 
-fn move_one_2d<const X: usize, const Y: usize>(&State { x, y, direction }: &State) -> State {
-    match direction {
+/// Steps one tile on a map that wraps around at its edges, for a grid `width` by `height`.
+fn move_one_2d(width: usize, height: usize) -> impl Fn(&State) -> State {
+    move |&State { x, y, direction }| match direction {
         Right => State {
-            x: (x + 1) % X,
+            x: (x + 1) % width,
             y,
             direction,
         },
         Down => State {
             x,
-            y: (y + 1) % Y,
+            y: (y + 1) % height,
             direction,
         },
         Left => State {
-            x: x.checked_sub(1).unwrap_or(X - 1),
+            x: x.checked_sub(1).unwrap_or(width - 1),
             y,
             direction,
         },
         Up => State {
             x,
-            y: y.checked_sub(1).unwrap_or(Y - 1),
+            y: y.checked_sub(1).unwrap_or(height - 1),
             direction,
         },
     }
 }
 
-fn move_one_cube(&State { x, y, direction }: &State) -> State {
-    match direction {
-        Right => match (x, y) {
-            (149, 0..50) => State {
-                x: 99,
-                y: 149 - y,
-                direction: Left,
-            },
-            (99, 50..100) => State {
-                x: 100 + (y - 50),
-                y: 49,
-                direction: Up,
-            },
-            (99, 100..150) => State {
-                x: 149,
-                y: 49 - (y - 100),
-                direction: Left,
-            },
-            (49, 150..200) => State {
-                x: 50 + (y - 150),
-                y: 149,
-                direction: Up,
-            },
-            _ => State {
-                x: x + 1,
-                y,
-                direction,
-            },
-        },
-        Left => match (x, y) {
-            (50, 0..50) => State {
-                x: 0,
-                y: 149 - y,
-                direction: Right,
-            },
-            (50, 50..100) => State {
-                x: y - 50,
-                y: 100,
-                direction: Down,
-            },
-            (0, 100..150) => State {
-                x: 50,
-                y: 49 - (y - 100),
-                direction: Right,
-            },
-            (0, 150..200) => State {
-                x: 50 + (y - 150),
-                y: 0,
-                direction: Down,
-            },
-            _ => State {
-                x: x - 1,
-                y,
-                direction,
-            },
-        },
-        Down => match (x, y) {
-            (0..50, 199) => State {
-                x: x + 100,
-                y: 0,
-                direction: Down,
-            },
-            (50..100, 149) => State {
-                x: 49,
-                y: 150 + (x - 50),
-                direction: Left,
-            },
-            (100..150, 49) => State {
-                x: 99,
-                y: 50 + (x - 100),
-                direction: Left,
-            },
-            _ => State {
-                x,
-                y: y + 1,
-                direction,
-            },
-        },
-        Up => match (x, y) {
-            (0..50, 100) => State {
-                x: 50,
-                y: 50 + x,
-                direction: Right,
-            },
-            (50..100, 0) => State {
-                x: 0,
-                y: 150 + (x - 50),
-                direction: Right,
-            },
-            (100..150, 0) => State {
-                x: x - 100,
-                y: 199,
-                direction: Up,
-            },
-            _ => State {
-                x,
-                y: y - 1,
-                direction,
-            },
-        },
+const DIRECTIONS: [Direction; 4] = [Right, Down, Left, Up];
+
+type Vec3 = [i32; 3];
+
+fn neg(v: Vec3) -> Vec3 {
+    v.map(|c| -c)
+}
+
+fn dot(a: Vec3, b: Vec3) -> i32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// A face of the net once it has been folded into a cube centred on the origin: its outward
+/// normal, and the directions that right and down on the map point on the cube.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Face {
+    fn towards(&self, direction: Direction) -> Vec3 {
+        match direction {
+            Right => self.right,
+            Down => self.down,
+            Left => neg(self.right),
+            Up => neg(self.down),
+        }
+    }
+
+    /// The face next to this one on the map in `direction`, folded down along their shared edge.
+    fn fold(&self, direction: Direction) -> Face {
+        let Face {
+            normal,
+            right,
+            down,
+        } = *self;
+        match direction {
+            Right => Face {
+                normal: right,
+                right: neg(normal),
+                down,
+            },
+            Left => Face {
+                normal: neg(right),
+                right: normal,
+                down,
+            },
+            Down => Face {
+                normal: down,
+                right,
+                down: neg(normal),
+            },
+            Up => Face {
+                normal: neg(down),
+                right,
+                down: normal,
+            },
+        }
     }
 }
 
-#[allow(dead_code)]
-fn move_one_sample_cube(&State { x, y, direction }: &State) -> State {
-    match direction {
-        Right => match (x, y) {
-            (11, 0..4) => State {
-                x: 15,
-                y: 11 - y,
-                direction: Left,
-            },
-            (11, 4..8) => State {
-                x: 15 - (y - 4),
-                y: 8,
-                direction: Down,
-            },
-            (15, 8..12) => State {
-                x: 11,
-                y: 3 - (y - 8),
-                direction: Left,
-            },
-            _ => State {
-                x: x + 1,
-                y,
-                direction,
-            },
-        },
-        Left => match (x, y) {
-            (8, 0..4) => State {
-                x: y + 4,
-                y: 4,
-                direction: Down,
-            },
-            (0, 4..8) => State {
-                x: 15 - (y - 4),
-                y: 11,
-                direction: Up,
-            },
-            (8, 8..12) => State {
-                x: 7 - (y - 8),
-                y: 7,
-                direction: Up,
-            },
-            _ => State {
-                x: x - 1,
-                y,
-                direction,
-            },
-        },
-        Down => match (x, y) {
-            (0..4, 7) => State {
-                x: 11 - x,
-                y: 11,
-                direction: Up,
-            },
-            (4..8, 7) => State {
-                x: 8,
-                y: 11 - (x - 4),
-                direction: Right,
-            },
-            (8..12, 11) => State {
-                x: 3 - (x - 8),
-                y: 7,
-                direction: Up,
-            },
-            (12..16, 11) => State {
-                x: 0,
-                y: 7 - (x - 12),
-                direction: Right,
-            },
-            _ => State {
-                x,
-                y: y + 1,
-                direction,
-            },
-        },
-        Up => match (x, y) {
-            (0..4, 4) => State {
-                x: 11 - x,
-                y: 0,
-                direction: Down,
-            },
-            (4..8, 4) => State {
-                x: 8,
-                y: x - 4,
-                direction: Right,
-            },
-            (8..12, 0) => State {
-                x: 4,
-                y: 3 - (x - 8),
-                direction: Down,
-            },
-            (12..16, 4) => State {
-                x: 11,
-                y: 7 - (x - 12),
-                direction: Left,
-            },
-            _ => State {
-                x,
-                y: y - 1,
-                direction,
-            },
-        },
+/// The map folded into a cube. The faces are keyed by their block position on the map,
+/// counting in whole faces.
+#[derive(Debug)]
+struct Cube {
+    size: usize,
+    faces: HashMap<(usize, usize), Face>,
+}
+
+impl Cube {
+    /// Works out the face size from the number of tiles, finds the six faces on the map, and
+    /// folds them up starting from the first face in reading order. Fails if the map isn't the
+    /// net of a cube.
+    fn fold(grid: &[Vec<u8>]) -> Result<Cube, String> {
+        let is_tile = |x: usize, y: usize| {
+            grid.get(y)
+                .and_then(|row| row.get(x))
+                .is_some_and(|&c| c != b' ')
+        };
+        let tiles = grid.iter().flatten().filter(|&&c| c != b' ').count();
+        let size = (1..).take_while(|n| 6 * n * n <= tiles).last().unwrap_or(0);
+        if size == 0 || 6 * size * size != tiles {
+            return Err(format!("{tiles} tiles can't cover the six faces of a cube"));
+        }
+
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        let blocks: Vec<(usize, usize)> = (0..grid.len().div_ceil(size))
+            .flat_map(|by| (0..width.div_ceil(size)).map(move |bx| (bx, by)))
+            .filter(|&(bx, by)| is_tile(bx * size, by * size))
+            .collect();
+        for &(bx, by) in &blocks {
+            let filled =
+                (0..size).all(|j| (0..size).all(|i| is_tile(bx * size + i, by * size + j)));
+            if !filled {
+                return Err(format!(
+                    "The face at block ({bx}, {by}) isn't {size}x{size}"
+                ));
+            }
+        }
+
+        let mut faces = HashMap::new();
+        let mut queue = VecDeque::new();
+        if let Some(&first) = blocks.first() {
+            let face = Face {
+                normal: [0, 0, 1],
+                right: [1, 0, 0],
+                down: [0, 1, 0],
+            };
+            faces.insert(first, face);
+            queue.push_back((first, face));
+        }
+        while let Some(((bx, by), face)) = queue.pop_front() {
+            for direction in DIRECTIONS {
+                let next = match direction {
+                    Right => Some((bx + 1, by)),
+                    Down => Some((bx, by + 1)),
+                    Left => bx.checked_sub(1).map(|bx| (bx, by)),
+                    Up => by.checked_sub(1).map(|by| (bx, by)),
+                };
+                if let Some(next) = next.filter(|next| blocks.contains(next)) {
+                    if let Entry::Vacant(entry) = faces.entry(next) {
+                        let folded = face.fold(direction);
+                        entry.insert(folded);
+                        queue.push_back((next, folded));
+                    }
+                }
+            }
+        }
+
+        let normals: HashSet<Vec3> = faces.values().map(|face| face.normal).collect();
+        if faces.len() != 6 || normals.len() != 6 {
+            return Err("The map doesn't fold into a cube".to_string());
+        }
+        Ok(Cube { size, faces })
+    }
+
+    /// Steps one tile, crossing onto the adjoining face of the cube when walking off an edge
+    /// of the net.
+    fn move_one(&self, &State { x, y, direction }: &State) -> State {
+        let n = self.size;
+        let next = match direction {
+            Right => Some((x + 1, y)),
+            Down => Some((x, y + 1)),
+            Left => x.checked_sub(1).map(|x| (x, y)),
+            Up => y.checked_sub(1).map(|y| (x, y)),
+        };
+        if let Some((x, y)) = next.filter(|&(x, y)| self.faces.contains_key(&(x / n, y / n))) {
+            return State { x, y, direction };
+        }
+
+        // Work in doubled coordinates so that tile centres are whole numbers: the cube spans
+        // -n..=n on each axis, and a tile's centre sits on the surface at an odd offset.
+        let face = self.faces[&(x / n, y / n)];
+        let (n3, i, j) = (n as i32, (x % n) as i32, (y % n) as i32);
+        let axis = |v: Vec3, k: i32| v.map(|c| c * k);
+        let centre = [
+            axis(face.normal, n3),
+            axis(face.right, 2 * i + 1 - n3),
+            axis(face.down, 2 * j + 1 - n3),
+        ];
+        let heading = face.towards(direction);
+        // Over the edge and down the side of the cube onto the face we're heading towards.
+        let centre: Vec3 = std::array::from_fn(|k| {
+            centre.iter().map(|v| v[k]).sum::<i32>() + heading[k] - face.normal[k]
+        });
+
+        let (&(bx, by), target) = self
+            .faces
+            .iter()
+            .find(|(_, target)| target.normal == heading)
+            .unwrap();
+        let i = (dot(centre, target.right) + n3 - 1) / 2;
+        let j = (dot(centre, target.down) + n3 - 1) / 2;
+        let direction = DIRECTIONS
+            .into_iter()
+            .find(|&d| target.towards(d) == neg(face.normal))
+            .unwrap();
+        State {
+            x: bx * n + i as usize,
+            y: by * n + j as usize,
+            direction,
+        }
     }
 }
 
-fn solve_p1(input: &str) -> usize {
-    let data = parse_input(input);
-    solve(data, move_one_2d::<150, 200>)
+fn solve_p1(data: &(Vec<Vec<u8>>, Vec<Move>)) -> usize {
+    let width = data.0.iter().map(|row| row.len()).max().unwrap();
+    let height = data.0.len();
+    solve(data, move_one_2d(width, height))
 }
 
-fn solve_p2(input: &str) -> usize {
-    let data = parse_input(input);
-    solve(data, move_one_cube)
+fn solve_p2(data: &(Vec<Vec<u8>>, Vec<Move>)) -> usize {
+    let cube = Cube::fold(&data.0).expect("The map should be the net of a cube");
+    solve(data, |state| cube.move_one(state))
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day22;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day22 {
+    type Input<'a> = (Vec<Vec<u8>>, Vec<Move>);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (Vec<Vec<u8>>, Vec<Move>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2022, 22);
+    aoc_core::run::<Day22>(&input);
 }
 
 #[cfg(test)]
@@ -363,10 +358,61 @@ mod tests {
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
         let data = parse_input(&input);
-        let answer = solve(data, move_one_2d::<16, 12>);
-        assert_eq!(answer, 6032);
-        let data = parse_input(&input);
-        let answer = solve(data, move_one_sample_cube);
-        assert_eq!(answer, 5031);
+        assert_eq!(solve_p1(&data), 6032);
+        assert_eq!(solve_p2(&data), 5031);
+    }
+
+    /// The eleven nets of a cube, one character per face.
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    fn expand(net: &str, size: usize) -> Vec<Vec<u8>> {
+        net.lines()
+            .flat_map(|line| {
+                let row: Vec<u8> = line
+                    .bytes()
+                    .flat_map(|c| vec![if c == b'#' { b'.' } else { b' ' }; size])
+                    .collect();
+                vec![row; size]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_all_nets_fold() {
+        for net in NETS {
+            let size = 3;
+            let grid = expand(net, size);
+            let cube = Cube::fold(&grid).unwrap();
+            assert_eq!(cube.size, size);
+            // Walking straight ahead all the way round the cube ends up back where we started.
+            for (y, row) in grid.iter().enumerate() {
+                for x in (0..row.len()).filter(|&x| row[x] == b'.') {
+                    for direction in DIRECTIONS {
+                        let start = State { x, y, direction };
+                        let end = (0..4 * size).fold(start, |state, _| cube.move_one(&state));
+                        assert_eq!(end, start, "net:\n{net}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_not_a_cube() {
+        assert!(Cube::fold(&expand("######", 2)).is_err());
+        assert!(Cube::fold(&expand("###\n###", 2)).is_err());
+        assert!(Cube::fold(&expand("#####", 2)).is_err());
     }
 }
@@ -1,52 +1,6 @@
-#![feature(portable_simd)]
-#![feature(iter_map_windows)]
-
-use std::{ops::Range, simd::u8x32};
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct BitGrid([u8x32; 160]);
-
-fn shift_west(&row: &u8x32) -> u8x32 {
-    (row >> u8x32::splat(1)) | (row.rotate_elements_left::<1>() << u8x32::splat(7))
-}
-
-fn shift_east(&row: &u8x32) -> u8x32 {
-    (row << u8x32::splat(1)) | (row.rotate_elements_right::<1>() >> u8x32::splat(7))
-}
-
-fn propose(
-    [nw, n, ne]: &[u8x32; 3],
-    [w, cur, e]: &[u8x32; 3],
-    [sw, s, se]: &[u8x32; 3],
-    priority: [Direction; 4],
-) -> [u8x32; 4] {
-    let mut propositions = [*cur; 4];
-    let mut not_chosen = nw | n | ne | w | e | sw | s | se;
-    for d in priority {
-        let (row, dir_available) = match d {
-            North => (&mut propositions[0], !(ne | n | nw)),
-            South => (&mut propositions[1], !(se | s | sw)),
-            West => (&mut propositions[2], !(nw | w | sw)),
-            East => (&mut propositions[3], !(ne | e | se)),
-        };
-        *row &= dir_available & not_chosen;
-        not_chosen &= !dir_available;
-    }
-    propositions
-}
-
-fn collide_proposals(
-    [_, south, _, _]: &[u8x32; 4],
-    [_, _, west, east]: &[u8x32; 4],
-    [north, _, _, _]: &[u8x32; 4],
-) -> [u8x32; 4] {
-    [
-        north & !*south,
-        south & !*north,
-        shift_west(west) & !shift_east(east),
-        shift_east(east) & !shift_west(west),
-    ]
-}
+use aoc_core::automaton::{BitPlane, Cell, Neighborhood};
+use aoc_core::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 enum Direction {
@@ -56,146 +10,138 @@ enum Direction {
     East,
 }
 
-use itertools::{chain, Itertools};
 use Direction::*;
 
-impl BitGrid {
-    fn new() -> Self {
-        Self([Default::default(); 160])
-    }
-
-    fn run_simulation(&mut self, max_rounds: usize) -> Option<usize> {
-        let mut priority = [North, South, West, East];
-        for round in 0..max_rounds {
-            let moved;
-            (*self, moved) = self.play_round(priority);
-            if !moved {
-                return Some(round + 1);
-            }
-            priority.rotate_left(1);
+impl Direction {
+    fn offset(self) -> Cell<2> {
+        match self {
+            North => [-1, 0],
+            South => [1, 0],
+            West => [0, -1],
+            East => [0, 1],
         }
-        None
-    }
-
-    fn play_round(&self, priority: [Direction; 4]) -> (Self, bool) {
-        let mut new_self = self.clone();
-        let mut moved = false;
-        let zeros = [Default::default(); 2];
-        chain!(&zeros, &self.0, &zeros)
-            .map(|row| [shift_east(row), *row, shift_west(row)])
-            .map_windows(|[above, cur, below]| propose(above, cur, below, priority))
-            .map_windows(|[above, cur, below]| collide_proposals(above, cur, below))
-            .enumerate()
-            .for_each(|(i, [from_south, from_north, from_east, from_west])| {
-                let destinations = from_north | from_south | from_west | from_east;
-                if destinations == u8x32::splat(0) {
-                    return;
-                }
-                moved = true;
-                new_self.0[i + 1] &= !from_south;
-                new_self.0[i - 1] &= !from_north;
-                new_self.0[i] &= !shift_west(&from_west);
-                new_self.0[i] &= !shift_east(&from_east);
-                new_self.0[i] |= destinations;
-            });
-        (new_self, moved)
-    }
-
-    fn insert(&mut self, row: usize, col: usize) {
-        self.0[row][col / 8] |= 1 << (col % 8);
-    }
-
-    fn get(&self, row: usize, col: usize) -> bool {
-        self.0[row][col / 8] & (1 << (col % 8)) != 0
     }
 
-    fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
-        (0..160)
-            .cartesian_product(0..256)
-            .filter(|&(row, col)| self.get(row, col))
+    /// The three neighbors that must be clear to move this way
+    fn clear(self) -> [Cell<2>; 3] {
+        match self {
+            North => [[-1, -1], [-1, 0], [-1, 1]],
+            South => [[1, -1], [1, 0], [1, 1]],
+            West => [[-1, -1], [0, -1], [1, -1]],
+            East => [[-1, 1], [0, 1], [1, 1]],
+        }
     }
+}
 
-    fn bounds(&self) -> (Range<usize>, Range<usize>) {
-        let mut min_row = usize::MAX;
-        let mut max_row = usize::MIN;
-        let mut min_col = usize::MAX;
-        let mut max_col = usize::MIN;
-        for (row, col) in self.iter() {
-            min_row = min_row.min(row);
-            max_row = max_row.max(row);
-            min_col = min_col.min(col);
-            max_col = max_col.max(col);
+fn run_simulation(elves: &mut BitPlane, max_rounds: usize) -> Option<usize> {
+    let mut priority = [North, South, West, East];
+    for round in 0..max_rounds {
+        if !play_round(elves, priority) {
+            return Some(round + 1);
         }
-        (min_row..max_row + 1, min_col..max_col + 1)
+        priority.rotate_left(1);
     }
+    None
+}
 
-    fn len(&self) -> usize {
-        self.0
+/// Moves the elves one round, returning whether any of them moved
+fn play_round(elves: &mut BitPlane, priority: [Direction; 4]) -> bool {
+    // For each neighbor, the cells with an elf there
+    let neighbors: Vec<(Cell<2>, BitPlane)> = Neighborhood::moore(1)
+        .offsets()
+        .iter()
+        .map(|&[row, col]| ([row, col], elves.shifted([-row, -col])))
+        .collect();
+    let occupied = |offsets: &[Cell<2>]| {
+        neighbors
             .iter()
-            .flat_map(|x| x.as_array())
-            .map(|x| x.count_ones() as usize)
-            .sum()
+            .filter(|(offset, _)| offsets.contains(offset))
+            .fold(
+                BitPlane::new(elves.rows(), elves.cols()),
+                |cells, (_, elf)| cells | elf,
+            )
+    };
+
+    // The elves with someone around them, who haven't picked a direction yet
+    let mut undecided = occupied(Neighborhood::moore(1).offsets()) & &*elves;
+    let mut proposed: [BitPlane; 4] =
+        std::array::from_fn(|_| BitPlane::new(elves.rows(), elves.cols()));
+    for d in priority {
+        let clear = !occupied(&d.clear());
+        proposed[d as usize] = (&undecided & &clear).shifted(d.offset());
+        undecided &= !clear;
     }
 
-    // for debugging
-    #[allow(dead_code)]
-    fn print(&self) {
-        let (rows, cols) = self.bounds();
-        for row in rows {
-            for col in cols.clone() {
-                if self.get(row, col) {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
+    // Only elves coming from opposite sides can propose the same cell, and then neither moves
+    let [north, south, west, east] = &proposed;
+    let open = !((north & south) | (west & east));
+    let mut moved = false;
+    for (d, to) in [North, South, West, East].into_iter().zip(proposed) {
+        let to = to & &open;
+        moved |= !to.is_empty();
+        let [row, col] = d.offset();
+        *elves &= !to.shifted([-row, -col]);
+        *elves |= to;
+    }
+    moved
+}
+
+/// The bounding rectangle of the elves, as its number of cells
+fn area(elves: &BitPlane) -> usize {
+    let (mut min, mut max) = ([i32::MAX; 2], [i32::MIN; 2]);
+    for cell in elves.cells() {
+        for axis in 0..2 {
+            min[axis] = min[axis].min(cell[axis]);
+            max[axis] = max[axis].max(cell[axis]);
         }
-        println!();
     }
+    ((max[0] - min[0] + 1) * (max[1] - min[1] + 1)) as usize
 }
 
-fn parse_input(input: &str) -> BitGrid {
-    let mut grid = BitGrid::new();
+fn parse_input(input: &str) -> BitPlane {
+    // Room for the elves to spread out around the grove
+    let mut grove = BitPlane::new(160, 256);
     input.lines().enumerate().for_each(|(row, line)| {
         line.chars()
             .enumerate()
             .filter(|&(_, c)| c == '#')
-            .for_each(|(col, _)| grid.insert(row + 24, col + 72));
+            .for_each(|(col, _)| grove.set([row as i32 + 24, col as i32 + 72], true));
     });
-    grid
+    grove
 }
 
-// fn parse_input(input: &str) -> Matrix<char> {
-//     Matrix::from_rows(input.lines().map(|line| line.chars().collect::<Vec<_>>())).unwrap()
-// }
-
-fn solve_p1(input: &str) -> usize {
-    let mut grove = parse_input(input);
-    grove.run_simulation(10);
-    let (rows, cols) = grove.bounds();
-    rows.len() * cols.len() - grove.len()
+fn solve_p1(grove: &BitPlane) -> usize {
+    let mut grove = grove.clone();
+    run_simulation(&mut grove, 10);
+    area(&grove) - grove.count()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut grove = parse_input(input);
-    grove
-        .run_simulation(10000)
-        .expect("not done within 10000 rounds")
+fn solve_p2(grove: &BitPlane) -> usize {
+    let mut grove = grove.clone();
+    run_simulation(&mut grove, 10000).expect("not done within 10000 rounds")
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = BitPlane;
+
+    fn parse(input: &str) -> BitPlane {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2022, 23);
+    aoc_core::run::<Day23>(&input);
 }
 
 #[cfg(test)]
@@ -205,9 +151,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let grove = parse_input(&input);
+        let answer = solve_p1(&grove);
         assert_eq!(answer, 110);
-        let answer = solve_p2(&input);
-        assert_eq!(answer, 0);
+        let answer = solve_p2(&grove);
+        assert_eq!(answer, 20);
     }
 }
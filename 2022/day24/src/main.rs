@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
 fn gcd(a: i32, b: i32) -> i32 {
     if b == 0 {
@@ -200,18 +202,27 @@ fn solve_p2(input: &str) -> i32 {
     valley.find_shortest_path(valley.start, valley.goal, time2)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = &'a str;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2022, 24);
+    aoc_core::run::<Day24>(&input);
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use maplit::hashmap;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct Snafu {
@@ -38,22 +40,36 @@ fn parse_input(input: &str) -> Vec<isize> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> String {
-    let data = parse_input(input);
+fn solve_p1(data: &[isize]) -> String {
     let value = data.iter().sum();
     to_snafu(value)
 }
 
-fn solve_p2(_input: &str) -> i32 {
+fn solve_p2(_data: &[isize]) -> i32 {
     0
 }
 
+struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<isize>;
+
+    fn parse(input: &str) -> Vec<isize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2022, 25);
+    aoc_core::run::<Day25>(&input);
 }
 
 #[cfg(test)]
@@ -76,9 +92,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let data = parse_input(INPUT);
+        let answer = solve_p1(&data);
         assert_eq!(answer, "2=-1=0");
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 0);
     }
 
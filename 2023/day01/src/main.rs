@@ -1,7 +1,7 @@
+use aoc_core::Solution;
 use log::debug;
 use regex::Regex;
-use std::error::Error;
-use std::fs;
+use std::fmt::Display;
 
 fn calibration_sum(text: &str) -> u32 {
     text.lines().map(extract_calib_value).sum()
@@ -63,13 +63,27 @@ fn str_to_digit(digit: Option<&str>) -> u32 {
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let text = fs::read_to_string("input.txt")?;
-    let sum = calibration_sum_digits(&text);
-    println!("Part 1: Calibration sum using only digits: {sum}");
-    let sum = calibration_sum(&text);
-    println!("Part 2: Calibration sum: {sum}");
-    Ok(())
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        calibration_sum_digits(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        calibration_sum(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 1);
+    aoc_core::run::<Day01>(&input);
 }
 
 #[cfg(test)]
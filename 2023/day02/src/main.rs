@@ -1,4 +1,5 @@
-use std::{error::Error, fs, str::FromStr};
+use aoc_core::Solution;
+use std::{fmt::Display, str::FromStr};
 
 #[derive(Debug, Default)]
 struct Game {
@@ -92,6 +93,12 @@ fn parse_games(data: &str) -> Vec<Game> {
         .collect()
 }
 
+const TARGET: CubeMix = CubeMix {
+    blue: 14,
+    green: 13,
+    red: 12,
+};
+
 fn filter_games<'a>(games: &'a [Game], target: &CubeMix) -> Vec<&'a Game> {
     let filtered: Vec<&Game> = games
         .iter()
@@ -110,23 +117,27 @@ fn power_sum(games: &[Game]) -> u32 {
     games.iter().map(|g| g.power).sum()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let target = CubeMix {
-        blue: 14,
-        green: 13,
-        red: 12,
-    };
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
 
-    let text = fs::read_to_string("input.txt")?;
-    let games = parse_games(&text);
-    let filtered = filter_games(&games, &target);
-    let sum1 = id_sum(&filtered);
-    let sum2 = power_sum(&games);
+    fn parse(input: &str) -> Vec<Game> {
+        parse_games(input)
+    }
 
-    println!("Part 1: {sum1}");
-    println!("Part 2: {sum2}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        id_sum(&filter_games(input, &TARGET))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        power_sum(input)
+    }
+}
 
-    Ok(())
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 2);
+    aoc_core::run::<Day02>(&input);
 }
 
 #[cfg(test)]
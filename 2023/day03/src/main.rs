@@ -1,5 +1,6 @@
+use aoc_core::Solution;
 use regex::Regex;
-use std::{error::Error, fs};
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
 struct PartNumber {
@@ -118,17 +119,27 @@ fn get_gears(engine: &str) -> Vec<u32> {
     gears
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let engine = fs::read_to_string("input.txt")?;
-    let part_numbers = get_part_numbers(&get_parts(&engine));
-    let sum: u32 = part_numbers.iter().sum();
-    println!("Part 1 sum: {sum}");
+struct Day03;
 
-    let gear_ratios = get_gears(&engine);
-    let sum: u32 = gear_ratios.iter().sum();
-    println!("Part 1 sum: {sum}");
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        get_part_numbers(&get_parts(input)).iter().sum::<u32>()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        get_gears(input).iter().sum::<u32>()
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 3);
+    aoc_core::run::<Day03>(&input);
 }
 
 #[cfg(test)]
@@ -1,7 +1,8 @@
+use aoc_core::Solution;
 use regex::Regex;
-use std::{collections::HashSet, error::Error, fs, str::FromStr};
+use std::{collections::HashSet, fmt::Display, str::FromStr};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Card {
     winners: HashSet<u8>,
     mine: HashSet<u8>,
@@ -78,16 +79,29 @@ fn process_scratchcards(cards: &mut Vec<Card>) {
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let mut cards = get_cards(&input);
-    let winnings = calculate_winnings(&cards);
-    println!("Part 1 Winnings: {winnings}");
-    process_scratchcards(&mut cards);
-    let count: u32 = cards.iter().map(|c| c.count).sum();
-    println!("Part 2 Total Card Count: {count}");
+struct Day04;
 
-    Ok(())
+impl Solution for Day04 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Vec<Card> {
+        get_cards(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        calculate_winnings(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut cards = input.clone();
+        process_scratchcards(&mut cards);
+        cards.iter().map(|c| c.count).sum::<u32>()
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 4);
+    aoc_core::run::<Day04>(&input);
 }
 
 #[cfg(test)]
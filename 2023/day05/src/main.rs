@@ -1,13 +1,7 @@
+use aoc_core::{IntervalSet, Solution};
 use log::debug;
 use regex::Regex;
-use std::{
-    cmp::{max, min},
-    collections::HashMap,
-    error::Error,
-    fs,
-    ops::Range,
-    str::FromStr,
-};
+use std::{collections::HashMap, fmt::Display, ops::Range, str::FromStr};
 
 #[derive(Debug, Default)]
 struct Seeds(Vec<usize>);
@@ -47,20 +41,7 @@ impl FromStr for Seeds {
 }
 
 #[derive(Debug, Default)]
-struct SeedRanges(Vec<Range<usize>>);
-
-impl std::ops::Deref for SeedRanges {
-    type Target = Vec<Range<usize>>;
-    fn deref(&self) -> &Vec<Range<usize>> {
-        &self.0
-    }
-}
-
-impl std::ops::DerefMut for SeedRanges {
-    fn deref_mut(&mut self) -> &mut Vec<Range<usize>> {
-        &mut self.0
-    }
-}
+struct SeedRanges(IntervalSet<usize>);
 
 impl FromStr for SeedRanges {
     type Err = ParseError;
@@ -75,15 +56,7 @@ impl FromStr for SeedRanges {
                 .split_whitespace()
                 .map(|x| x.parse::<usize>().unwrap())
                 .collect();
-            let seed_ranges = SeedRanges(
-                elements
-                    .chunks(2)
-                    .map(|r| Range {
-                        start: r[0],
-                        end: r[0] + r[1] - 1,
-                    })
-                    .collect(),
-            );
+            let seed_ranges = SeedRanges(elements.chunks(2).map(|r| r[0]..r[0] + r[1]).collect());
 
             return Ok(seed_ranges);
         }
@@ -106,56 +79,14 @@ impl Map {
         }
     }
 
-    fn convert_ranges(&self, input: &[Range<usize>]) -> Vec<Range<usize>> {
-        let mut output = Vec::with_capacity(100);
-        // Iterate over each input element.
-        // Convert that range. It may involve either:
-        // a. No overlap with mapping entries. => Direct copy, no transform.
-        // b. Contained entirely within a mapping entry. => Transform based on the mapping.
-        // c. Partial overlap with 1 or more mappings. Partition the input range and transform the
-        //    piece that overlaps with the mapping. Repeat for all parts of overlap.
-        // b is a special case of c.
-        let mut remaining = input.to_owned();
-        for mapping in &self.entries {
-            let mut new_remaining = Vec::with_capacity(100);
-            for r in remaining {
-                let mapping_range = Range {
-                    start: mapping.source,
-                    end: mapping.source + mapping.length - 1,
-                };
-                if is_overlap(&r, &mapping_range) {
-                    // Partition r into overlapping and non-overlapping partitions
-                    let overlap = Range {
-                        start: max(r.start, mapping_range.start),
-                        end: min(r.end, mapping_range.end),
-                    };
-                    let transformed_overlap = Range {
-                        start: mapping.convert(overlap.start).unwrap(),
-                        end: mapping.convert(overlap.end).unwrap(),
-                    };
-                    output.push(transformed_overlap);
-                    if r.start != overlap.start {
-                        let before = Range {
-                            start: r.start,
-                            end: overlap.start - 1,
-                        };
-                        new_remaining.push(before);
-                    }
-                    if r.end != overlap.end {
-                        let after = Range {
-                            start: overlap.end + 1,
-                            end: r.end,
-                        };
-                        new_remaining.push(after);
-                    }
-                } else {
-                    new_remaining.push(r.clone());
-                }
-            }
-            remaining = new_remaining;
-        }
-        output.extend(remaining);
-        output
+    /// Converts every value in the set, splitting its intervals wherever the mapping entries
+    /// start and end. Values outside all the entries are left as they are.
+    fn convert_ranges(&self, input: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let sources: Vec<Range<usize>> = self.entries.iter().map(Mapping::source_range).collect();
+        input.remap(&sources, |i, r| {
+            let mapping = &self.entries[i];
+            r.start - mapping.source + mapping.dest..r.end - mapping.source + mapping.dest
+        })
     }
 }
 
@@ -176,10 +107,6 @@ impl FromStr for Map {
     }
 }
 
-fn is_overlap(r1: &Range<usize>, r2: &Range<usize>) -> bool {
-    r1.start <= r2.end && r2.start <= r1.end
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 struct Mapping {
     source: usize,
@@ -197,6 +124,10 @@ impl Mapping {
         }
     }
 
+    fn source_range(&self) -> Range<usize> {
+        self.source..self.source + self.length
+    }
+
     fn in_range(&self, value: usize) -> bool {
         self.source <= value && value <= self.source + self.length
     }
@@ -306,9 +237,7 @@ fn parse_input_part2(input: &str) -> (SeedRanges, HashMap<String, Map>) {
     (seeds, maps)
 }
 
-fn get_seed_locations_part2(seeds: &SeedRanges, maps: &HashMap<String, Map>) -> Vec<Range<usize>> {
-    let mut seed_loc_map = Vec::with_capacity(100);
-
+fn get_seed_locations_part2(seeds: &SeedRanges, maps: &HashMap<String, Map>) -> IntervalSet<usize> {
     let mut map = maps.get("seed").unwrap();
     let mut ranges = map.convert_ranges(&seeds.0);
     loop {
@@ -318,9 +247,7 @@ fn get_seed_locations_part2(seeds: &SeedRanges, maps: &HashMap<String, Map>) ->
         map = maps.get(&map.to).unwrap();
         ranges = map.convert_ranges(&ranges);
     }
-    seed_loc_map.append(&mut ranges);
-
-    seed_loc_map
+    ranges
 }
 
 fn get_lowest_location(map: &HashMap<usize, usize>) -> usize {
@@ -330,23 +257,33 @@ fn get_lowest_location(map: &HashMap<usize, usize>) -> usize {
         .unwrap()
 }
 
-fn get_lowest_location_from_ranges(locations: &[Range<usize>]) -> usize {
-    locations.iter().map(|r| r.start).min().unwrap()
+fn get_lowest_location_from_ranges(locations: &IntervalSet<usize>) -> usize {
+    locations.min().unwrap()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let (seeds, maps) = parse_input(&input);
-    let seed_to_loc = get_seed_locations(&seeds, &maps);
-    let lowest = get_lowest_location(&seed_to_loc);
-    println!("Part 1: {lowest}");
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = &'a str;
 
-    let (seeds, maps) = parse_input_part2(&input);
-    let seed_to_loc = get_seed_locations_part2(&seeds, &maps);
-    let nearest = get_lowest_location_from_ranges(&seed_to_loc);
-    println!("Part 1: {nearest}");
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let (seeds, maps) = parse_input(input);
+        get_lowest_location(&get_seed_locations(&seeds, &maps))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let (seeds, maps) = parse_input_part2(input);
+        get_lowest_location_from_ranges(&get_seed_locations_part2(&seeds, &maps))
+    }
+}
 
-    Ok(())
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 5);
+    aoc_core::run::<Day05>(&input);
 }
 
 #[cfg(test)]
@@ -403,11 +340,8 @@ mod tests {
     fn test_seed_ranges_parse() {
         let input = "seeds: 79 14 55 13";
         let seeds = SeedRanges::from_str(&input).unwrap();
-        assert_eq!(seeds.len(), 2);
-        assert_eq!(
-            seeds.0,
-            vec![Range { start: 79, end: 92 }, Range { start: 55, end: 67 }]
-        );
+        assert_eq!(seeds.0.ranges().len(), 2);
+        assert_eq!(seeds.0.ranges(), &[55..68, 79..93]);
     }
 
     #[test]
@@ -485,8 +419,7 @@ mod tests {
             52 50 48";
         let (seeds, maps) = parse_input_part2(input);
         let seed_to_loc = get_seed_locations_part2(&seeds, &maps);
-        assert!(seed_to_loc.contains(&Range { start: 49, end: 49 }));
-        assert!(seed_to_loc.contains(&Range { start: 52, end: 64 }));
+        assert_eq!(seed_to_loc.ranges(), &[49..50, 52..65]);
         let nearest = get_lowest_location_from_ranges(&seed_to_loc);
         assert_eq!(nearest, 49);
     }
@@ -500,12 +433,7 @@ mod tests {
             40 50 48";
         let (seeds, maps) = parse_input_part2(input);
         let seed_to_loc = get_seed_locations_part2(&seeds, &maps);
-        assert!(seed_to_loc.contains(&Range { start: 86, end: 87 }));
-        assert!(seed_to_loc.contains(&Range { start: 50, end: 51 }));
-        assert!(seed_to_loc.contains(&Range {
-            start: 100,
-            end: 105
-        }));
+        assert_eq!(seed_to_loc.ranges(), &[50..52, 86..88, 100..106]);
         let nearest = get_lowest_location_from_ranges(&seed_to_loc);
         assert_eq!(nearest, 50);
     }
@@ -1,5 +1,6 @@
-use std::{fs, iter::zip};
+use std::{fmt::Display, iter::zip};
 
+use aoc_core::Solution;
 use regex::Regex;
 
 struct Race {
@@ -87,15 +88,30 @@ fn parse_distance(input: &str) -> usize {
         .unwrap()
 }
 
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        parse_input(input)
+            .iter()
+            .map(ways_to_win)
+            .product::<usize>()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        ways_to_win(&parse_input2(input))
+    }
+}
+
 fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-    let races = parse_input(&input);
-    let product: usize = races.iter().map(ways_to_win).product();
-    println!("Part 1: {product}");
-
-    let race = parse_input2(&input);
-    let ways = ways_to_win(&race);
-    println!("Part 2: {ways}");
+    let input = aoc_core::puzzle_input(2023, 6);
+    aoc_core::run::<Day06>(&input);
 }
 
 #[cfg(test)]
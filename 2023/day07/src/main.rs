@@ -1,4 +1,5 @@
-use std::{error::Error, fs, str::FromStr};
+use aoc_core::Solution;
+use std::{fmt::Display, str::FromStr};
 
 #[derive(Debug, PartialEq)]
 pub struct ParseError;
@@ -437,19 +438,31 @@ pub mod part2 {
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let mut game = part1::Game::from_str(&input).unwrap();
-    game.score();
-    let winnings = game.winnings();
-    println!("Part 1: Winnings = {winnings}");
+struct Day07;
 
-    let mut game = part2::Game::from_str(&input).unwrap();
-    game.score();
-    let winnings = game.winnings();
-    println!("Part 2: Winnings = {winnings}");
+impl Solution for Day07 {
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        let mut game = part1::Game::from_str(input).unwrap();
+        game.score();
+        game.winnings()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let mut game = part2::Game::from_str(input).unwrap();
+        game.score();
+        game.winnings()
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 7);
+    aoc_core::run::<Day07>(&input);
 }
 
 #[cfg(test)]
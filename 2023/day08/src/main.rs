@@ -1,8 +1,8 @@
-use std::{error::Error, fs};
-
+use aoc_core::{cycle::find_repeat, Solution};
 use fnv::FnvHashMap;
 use num::Integer;
 use regex::Regex;
+use std::fmt::Display;
 
 struct Map<'a> {
     directions: Vec<char>,
@@ -46,10 +46,35 @@ impl Map<'_> {
         steps
     }
 
+    /// How often the ghost starting at `start` is on a node ending in Z. The ghost's state is
+    /// its node and where it is in the directions, so it ends up going round a loop; the maps
+    /// are made so that the loop passes the Z nodes at every multiple of the step where the
+    /// ghost first reaches one.
+    fn ghost_period(&self, start: &str) -> usize {
+        let len = self.directions.len();
+        let repeat = find_repeat((start, 0), |&(node, i)| {
+            (self.network[node].walk(self.directions[i]), (i + 1) % len)
+        });
+        let last = repeat.states.len() - 1;
+        let ends: Vec<usize> = (1..last)
+            .filter(|&step| repeat.states[step].0.ends_with('Z'))
+            .collect();
+        let period = *ends.first().expect("The ghost never reaches a Z node");
+        assert!(
+            repeat.cycle.length.is_multiple_of(period)
+                && ends.iter().copied().eq((period..last).step_by(period)),
+            "The ghost from {start} doesn't reach Z nodes at regular intervals"
+        );
+        period
+    }
+
     fn multi_walk(&self) -> usize {
-        let start: Vec<_> = self.network.keys().filter(|k| k.ends_with('A')).collect();
-        let distances: Vec<usize> = start.iter().map(|&s| self.walk(s, "Z")).collect();
-        distances.into_iter().reduce(|acc, d| acc.lcm(&d)).unwrap()
+        self.network
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|start| self.ghost_period(start))
+            .reduce(|acc, period| acc.lcm(&period))
+            .unwrap()
     }
 }
 
@@ -104,16 +129,27 @@ impl Node<'_> {
 #[derive(Debug, PartialEq, PartialOrd)]
 struct ParseError;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let map = parse_input(&input);
-    let steps = map.walk("AAA", "ZZZ");
-    println!("Part 1: Steps = {steps}");
+struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Map<'a>;
 
-    let steps = map.multi_walk();
-    println!("Part 2: Steps = {steps}");
+    fn parse(input: &str) -> Map<'_> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.walk("AAA", "ZZZ")
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input.multi_walk()
+    }
+}
 
-    Ok(())
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 8);
+    aoc_core::run::<Day08>(&input);
 }
 
 #[cfg(test)]
@@ -174,6 +210,8 @@ mod tests {
     fn test_sample_map_walk_p2() {
         let input = get_input2();
         let map = parse_input(&input);
+        assert_eq!(map.ghost_period("11A"), 2);
+        assert_eq!(map.ghost_period("22A"), 3);
         let steps = map.multi_walk();
         assert_eq!(steps, 6);
     }
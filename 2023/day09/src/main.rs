@@ -1,4 +1,5 @@
-use std::{error::Error, fs};
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn compute_diff(data: &[i64]) -> Vec<i64> {
     data.iter()
@@ -48,16 +49,27 @@ fn parse_input(input: &str) -> Vec<Vec<i64>> {
         .collect()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let data = parse_input(&input);
-    let sum: i64 = data.iter().map(|x| get_next_value(x)).sum();
-    println!("Part 1: {sum}");
+struct Day09;
 
-    let sum: i64 = data.iter().map(|x| get_prev_value(x)).sum();
-    println!("Part 2: {sum}");
+impl Solution for Day09 {
+    type Input<'a> = Vec<Vec<i64>>;
 
-    Ok(())
+    fn parse(input: &str) -> Vec<Vec<i64>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.iter().map(|x| get_next_value(x)).sum::<i64>()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input.iter().map(|x| get_prev_value(x)).sum::<i64>()
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 9);
+    aoc_core::run::<Day09>(&input);
 }
 
 #[cfg(test)]
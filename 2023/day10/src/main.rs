@@ -1,4 +1,5 @@
-use std::{error::Error, fs};
+use aoc_core::{geometry::Polygon, Solution};
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct Grid(Vec<Vec<char>>);
@@ -174,40 +175,40 @@ impl Grid {
     }
 
     fn enclosed(&self, points: &[Point]) -> usize {
-        let area = interior_area(points);
-
-        // Picks Theorem - find the number of points in a shape given its area
-        area - points.len() / 2 + 1
+        // The loop runs through the centres of its tiles, so the tiles it encloses are the
+        // lattice points inside it
+        let vertices = points
+            .iter()
+            .map(|p| aoc_core::Point::new(p.0 as isize, p.1 as isize))
+            .collect();
+        Polygon::new(vertices).interior_points()
     }
 }
 
-fn interior_area(points: &[Point]) -> usize {
-    let mut padded_points = Vec::from(points);
-    padded_points.push(points[0]);
-
-    let area: i32 = padded_points
-        .iter()
-        .zip(padded_points.iter().skip(1))
-        .map(|(p1, p2)| (p1.0 as i32) * (p2.1 as i32) - (p2.0 as i32) * (p1.1 as i32))
-        .sum();
-    (area.abs() / 2) as usize
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 struct Point(usize, usize);
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let grid = Grid::from(input.as_str());
-    println!("Start: {:?}", grid.find_start());
-    let longest_path = grid.walk();
-    let furthest_away = longest_path.len() / 2;
-    println!("Part 1: {furthest_away}");
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Grid;
 
-    let num_interior_points = grid.enclosed(&longest_path);
-    println!("Part 2: {num_interior_points}");
+    fn parse(input: &str) -> Grid {
+        Grid::from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        input.walk().len() / 2
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        input.enclosed(&input.walk())
+    }
+}
 
-    Ok(())
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 10);
+    aoc_core::run::<Day10>(&input);
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
@@ -1,9 +1,9 @@
+use aoc_core::Solution;
 use grid::Grid;
 use itertools::Itertools;
 use std::{
     cmp::{max, min},
-    error::Error,
-    fs,
+    fmt::Display,
 };
 
 fn parse_input(input: &str) -> Grid<char> {
@@ -78,11 +78,10 @@ fn count_between(
     (rows, cols)
 }
 
-fn solve(input: &str, expansion_factor: usize) -> usize {
-    let grid = parse_input(input);
-    let empty_rows = get_empty_rows(&grid);
-    let empty_cols = get_empty_cols(&grid);
-    let galaxies = get_galaxies(&grid);
+fn solve(grid: &Grid<char>, expansion_factor: usize) -> usize {
+    let empty_rows = get_empty_rows(grid);
+    let empty_cols = get_empty_cols(grid);
+    let galaxies = get_galaxies(grid);
     galaxies
         .iter()
         .combinations(2)
@@ -93,20 +92,33 @@ fn solve(input: &str, expansion_factor: usize) -> usize {
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Point(usize, usize);
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let sum_of_distances = solve(&input, 2);
-    println!("Part 1: {sum_of_distances}");
+struct Day11;
 
-    let sum_of_distances = solve(&input, 1_000_000);
-    println!("Part 2: {sum_of_distances}");
+impl Solution for Day11 {
+    type Input<'a> = Grid<char>;
 
-    Ok(())
+    fn parse(input: &str) -> Grid<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve(input, 2)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve(input, 1_000_000)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 11);
+    aoc_core::run::<Day11>(&input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     fn get_input() -> String {
         String::from(
@@ -196,25 +208,27 @@ mod tests {
 
     #[test]
     fn test_solve_p1() {
-        let sum_of_distances = solve(&get_input(), 2);
+        let sum_of_distances = solve(&parse_input(&get_input()), 2);
         assert_eq!(sum_of_distances, 374);
     }
 
     #[test]
     fn test_solve_p2() {
-        let sum_of_distances = solve(&get_input(), 10);
+        let grid = parse_input(&get_input());
+        let sum_of_distances = solve(&grid, 10);
         assert_eq!(sum_of_distances, 1030);
 
-        let sum_of_distances = solve(&get_input(), 100);
+        let sum_of_distances = solve(&grid, 100);
         assert_eq!(sum_of_distances, 8410);
     }
 
     #[test]
     fn test_full() {
         let input = fs::read_to_string("input.txt").unwrap();
-        let part1 = solve(&input, 2);
+        let grid = parse_input(&input);
+        let part1 = solve(&grid, 2);
         assert_eq!(part1, 9795148);
-        let part2 = solve(&input, 1_000_000);
+        let part2 = solve(&grid, 1_000_000);
         assert_eq!(part2, 650672493820);
     }
 }
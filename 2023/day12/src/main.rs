@@ -1,4 +1,5 @@
-use std::{collections::HashMap, error::Error, fs};
+use aoc_core::Solution;
+use std::{collections::HashMap, fmt::Display};
 
 fn parse_input(input: &str) -> Vec<String> {
     input
@@ -92,8 +93,7 @@ fn unfold(pair: &(String, Vec<usize>)) -> (String, Vec<usize>) {
     (unfolded_str, unfolded_target)
 }
 
-fn solve_p1(input: &str) -> usize {
-    let rows = parse_input(input);
+fn solve_p1(rows: &[String]) -> usize {
     rows.iter()
         .map(|row| {
             let (springs, target) = parse_row(row);
@@ -102,8 +102,7 @@ fn solve_p1(input: &str) -> usize {
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let rows = parse_input(input);
+fn solve_p2(rows: &[String]) -> usize {
     rows.iter()
         .map(|row| {
             let (springs, target) = unfold(&parse_row(row));
@@ -112,20 +111,33 @@ fn solve_p2(input: &str) -> usize {
         .sum()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let sum_of_counts = solve_p1(&input);
-    println!("Part 1: {sum_of_counts}");
+struct Day12;
 
-    let sum_of_counts = solve_p2(&input);
-    println!("Part 2: {sum_of_counts}");
+impl Solution for Day12 {
+    type Input<'a> = Vec<String>;
 
-    Ok(())
+    fn parse(input: &str) -> Vec<String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 12);
+    aoc_core::run::<Day12>(&input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     fn get_input() -> String {
         String::from(
@@ -162,18 +174,19 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(&get_input());
+        let answer = solve_p1(&parse_input(&get_input()));
         assert_eq!(answer, 21);
-        let answer = solve_p2(&get_input());
+        let answer = solve_p2(&parse_input(&get_input()));
         assert_eq!(answer, 525152);
     }
 
     #[test]
     fn test_solve() {
         let input = fs::read_to_string("input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let rows = parse_input(&input);
+        let answer = solve_p1(&rows);
         assert_eq!(answer, 7599);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&rows);
         assert_eq!(answer, 15454556629917);
     }
 
@@ -1,5 +1,6 @@
+use aoc_core::Solution;
 use grid::Grid;
-use std::{cmp::min, error::Error, fs};
+use std::{cmp::min, fmt::Display};
 
 fn parse_input(input: &str) -> Vec<Grid<char>> {
     let mut grids: Vec<Grid<char>> = Vec::new();
@@ -113,15 +114,13 @@ fn find_horizontal_line_with_smudge(grid: &Grid<char>) -> Option<usize> {
     None
 }
 
-fn solve_p1(input: &str) -> usize {
-    let grids = parse_input(input);
+fn solve_p1(grids: &[Grid<char>]) -> usize {
     let vsum: usize = grids.iter().filter_map(find_vertical_line).sum();
     let hsum: usize = grids.iter().filter_map(find_horizontal_line).sum();
     vsum + (100 * hsum)
 }
 
-fn solve_p2(input: &str) -> usize {
-    let grids = parse_input(input);
+fn solve_p2(grids: &[Grid<char>]) -> usize {
     let vsum: usize = grids
         .iter()
         .filter_map(find_vertical_line_with_smudge)
@@ -133,20 +132,34 @@ fn solve_p2(input: &str) -> usize {
     vsum + (100 * hsum)
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let answer = solve_p1(&input);
-    println!("Part 1: {answer}");
+struct Day13;
 
-    let answer = solve_p2(&input);
-    println!("Part 2: {answer}");
+impl Solution for Day13 {
+    type Input<'a> = Vec<Grid<char>>;
 
-    Ok(())
+    fn parse(input: &str) -> Vec<Grid<char>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 13);
+    aoc_core::run::<Day13>(&input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::error::Error;
+    use std::fs;
 
     fn get_test_input() -> String {
         String::from(
@@ -208,18 +221,19 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(&get_test_input());
+        let answer = solve_p1(&parse_input(&get_test_input()));
         assert_eq!(answer, 405);
-        let answer = solve_p2(&get_test_input());
+        let answer = solve_p2(&parse_input(&get_test_input()));
         assert_eq!(answer, 400);
     }
 
     #[test]
     fn test_solve() -> Result<(), Box<dyn Error>> {
         let input = fs::read_to_string("input.txt")?;
-        let answer = solve_p1(&input);
+        let grids = parse_input(&input);
+        let answer = solve_p1(&grids);
         assert_eq!(answer, 35360);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&grids);
         assert_eq!(answer, 36755);
 
         Ok(())
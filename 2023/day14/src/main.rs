@@ -1,7 +1,8 @@
 use log::debug;
-use std::{error::Error, fs};
 
+use aoc_core::{cycle::find_repeat, Solution};
 use itertools::Itertools;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<Vec<char>> {
     input
@@ -52,7 +53,7 @@ fn rock_weight(c: char) -> usize {
     }
 }
 
-fn transpose<T>(v: &Vec<Vec<T>>) -> Vec<Vec<T>>
+fn transpose<T>(v: &[Vec<T>]) -> Vec<Vec<T>>
 where
     T: Clone,
 {
@@ -84,59 +85,55 @@ fn cycle(platform: &Vec<Vec<char>>) -> Vec<Vec<char>> {
     sort_by_row(&cycled, false)
 }
 
-fn solve_p1(input: &str) -> usize {
+fn solve_p1(platform: &[Vec<char>]) -> usize {
     // The problem becomes a straightforward sorting problem if each colum of rocks is treated as a
     // string. Split it on #, sort it, rejoin with # and then calculate the load. Easy =)
     // let platform = preprocess(&parse_input(input));
-    let platform = transpose(&parse_input(input));
-    platform
+    transpose(platform)
         .iter()
         .map(|rocks| sort_rocks(rocks, true))
         .map(|rocks| calculate_col_load(&rocks))
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
+fn solve_p2(platform: &[Vec<char>]) -> usize {
     let iteration_count = 1_000_000_000;
 
-    let platform = parse_input(input);
+    let spins = find_repeat(platform.to_vec(), cycle);
+    debug!(
+        "Prefix: {}, Period: {}",
+        spins.cycle.prefix, spins.cycle.length
+    );
+    calculate_load(spins.nth(iteration_count))
+}
 
-    let mut cache: Vec<Vec<Vec<char>>> = Vec::with_capacity(1000);
+struct Day14;
 
-    let mut tmp = platform.clone();
+impl Solution for Day14 {
+    type Input<'a> = Vec<Vec<char>>;
 
-    for i in 0..iteration_count {
-        tmp = cycle(&tmp);
+    fn parse(input: &str) -> Vec<Vec<char>> {
+        parse_input(input)
+    }
 
-        if cache.contains(&tmp) {
-            // cycle detected
-            let previous_idx = cache.iter().find_position(|x| **x == tmp).unwrap().0;
-            let period = i - previous_idx;
-            let target_idx = previous_idx - 1 + ((iteration_count - previous_idx) % period);
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-            debug!("Curr: {i}, Prev: {previous_idx}, Period: {period}, Target: {target_idx}");
-            return calculate_load(&cache[target_idx]);
-        } else {
-            cache.push(tmp.clone());
-        }
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
     }
-    0
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    let load = solve_p1(&input);
-    println!("Part 1: {load}");
-
-    let load = solve_p2(&input);
-    println!("Part 2: {load}");
-
-    Ok(())
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 14);
+    aoc_core::run::<Day14>(&input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     fn get_test_input() -> String {
         String::from(
@@ -199,19 +196,21 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = get_test_input();
-        let answer = solve_p1(&input);
+        let platform = parse_input(&input);
+        let answer = solve_p1(&platform);
         assert_eq!(answer, 136);
 
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&platform);
         assert_eq!(answer, 64);
     }
 
     #[test]
     fn test_solve() {
         let input = fs::read_to_string("input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let platform = parse_input(&input);
+        let answer = solve_p1(&platform);
         assert_eq!(answer, 106648);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&platform);
         assert_eq!(answer, 87700);
     }
 
@@ -262,7 +261,7 @@ mod tests {
     #[test]
     fn test_sort_by_row() {
         let input = "...\n.O.\n...";
-        let platform = parse_input(input);
+        let platform = parse_input(&input);
         let sorted = sort_by_row(&platform, true);
         let expected = parse_input("...\nO..\n...");
         assert_eq!(sorted, expected);
@@ -275,7 +274,7 @@ mod tests {
     #[test]
     fn test_sort_by_col() {
         let input = "...\n.O.\n...";
-        let platform = parse_input(input);
+        let platform = parse_input(&input);
         let sorted = sort_by_col(&platform, true);
         let expected = parse_input(".O.\n...\n...");
         assert_eq!(sorted, expected);
@@ -1,5 +1,5 @@
-use anyhow::Result;
-use std::fs;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct LensBox {
@@ -70,32 +70,44 @@ fn add_operation(boxes: &mut [LensBox], op: &str) {
     }
 }
 
-fn solve_p1(input: &str) -> usize {
-    let steps = parse_input(input);
+fn solve_p1(steps: &[String]) -> usize {
     steps.iter().map(|step| hash(step)).sum()
 }
 
-fn solve_p2(input: &str) -> u32 {
-    let steps = parse_input(input);
+fn solve_p2(steps: &[String]) -> u32 {
     let mut boxes: Vec<_> = (0..256).map(LensBox::new).collect();
-    process(&steps, &mut boxes);
+    process(steps, &mut boxes);
     boxes.iter().map(|b| b.focusing_power()).sum()
 }
 
-fn main() -> Result<()> {
-    let input = fs::read_to_string("input.txt")?;
-    let answer = solve_p1(&input);
-    println!("Part 1: {answer}");
+struct Day15;
 
-    let answer = solve_p2(&input);
-    println!("Part 1: {answer}");
+impl Solution for Day15 {
+    type Input<'a> = Vec<String>;
 
-    Ok(())
+    fn parse(input: &str) -> Vec<String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 15);
+    aoc_core::run::<Day15>(&input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use anyhow::Result;
+    use std::fs;
 
     fn get_test_input() -> String {
         String::from("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7")
@@ -132,18 +144,20 @@ mod tests {
     #[test]
     fn solve_with_test_input() {
         let input = get_test_input();
-        let answer = solve_p1(&input);
+        let steps = parse_input(&input);
+        let answer = solve_p1(&steps);
         assert_eq!(answer, 1320);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&steps);
         assert_eq!(answer, 145);
     }
 
     #[test]
     fn solve() -> Result<()> {
         let input = fs::read_to_string("input.txt")?;
-        let answer = solve_p1(&input);
+        let steps = parse_input(&input);
+        let answer = solve_p1(&steps);
         assert_eq!(answer, 516804);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&steps);
         assert_eq!(answer, 231844);
 
         Ok(())
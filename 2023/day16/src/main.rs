@@ -1,6 +1,7 @@
-use anyhow::Result;
+use aoc_core::Solution;
 use grid::Grid;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Grid<char> {
     let mut grid = Grid::new(0, 0);
@@ -193,25 +194,35 @@ fn max_energized(grid: &Grid<char>) -> usize {
     *[m1, m2, m3, m4].iter().max().unwrap()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let grid = parse_input(input);
-    calc_energized(&grid, &Point(0, 0), Direction::Right)
+fn solve_p1(grid: &Grid<char>) -> usize {
+    calc_energized(grid, &Point(0, 0), Direction::Right)
 }
 
-fn solve_p2(input: &str) -> usize {
-    let grid = parse_input(input);
-    max_energized(&grid)
+fn solve_p2(grid: &Grid<char>) -> usize {
+    max_energized(grid)
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("../input.txt");
-    // let input = fs::read_to_string("input.txt")?;
-    let answer = solve_p1(&input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(&input);
-    println!("Part 2: {answer}");
+struct Day16;
 
-    Ok(())
+impl Solution for Day16 {
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Grid<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 16);
+    aoc_core::run::<Day16>(&input);
 }
 
 #[cfg(test)]
@@ -298,18 +309,20 @@ mod tests {
     #[test]
     fn solve_with_test_input() {
         let input = get_test_input();
-        let answer = solve_p1(&input);
+        let grid = parse_input(&input);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 46);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 51);
     }
 
     #[test]
     fn solve_with_real_input() {
         let input = include_str!("../input.txt");
-        let answer = solve_p1(&input);
+        let grid = parse_input(input);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 7996);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 8239);
     }
 }
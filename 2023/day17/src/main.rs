@@ -1,7 +1,8 @@
-use anyhow::Result;
+use aoc_core::Solution;
 use grid::Grid;
 use pathfinding::prelude::dijkstra;
-use std::{cmp::min, fs};
+use std::cmp::min;
+use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
 enum Direction {
@@ -108,31 +109,29 @@ impl Pos {
 //     0
 // }
 
-fn solve_p1(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p1(grid: &Grid<usize>) -> usize {
     dijkstra(
         &Pos(0, 0, 0, Direction::East),
-        |p| p.successors(&grid, 0, 3),
-        |p| p.is_goal(&grid),
+        |p| p.successors(grid, 0, 3),
+        |p| p.is_goal(grid),
     )
     .unwrap()
     .1
 }
 
-fn solve_p2(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p2(grid: &Grid<usize>) -> usize {
     let min_dist_e = dijkstra(
         &Pos(0, 0, 0, Direction::East),
-        |p| p.successors(&grid, 4, 10),
-        |p| p.is_goal(&grid),
+        |p| p.successors(grid, 4, 10),
+        |p| p.is_goal(grid),
     )
     .unwrap()
     .1;
 
     let min_dist_s = dijkstra(
         &Pos(0, 0, 0, Direction::South),
-        |p| p.successors(&grid, 4, 10),
-        |p| p.is_goal(&grid),
+        |p| p.successors(grid, 4, 10),
+        |p| p.is_goal(grid),
     )
     .unwrap()
     .1;
@@ -140,15 +139,27 @@ fn solve_p2(input: &str) -> usize {
     min(min_dist_e, min_dist_s)
 }
 
-fn main() -> Result<()> {
-    let input = fs::read_to_string("input.txt")?;
-    let answer = solve_p1(&input);
-    println!("Part 1: {answer}");
+struct Day17;
 
-    let answer = solve_p2(&input);
-    println!("Part 2: {answer}");
+impl Solution for Day17 {
+    type Input<'a> = Grid<usize>;
 
-    Ok(())
+    fn parse(input: &str) -> Grid<usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 17);
+    aoc_core::run::<Day17>(&input);
 }
 
 #[cfg(test)]
@@ -179,18 +190,20 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let grid = parse_input(INPUT);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 102);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 94);
     }
 
     #[test]
     fn test_solve() {
         let input = include_str!("../input.txt");
-        let answer = solve_p1(input);
+        let grid = parse_input(input);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 1008);
-        let answer = solve_p2(input);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 1210);
     }
 }
@@ -1,5 +1,6 @@
-use anyhow::Result;
+use aoc_core::{geometry::Polygon, Point, Solution};
 use regex::Regex;
+use std::fmt::Display;
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 struct Plan {
@@ -61,34 +62,28 @@ fn parse_input_p2(input: &str) -> Vec<Plan> {
         .collect()
 }
 
-const fn dig(curr: (isize, isize), dir: Direction, steps: usize) -> (isize, isize) {
+fn dig(curr: Point, dir: Direction, steps: usize) -> Point {
+    let steps = steps as isize;
     match dir {
-        Direction::Up => (curr.0, curr.1 - steps as isize),
-        Direction::Down => (curr.0, curr.1 + steps as isize),
-        Direction::Right => (curr.0 + steps as isize, curr.1),
-        Direction::Left => (curr.0 - steps as isize, curr.1),
+        Direction::Up => Point::new(curr.x, curr.y - steps),
+        Direction::Down => Point::new(curr.x, curr.y + steps),
+        Direction::Right => Point::new(curr.x + steps, curr.y),
+        Direction::Left => Point::new(curr.x - steps, curr.y),
     }
 }
 
 fn dig_and_calc_area(plans: &[Plan]) -> isize {
-    let mut curr = (0, 0);
+    let mut curr = Point::new(0, 0);
     let mut vertices = vec![curr];
-    let mut boundary: isize = 1;
     for plan in plans {
-        let steps = plan.distance;
         curr = dig(curr, plan.dir, plan.distance);
         vertices.push(curr);
-        boundary += steps as isize;
     }
+    // The trench ends where it started
+    vertices.pop();
 
-    // Shoelace formula
-    // https://www.themathdoctors.org/polygon-coordinates-and-areas/
-    let area: isize = vertices
-        .iter()
-        .zip(vertices.iter().cycle().skip(1))
-        .map(|(a, b)| a.0 * b.1 - a.1 * b.0)
-        .sum();
-    area.abs() / 2 + boundary / 2 + 1
+    // The lagoon holds a cubic metre for each lattice point inside the trench or on it
+    Polygon::new(vertices).lattice_points() as isize
 }
 
 fn parse_hexcode(code: &str) -> Plan {
@@ -116,15 +111,27 @@ fn solve_p2(input: &str) -> isize {
     dig_and_calc_area(&plans)
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
+struct Day18;
 
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+impl Solution for Day18 {
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2023, 18);
+    aoc_core::run::<Day18>(&input);
 }
 
 #[cfg(test)]
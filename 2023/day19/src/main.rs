@@ -1,5 +1,10 @@
-use std::{collections::HashMap, ops::Range, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+    str::FromStr,
+};
 
+use aoc_core::{interval, Solution};
 use regex::Regex;
 
 #[derive(Debug, Clone)]
@@ -71,6 +76,32 @@ enum Category {
     S,
 }
 
+impl Category {
+    /// The axis of a `PartRange` holding this category's ratings.
+    fn axis(self) -> Option<usize> {
+        match self {
+            Category::X => Some(0),
+            Category::M => Some(1),
+            Category::A => Some(2),
+            Category::S => Some(3),
+            Category::None => None,
+        }
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Category::X => "x",
+            Category::M => "m",
+            Category::A => "a",
+            Category::S => "s",
+            Category::None => "",
+        };
+        write!(f, "{name}")
+    }
+}
+
 impl From<&str> for Category {
     fn from(s: &str) -> Self {
         match s {
@@ -145,264 +176,294 @@ fn parse_input(input: &str) -> (HashMap<String, Workflow>, Vec<Part>) {
     (workflows, parts)
 }
 
-/// Returns true if the part is accepted.
-fn process(workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
-    let mut wf = workflows.get("in").unwrap();
-    loop {
-        for rule in &wf.rules {
-            let match_found = match rule.op.as_str() {
-                "<" => part.cat_val(rule.category) < rule.threshold,
-                ">" => part.cat_val(rule.category) > rule.threshold,
-                "" => true,
-                _ => unreachable!(),
-            };
-            if match_found {
-                match rule.action.as_str() {
-                    "A" => return true,
-                    "R" => return false,
-                    _ => {
-                        wf = workflows.get(&rule.action).unwrap();
-                        break;
-                    }
-                }
-            }
+/// The x, m, a and s ratings of a set of parts.
+type PartRange = interval::Box<usize, 4>;
+
+// lhs: range that matches the rule condition
+// rhs: range that doesn't match the rule condition
+fn split_range(in_range: &PartRange, rule: &Rule) -> (Option<PartRange>, Option<PartRange>) {
+    let Some(axis) = rule.category.axis() else {
+        return (None, None);
+    };
+    match rule.op.as_str() {
+        "<" => in_range.split(axis, rule.threshold),
+        ">" => {
+            let (below, above) = in_range.split(axis, rule.threshold + 1);
+            (above, below)
         }
+        _ => (None, None),
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct PartRange {
-    x: Range<usize>,
-    m: Range<usize>,
-    a: Range<usize>,
-    s: Range<usize>,
+/// Every rating starts out anywhere from 1 to 4000.
+const ALL_PARTS: PartRange = PartRange {
+    min: [1; 4],
+    max: [4001; 4],
+};
+
+#[derive(Debug, PartialEq, Eq)]
+enum CompileError {
+    UnknownWorkflow(String),
+    /// The workflows that send parts round in a loop, starting and ending with the same one.
+    Cycle(Vec<String>),
 }
 
-impl PartRange {
-    fn get_cat(&self, cat: Category) -> Range<usize> {
-        match cat {
-            Category::X => self.x.clone(),
-            Category::M => self.m.clone(),
-            Category::A => self.a.clone(),
-            Category::S => self.s.clone(),
-            _ => 0..0,
+impl Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompileError::UnknownWorkflow(name) => write!(f, "unknown workflow {name}"),
+            CompileError::Cycle(names) => write!(f, "workflows loop: {}", names.join(" -> ")),
         }
     }
+}
 
-    fn combinations(&self) -> usize {
-        self.x.len() * self.m.len() * self.a.len() * self.s.len()
+/// Checks that every workflow reachable from `in` exists and that none of them can send a
+/// part back to one it has already been through.
+fn validate(workflows: &HashMap<String, Workflow>) -> Result<(), CompileError> {
+    fn visit<'a>(
+        workflows: &'a HashMap<String, Workflow>,
+        name: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Result<(), CompileError> {
+        if matches!(name, "A" | "R") || done.contains(name) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|&n| n == name) {
+            let mut cycle: Vec<String> = path[start..].iter().map(|n| n.to_string()).collect();
+            cycle.push(name.to_string());
+            return Err(CompileError::Cycle(cycle));
+        }
+        let workflow = workflows
+            .get(name)
+            .ok_or_else(|| CompileError::UnknownWorkflow(name.to_string()))?;
+        path.push(name);
+        for rule in &workflow.rules {
+            visit(workflows, &rule.action, path, done)?;
+        }
+        path.pop();
+        done.insert(name);
+        Ok(())
     }
+    visit(workflows, "in", &mut Vec::new(), &mut HashSet::new())
 }
 
-/// Returns true if the part is accepted.
-fn get_accepted_ranges(workflows: &HashMap<String, Workflow>) -> Vec<PartRange> {
-    let start = PartRange {
-        x: 1..4001,
-        m: 1..4001,
-        a: 1..4001,
-        s: 1..4001,
-    };
-
-    process_workflow(workflows, "in", &start)
+/// The workflows compiled into a single decision tree. Each test is a rating compared against
+/// a threshold, and each leaf accepts or rejects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Accept,
+    Reject,
+    /// Parts with a `category` rating below `at` go `below`, the rest go `above`.
+    Split {
+        category: Category,
+        at: usize,
+        below: Box<Node>,
+        above: Box<Node>,
+    },
 }
 
-fn process_workflow(
-    workflows: &HashMap<String, Workflow>,
-    name: &str,
-    in_range: &PartRange,
-) -> Vec<PartRange> {
-    match name {
-        "A" => {
-            return vec![in_range.clone()];
-        }
-        "R" => {
-            return vec![];
-        }
-        _ => (),
+impl Node {
+    /// Validates the workflows and compiles them, starting from `in`. Tests that every part
+    /// reaching them would pass, or fail, are dropped, as are tests whose outcomes lead to
+    /// the same place.
+    fn compile(workflows: &HashMap<String, Workflow>) -> Result<Node, CompileError> {
+        validate(workflows)?;
+        Ok(Node::compile_target(workflows, "in", &ALL_PARTS))
     }
 
-    let mut accepted = Vec::with_capacity(100);
-    let mut working_range = in_range.clone();
-    let wf = workflows.get(name).unwrap();
+    fn compile_target(
+        workflows: &HashMap<String, Workflow>,
+        name: &str,
+        range: &PartRange,
+    ) -> Node {
+        match name {
+            "A" => Node::Accept,
+            "R" => Node::Reject,
+            _ => Node::compile_rules(workflows, &workflows[name].rules, range),
+        }
+    }
 
-    for rule in &wf.rules {
-        match rule.op.as_str() {
-            "" => {
-                accepted.extend(process_workflow(workflows, &rule.action, &working_range));
-                break;
-            }
-            _ => {
-                let (a, b) = split_range(&working_range, rule);
-                if let Some(r) = a {
-                    accepted.extend(process_workflow(workflows, &rule.action, &r));
-                }
-                if let Some(r) = b {
-                    working_range = r;
+    fn compile_rules(
+        workflows: &HashMap<String, Workflow>,
+        rules: &[Rule],
+        range: &PartRange,
+    ) -> Node {
+        let (rule, rest) = rules
+            .split_first()
+            .expect("A workflow ends with a rule that always applies");
+        if rule.category.axis().is_none() {
+            return Node::compile_target(workflows, &rule.action, range);
+        }
+        let (matching, other) = split_range(range, rule);
+        let matched = matching.map(|r| Node::compile_target(workflows, &rule.action, &r));
+        let unmatched = other.map(|r| Node::compile_rules(workflows, rest, &r));
+        match (matched, unmatched) {
+            (Some(node), None) | (None, Some(node)) => node,
+            (Some(matched), Some(unmatched)) if matched == unmatched => matched,
+            (Some(matched), Some(unmatched)) => {
+                let (at, below, above) = match rule.op.as_str() {
+                    "<" => (rule.threshold, matched, unmatched),
+                    _ => (rule.threshold + 1, unmatched, matched),
+                };
+                Node::Split {
+                    category: rule.category,
+                    at,
+                    below: Box::new(below),
+                    above: Box::new(above),
                 }
             }
-        };
+            (None, None) => unreachable!("Splitting a range leaves at least one part of it"),
+        }
     }
-    accepted
-}
 
-// lhs: range that matches the rule condition
-// rhs: range that doesn't match the rule condition
-fn split_range(in_range: &PartRange, rule: &Rule) -> (Option<PartRange>, Option<PartRange>) {
-    match rule.op.as_str() {
-        "<" => split_range_lt(in_range, rule),
-        ">" => split_range_gt(in_range, rule),
-        _ => (None, None),
+    fn accepts(&self, part: &Part) -> bool {
+        match self {
+            Node::Accept => true,
+            Node::Reject => false,
+            Node::Split {
+                category,
+                at,
+                below,
+                above,
+            } => {
+                if part.cat_val(*category) < *at {
+                    below.accepts(part)
+                } else {
+                    above.accepts(part)
+                }
+            }
+        }
     }
-}
 
-fn split_range_lt(in_range: &PartRange, rule: &Rule) -> (Option<PartRange>, Option<PartRange>) {
-    let target = in_range.get_cat(rule.category);
-    if target.start > rule.threshold {
-        // entire range is outside the threshold
-        return (None, Some(in_range.clone()));
-    } else if target.end <= rule.threshold {
-        // entire range is within the threshold
-        return (Some(in_range.clone()), None);
-    }
-
-    // threshold lies within the range. Split it.
-    match rule.category {
-        Category::X => {
-            let lhs = PartRange {
-                x: in_range.x.start..rule.threshold,
-                ..in_range.clone()
-            };
-            let rhs = PartRange {
-                x: rule.threshold..in_range.x.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
-        }
-        Category::M => {
-            let lhs = PartRange {
-                m: in_range.m.start..rule.threshold,
-                ..in_range.clone()
-            };
-            let rhs = PartRange {
-                m: rule.threshold..in_range.m.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
+    /// The disjoint boxes of ratings that are accepted.
+    fn accepted_ranges(&self) -> Vec<PartRange> {
+        fn collect(node: &Node, range: PartRange, accepted: &mut Vec<PartRange>) {
+            match node {
+                Node::Accept => accepted.push(range),
+                Node::Reject => {}
+                Node::Split {
+                    category,
+                    at,
+                    below,
+                    above,
+                } => {
+                    let (lower, upper) = range.split(category.axis().unwrap(), *at);
+                    if let Some(lower) = lower {
+                        collect(below, lower, accepted);
+                    }
+                    if let Some(upper) = upper {
+                        collect(above, upper, accepted);
+                    }
+                }
+            }
         }
-        Category::A => {
-            let lhs = PartRange {
-                a: in_range.a.start..rule.threshold,
-                ..in_range.clone()
-            };
-            let rhs = PartRange {
-                a: rule.threshold..in_range.a.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
+        let mut accepted = Vec::new();
+        collect(self, ALL_PARTS, &mut accepted);
+        accepted
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, label: &str, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        match self {
+            Node::Accept => writeln!(f, "{indent}{label}A"),
+            Node::Reject => writeln!(f, "{indent}{label}R"),
+            Node::Split {
+                category,
+                at,
+                below,
+                above,
+            } => {
+                writeln!(f, "{indent}{label}{category} < {at}")?;
+                below.write_tree(f, "yes: ", depth + 1)?;
+                above.write_tree(f, "no: ", depth + 1)
+            }
         }
-        Category::S => {
-            let lhs = PartRange {
-                s: in_range.s.start..rule.threshold,
-                ..in_range.clone()
-            };
-            let rhs = PartRange {
-                s: rule.threshold..in_range.s.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
+    }
+
+    /// Writes the tree in Graphviz DOT format.
+    fn to_dot(&self) -> String {
+        fn write(node: &Node, dot: &mut String, next_id: &mut usize) -> usize {
+            let id = *next_id;
+            *next_id += 1;
+            match node {
+                Node::Accept => *dot += &format!("    n{id} [label=\"A\", shape=box];\n"),
+                Node::Reject => *dot += &format!("    n{id} [label=\"R\", shape=box];\n"),
+                Node::Split {
+                    category,
+                    at,
+                    below,
+                    above,
+                } => {
+                    *dot += &format!("    n{id} [label=\"{category} < {at}\"];\n");
+                    let yes = write(below, dot, next_id);
+                    let no = write(above, dot, next_id);
+                    *dot += &format!("    n{id} -> n{yes} [label=\"yes\"];\n");
+                    *dot += &format!("    n{id} -> n{no} [label=\"no\"];\n");
+                }
+            }
+            id
         }
-        _ => (None, None),
+        let mut dot = String::from("digraph workflows {\n");
+        write(self, &mut dot, &mut 0);
+        dot += "}\n";
+        dot
     }
 }
 
-fn split_range_gt(in_range: &PartRange, rule: &Rule) -> (Option<PartRange>, Option<PartRange>) {
-    let target = in_range.get_cat(rule.category);
-    if target.start > rule.threshold {
-        // entire range is inside the threshold
-        return (Some(in_range.clone()), None);
-    } else if target.end <= rule.threshold {
-        // entire range is outside the threshold
-        return (None, Some(in_range.clone()));
-    }
-
-    // threshold lies within the range. Split it.
-
-    match rule.category {
-        Category::X => {
-            let rhs = PartRange {
-                x: in_range.x.start..rule.threshold + 1,
-                ..in_range.clone()
-            };
-            let lhs = PartRange {
-                x: (rule.threshold + 1)..in_range.x.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
-        }
-        Category::M => {
-            let rhs = PartRange {
-                m: in_range.m.start..rule.threshold + 1,
-                ..in_range.clone()
-            };
-            let lhs = PartRange {
-                m: (rule.threshold + 1)..in_range.m.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
-        }
-        Category::A => {
-            let rhs = PartRange {
-                a: in_range.a.start..rule.threshold + 1,
-                ..in_range.clone()
-            };
-            let lhs = PartRange {
-                a: (rule.threshold + 1)..in_range.a.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
-        }
-        Category::S => {
-            let rhs = PartRange {
-                s: in_range.s.start..rule.threshold + 1,
-                ..in_range.clone()
-            };
-            let lhs = PartRange {
-                s: (rule.threshold + 1)..in_range.s.end,
-                ..in_range.clone()
-            };
-            (Some(lhs), Some(rhs))
-        }
-        _ => (None, None),
+/// An indented outline of the tree, with each test followed by its yes and no branches.
+impl Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_tree(f, "", 0)
     }
 }
 
-fn get_combinations(accepted: &[PartRange]) -> usize {
-    accepted.iter().map(|r| r.combinations()).sum()
+fn compile(workflows: &HashMap<String, Workflow>) -> Node {
+    Node::compile(workflows).unwrap_or_else(|err| panic!("Invalid workflows: {err}"))
 }
 
-fn solve_p1(input: &str) -> usize {
-    let (workflows, parts) = parse_input(input);
+fn solve_p1((workflows, parts): &(HashMap<String, Workflow>, Vec<Part>)) -> usize {
+    let tree = compile(workflows);
+    parts
+        .iter()
+        .filter(|p| tree.accepts(p))
+        .map(|p| p.rating())
+        .sum()
+}
 
-    let accepted: Vec<Part> = parts
+fn solve_p2((workflows, _): &(HashMap<String, Workflow>, Vec<Part>)) -> usize {
+    compile(workflows)
+        .accepted_ranges()
         .iter()
-        .filter(|p| process(&workflows, p))
-        .cloned()
-        .collect();
-    accepted.iter().map(|p| p.rating()).sum()
+        .map(|r| r.volume() as usize)
+        .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let (workflows, _) = parse_input(input);
-    let accepted = get_accepted_ranges(&workflows);
-    get_combinations(&accepted)
+struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(input: &str) -> (HashMap<String, Workflow>, Vec<Part>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2023, 19);
+    match std::env::args().nth(1).as_deref() {
+        Some("--tree") => print!("{}", compile(&parse_input(&input).0)),
+        Some("--dot") => print!("{}", compile(&parse_input(&input).0).to_dot()),
+        _ => aoc_core::run::<Day19>(&input),
+    }
 }
 
 #[cfg(test)]
@@ -430,14 +491,18 @@ mod tests {
         {x=2461,m=1339,a=466,s=291}
         {x=2127,m=1623,a=2188,s=1013}";
 
+    /// `range` with the ratings for one category replaced by `ratings`.
+    fn with(range: &PartRange, category: Category, ratings: std::ops::Range<usize>) -> PartRange {
+        let axis = category.axis().unwrap();
+        let mut range = *range;
+        range.min[axis] = ratings.start;
+        range.max[axis] = ratings.end;
+        range
+    }
+
     #[test]
     fn test_split_range() {
-        let start = PartRange {
-            x: 1..4001,
-            m: 1..4001,
-            a: 1..4001,
-            s: 1..4001,
-        };
+        let start = PartRange::from_inclusive([1; 4], [4000; 4]);
         let r1 = Rule {
             category: Category::X,
             op: "<".to_string(),
@@ -445,20 +510,8 @@ mod tests {
             action: "".to_string(),
         };
         let (lhs, rhs) = split_range(&start, &r1);
-        assert_eq!(
-            lhs,
-            Some(PartRange {
-                x: 1..1000,
-                ..start.clone()
-            })
-        );
-        assert_eq!(
-            rhs,
-            Some(PartRange {
-                x: 1000..4001,
-                ..start.clone()
-            })
-        );
+        assert_eq!(lhs, Some(with(&start, Category::X, 1..1000)));
+        assert_eq!(rhs, Some(with(&start, Category::X, 1000..4001)));
 
         let r1 = Rule {
             category: Category::M,
@@ -467,20 +520,8 @@ mod tests {
             action: "".to_string(),
         };
         let (lhs, rhs) = split_range(&start, &r1);
-        assert_eq!(
-            lhs,
-            Some(PartRange {
-                m: 1001..4001,
-                ..start.clone()
-            })
-        );
-        assert_eq!(
-            rhs,
-            Some(PartRange {
-                m: 1..1001,
-                ..start.clone()
-            })
-        );
+        assert_eq!(lhs, Some(with(&start, Category::M, 1001..4001)));
+        assert_eq!(rhs, Some(with(&start, Category::M, 1..1001)));
 
         let r1 = Rule {
             category: Category::S,
@@ -495,37 +536,91 @@ mod tests {
             action: "".to_string(),
         };
         let (lhs, rhs) = split_range(&start, &r1);
+        assert_eq!(lhs, Some(with(&start, Category::S, 1..1351)));
+        assert_eq!(rhs, Some(with(&start, Category::S, 1351..4001)));
+        let (lhs, rhs) = split_range(&lhs.unwrap(), &r2);
+        assert_eq!(lhs, Some(with(&start, Category::S, 1..537)));
+        assert_eq!(rhs, Some(with(&start, Category::S, 537..1351)));
+    }
+
+    fn workflows(s: &str) -> HashMap<String, Workflow> {
+        s.lines()
+            .map(|line| Workflow::from_str(line.trim()).unwrap())
+            .map(|w| (w.name.clone(), w))
+            .collect()
+    }
+
+    #[test]
+    fn test_compile() {
+        let tree = Node::compile(&workflows("in{x<10:A,m>5:R,A}")).unwrap();
         assert_eq!(
-            lhs,
-            Some(PartRange {
-                s: 1..1351,
-                ..start.clone()
-            })
+            tree.to_string(),
+            "x < 10\n  yes: A\n  no: m < 6\n    yes: A\n    no: R\n"
         );
+        assert!(tree.accepts(&Part::from_str("{x=9,m=3000,a=1,s=1}").unwrap()));
+        assert!(!tree.accepts(&Part::from_str("{x=10,m=6,a=1,s=1}").unwrap()));
+        let accepted = tree.accepted_ranges();
+        assert_eq!(accepted.len(), 2);
         assert_eq!(
-            rhs,
-            Some(PartRange {
-                s: 1351..4001,
-                ..start.clone()
-            })
+            accepted.iter().map(|r| r.volume()).sum::<u64>(),
+            9 * 4000 * 4000 * 4000 + 3991 * 5 * 4000 * 4000
         );
-        let (lhs, rhs) = split_range(&lhs.unwrap(), &r2);
+    }
+
+    #[test]
+    fn test_compile_removes_dead_branches() {
+        // Parts sent to `a` all have x < 10, so its x > 20 test can't pass and both of `in`'s
+        // branches reject.
+        let tree = Node::compile(&workflows("in{x<10:a,R}\na{x>20:A,R}")).unwrap();
+        assert_eq!(tree, Node::Reject);
+        let tree = Node::compile(&workflows("in{x<10:a,m<5:A,R}\na{x<20:R,A}")).unwrap();
         assert_eq!(
-            lhs,
-            Some(PartRange {
-                s: 1..537,
-                ..start.clone()
-            })
+            tree.to_string(),
+            "x < 10\n  yes: R\n  no: m < 5\n    yes: A\n    no: R\n"
+        );
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        assert_eq!(
+            Node::compile(&workflows(
+                "in{x<10:a,b}\na{m<5:A,R}\nb{s>3:a,c}\nc{a<5:b,R}"
+            )),
+            Err(CompileError::Cycle(vec![
+                "b".into(),
+                "c".into(),
+                "b".into()
+            ]))
         );
         assert_eq!(
-            rhs,
-            Some(PartRange {
-                s: 537..1351,
-                ..start.clone()
-            })
+            Node::compile(&workflows("in{x<10:a,R}")),
+            Err(CompileError::UnknownWorkflow("a".into()))
         );
     }
 
+    #[test]
+    fn test_to_dot() {
+        let dot = Node::compile(&workflows("in{x<10:A,R}")).unwrap().to_dot();
+        assert_eq!(
+            dot,
+            "digraph workflows {\n    n0 [label=\"x < 10\"];\n    n1 [label=\"A\", shape=box];\n    \
+             n2 [label=\"R\", shape=box];\n    n0 -> n1 [label=\"yes\"];\n    \
+             n0 -> n2 [label=\"no\"];\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_compiled_example() {
+        let (workflows, parts) = parse_input(INPUT);
+        let tree = Node::compile(&workflows).unwrap();
+        let accepted: Vec<bool> = parts.iter().map(|p| tree.accepts(p)).collect();
+        assert_eq!(accepted, [true, false, true, false, true]);
+        let accepted = tree.accepted_ranges();
+        for (i, a) in accepted.iter().enumerate() {
+            assert!(accepted[i + 1..].iter().all(|b| !a.intersects(b)));
+        }
+    }
+
     #[test]
     fn test_parse_part() {
         let s = "{x=787,m=2655,a=1222,s=2876}";
@@ -569,16 +664,17 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let data = parse_input(INPUT);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 19114);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 167409079868000);
     }
 
     #[test]
     fn test_solve() {
         let input = include_str!("../input.txt");
-        let answer = solve_p1(input);
+        let answer = solve_p1(&parse_input(input));
         assert_eq!(answer, 402185);
     }
 }
@@ -1,6 +1,7 @@
+use aoc_core::Solution;
 use core::fmt;
 use num::Integer;
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
 enum Pulse {
@@ -41,7 +42,7 @@ enum ModuleType {
     Output,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Module {
     kind: ModuleType,
     name: String,
@@ -275,21 +276,19 @@ fn broadcast_process(module: &mut Module, pulse: Pulse) -> Vec<Signal> {
         .collect();
 }
 
-fn button_press(modules: &mut HashMap<String, Module>) {
-    let mut next = process(
-        modules,
-        &vec![Signal {
-            pulse: Pulse::Low,
-            dst: "broadcaster".to_string(),
-            src: "button".to_string(),
-        }],
-    );
+/// Presses the button once, processing pulses until the network settles. Every pulse sent
+/// along the way is passed to `on_signal` before it is delivered.
+fn button_press(modules: &mut HashMap<String, Module>, mut on_signal: impl FnMut(&Signal)) {
+    let mut next = vec![Signal {
+        pulse: Pulse::Low,
+        dst: "broadcaster".to_string(),
+        src: "button".to_string(),
+    }];
 
     while !next.is_empty() {
-        // dbg!(&next);
+        next.iter().for_each(&mut on_signal);
         next = process(modules, &next);
     }
-    // dbg!(modules);
 }
 
 fn setup(modules: &mut HashMap<String, Module>) {
@@ -314,13 +313,13 @@ fn setup(modules: &mut HashMap<String, Module>) {
     // dbg!(modules);
 }
 
-fn solve_p1(input: &str) -> usize {
+fn solve_p1(modules: &HashMap<String, Module>) -> usize {
     let iterations = 1000;
-    let mut modules = parse_input(input);
+    let mut modules = modules.clone();
 
     setup(&mut modules);
 
-    (0..iterations).for_each(|_| button_press(&mut modules));
+    (0..iterations).for_each(|_| button_press(&mut modules, |_| {}));
 
     let low: usize = modules
         .iter()
@@ -334,55 +333,133 @@ fn solve_p1(input: &str) -> usize {
     low * high
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut iterations = 0;
-    let mut modules = parse_input(input);
+/// Give up looking for the counters' cycles after this many presses.
+const MAX_PRESSES: usize = 100_000;
+
+/// The names of the modules that send pulses to `name`, sorted.
+fn inputs_of<'a>(modules: &'a HashMap<String, Module>, name: &str) -> Vec<&'a str> {
+    let mut inputs: Vec<&str> = modules
+        .values()
+        .filter(|module| module.outputs.iter().any(|output| output == name))
+        .map(|module| module.name.as_str())
+        .collect();
+    inputs.sort();
+    inputs
+}
 
+/// Finds how many button presses it takes for `target` to receive a low pulse.
+///
+/// The target is fed by a single conjunction, which sends a low pulse once all of its inputs
+/// have sent it a high pulse. Each of those inputs is driven by a counter that sends a high
+/// pulse every so many presses, so the presses are simulated until every counter has fired
+/// twice, and the answer is the LCM of their cycle lengths. That only holds if each counter
+/// first fires at the end of its first cycle, which is checked.
+fn presses_until_low_pulse(
+    modules: &HashMap<String, Module>,
+    target: &str,
+) -> Result<usize, String> {
+    let mut modules = modules.clone();
     setup(&mut modules);
 
-    let mut zhwatch: HashMap<String, usize> = HashMap::new();
-    let zh = modules.get("zh").unwrap();
-    zh.conj_state.iter().for_each(|(k, _)| {
-        zhwatch.insert(k.clone(), 0);
-    });
-
-    loop {
-        button_press(&mut modules);
-        iterations += 1;
-        if modules.get("rx").unwrap().count[Pulse::Low as usize] > 0 {
-            break;
-        }
-        let zh = modules.get("zh").unwrap();
-        zh.conj_state.iter().for_each(|(k, v)| {
-            if *v == Pulse::High {
-                let watcher = zhwatch.get_mut(k).unwrap();
-                if *watcher == 0 {
-                    *watcher = iterations;
-                    dbg!(&zhwatch);
+    let last = match inputs_of(&modules, target)[..] {
+        [last] if modules[last].kind == ModuleType::Conjunction => last.to_string(),
+        _ => return Err(format!("{target} isn't fed by a single conjunction")),
+    };
+    let counters: Vec<String> = inputs_of(&modules, &last)
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let mut fired: HashMap<String, Vec<usize>> = HashMap::new();
+    for press in 1..=MAX_PRESSES {
+        let mut reached = false;
+        button_press(&mut modules, |signal| {
+            if signal.dst == target && signal.pulse == Pulse::Low {
+                reached = true;
+            }
+            if signal.dst == last && signal.pulse == Pulse::High {
+                let presses = fired.entry(signal.src.clone()).or_default();
+                if presses.last() != Some(&press) {
+                    presses.push(press);
                 }
             }
         });
-        if zhwatch.iter().all(|(_, v)| *v != 0) {
-            break;
+        if reached {
+            return Ok(press);
+        }
+        if counters
+            .iter()
+            .all(|counter| fired.get(counter).is_some_and(|presses| presses.len() >= 2))
+        {
+            return counters.iter().try_fold(1, |acc: usize, counter| {
+                let (first, second) = (fired[counter][0], fired[counter][1]);
+                if second - first == first {
+                    Ok(acc.lcm(&first))
+                } else {
+                    Err(format!(
+                        "{counter} fires at presses {first} and {second}, which isn't a cycle from the start"
+                    ))
+                }
+            });
         }
     }
+    Err(format!(
+        "The counters feeding {last} didn't all repeat within {MAX_PRESSES} presses"
+    ))
+}
 
-    if modules.get("rx").unwrap().count[Pulse::Low as usize] == 0 {
-        return zhwatch
-            .values()
-            .cloned()
-            .reduce(|acc, d| acc.lcm(&d))
-            .unwrap();
+fn solve_p2(modules: &HashMap<String, Module>) -> usize {
+    presses_until_low_pulse(modules, "rx").unwrap()
+}
+
+/// Writes the module graph in Graphviz DOT format, with a shape for each kind of module.
+fn to_dot(modules: &HashMap<String, Module>) -> String {
+    let mut names: Vec<&String> = modules.keys().collect();
+    names.sort();
+    let mut dot = String::from("digraph modules {\n");
+    for name in &names {
+        let shape = match modules[*name].kind {
+            ModuleType::Broadcast => "doublecircle",
+            ModuleType::FlipFlop => "box",
+            ModuleType::Conjunction => "invhouse",
+            ModuleType::Output => "ellipse",
+        };
+        dot += &format!("    {name} [shape={shape}];\n");
+    }
+    for name in &names {
+        for output in &modules[*name].outputs {
+            dot += &format!("    {name} -> {output};\n");
+        }
+    }
+    dot += "}\n";
+    dot
+}
+
+struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = HashMap<String, Module>;
+
+    fn parse(input: &str) -> HashMap<String, Module> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
     }
-    iterations
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    // let answer = solve_p2(input);
-    // println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2023, 20);
+    if std::env::args().nth(1).as_deref() == Some("--dot") {
+        print!("{}", to_dot(&parse_input(&input)));
+        return;
+    }
+    aoc_core::run::<Day20>(&input);
 }
 
 #[cfg(test)]
@@ -419,16 +496,72 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let answer = solve_p1(&parse_input(INPUT));
         assert_eq!(answer, 32000000);
-        let answer = solve_p1(INPUT2);
+        let answer = solve_p1(&parse_input(INPUT2));
         assert_eq!(answer, 11687500);
     }
 
+    /// Two counters feeding `fin` through inverters: `a` fires every 3 presses and `b` every 5,
+    /// each resetting itself as it fires.
+    const COUNTERS: &str = "
+        broadcaster -> a1, b1
+        %a1 -> a2, ca
+        %a2 -> ca
+        &ca -> ia, a1
+        &ia -> fin
+        %b1 -> b2, cb
+        %b2 -> b3
+        %b3 -> cb
+        &cb -> ib, b1, b2
+        &ib -> fin
+        &fin -> rx";
+
+    #[test]
+    fn test_presses_until_low_pulse() {
+        assert_eq!(
+            presses_until_low_pulse(&parse_input(COUNTERS), "rx"),
+            Ok(15)
+        );
+        assert_eq!(
+            presses_until_low_pulse(&parse_input(INPUT2), "output"),
+            Ok(1)
+        );
+        assert!(presses_until_low_pulse(&parse_input(COUNTERS), "ca").is_err());
+    }
+
+    #[test]
+    fn test_presses_until_low_pulse_by_simulation() {
+        let mut modules = parse_input(COUNTERS);
+        setup(&mut modules);
+        let presses = (1..)
+            .find(|_| {
+                let mut reached = false;
+                button_press(&mut modules, |signal| {
+                    reached |= signal.dst == "rx" && signal.pulse == Pulse::Low
+                });
+                reached
+            })
+            .unwrap();
+        assert_eq!(presses, 15);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let dot = to_dot(&parse_input(INPUT2));
+        assert!(dot.starts_with("digraph modules {\n"));
+        assert!(dot.contains("    a [shape=box];\n"));
+        assert!(dot.contains("    broadcaster [shape=doublecircle];\n"));
+        assert!(dot.contains("    con [shape=invhouse];\n"));
+        assert!(dot.contains("    output [shape=ellipse];\n"));
+        assert!(dot.contains("    a -> inv;\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
     #[test]
     fn test_solve() {
         let input = include_str!("../input.txt");
-        let answer = solve_p1(input);
+        let answer = solve_p1(&parse_input(input));
         assert_eq!(answer, 886701120);
     }
 }
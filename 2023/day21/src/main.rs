@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
+use aoc_core::Solution;
 use grid::Grid;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Grid<char> {
     let mut grid = Grid::new(0, 0);
@@ -138,31 +140,42 @@ fn process_p2(grid: &Grid<char>, start: (usize, usize), steps: usize) -> usize {
             * ((progression[2] - progression[1]) - (progression[1] - progression[0]))
 }
 
-fn solve_p1(input: &str, steps: usize) -> usize {
-    let grid = parse_input(input);
-    let start = get_start(&grid);
-    process(&grid, start, steps)
+fn solve_p1(grid: &Grid<char>, steps: usize) -> usize {
+    let start = get_start(grid);
+    process(grid, start, steps)
 }
 
-fn solve_palt(input: &str, steps: usize) -> usize {
-    let grid = parse_input(input);
-    let start = get_start(&grid);
-    process_inf(&grid, start, steps)
+fn solve_palt(grid: &Grid<char>, steps: usize) -> usize {
+    let start = get_start(grid);
+    process_inf(grid, start, steps)
 }
 
-fn solve_p2(input: &str, steps: usize) -> usize {
-    let grid = parse_input(input);
-    let start = get_start(&grid);
-    process_p2(&grid, start, steps)
+fn solve_p2(grid: &Grid<char>, steps: usize) -> usize {
+    let start = get_start(grid);
+    process_p2(grid, start, steps)
+}
+
+struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Grid<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, 64)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_palt(input, 26501365)
+    }
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input, 64);
-    println!("Part 1: {answer}");
-    // let answer = solve_p2(input, 26501365);
-    let answer = solve_palt(input, 26501365);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2023, 21);
+    aoc_core::run::<Day21>(&input);
 }
 
 #[cfg(test)]
@@ -200,20 +213,21 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        assert_eq!(solve_p1(INPUT, 6), 16);
-        assert_eq!(solve_palt(INPUT, 6), 16);
-        assert_eq!(solve_palt(INPUT, 10), 50);
-        assert_eq!(solve_palt(INPUT, 50), 1594);
-        assert_eq!(solve_palt(INPUT, 100), 6536);
-        assert_eq!(solve_palt(INPUT, 500), 167004);
-        assert_eq!(solve_palt(INPUT, 1000), 668697);
-        // assert_eq!(solve_palt(INPUT, 5000), 16733044);
+        let grid = parse_input(INPUT);
+        assert_eq!(solve_p1(&grid, 6), 16);
+        assert_eq!(solve_palt(&grid, 6), 16);
+        assert_eq!(solve_palt(&grid, 10), 50);
+        assert_eq!(solve_palt(&grid, 50), 1594);
+        assert_eq!(solve_palt(&grid, 100), 6536);
+        assert_eq!(solve_palt(&grid, 500), 167004);
+        assert_eq!(solve_palt(&grid, 1000), 668697);
+        // assert_eq!(solve_palt(&grid, 5000), 16733044);
     }
 
     #[test]
     fn test_solve() {
         let input = include_str!("../input.txt");
-        assert_eq!(solve_p1(input, 64), 3699);
-        // assert_eq!(solve_p2(INPUT, 26501365), ??);
+        assert_eq!(solve_p1(&parse_input(input), 64), 3699);
+        // assert_eq!(solve_p2(&parse_input(INPUT), 26501365), ??);
     }
 }
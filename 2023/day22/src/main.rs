@@ -1,123 +1,83 @@
 // Solution based heavily on the approach taken in
-// https://github.com/henryiii/aoc2023/blob/main/src/bin/22.rs. In particular the use of the
-// Interval.
+// https://github.com/henryiii/aoc2023/blob/main/src/bin/22.rs.
 
-use core::fmt::{Debug, Formatter};
-use core::ops::{AddAssign, SubAssign};
+use aoc_core::{interval, Solution};
+use core::cmp::Ordering;
 use itertools::Itertools;
+use std::fmt::Display;
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
-struct Interval {
-    low: usize,
-    high: usize,
-}
+type Cuboid = interval::Box<usize, 3>;
 
-impl Interval {
-    fn new(a: usize, b: usize) -> Self {
-        Self {
-            low: a.min(b),
-            high: a.max(b),
-        }
-    }
+const Z: usize = 2;
 
-    const fn intersects(self, rhs: &Self) -> bool {
-        self.low > rhs.high || self.high < rhs.low
-    }
-}
-
-impl Debug for Interval {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.low == self.high {
-            write!(f, "{}", self.low)
-        } else {
-            write!(f, "{}-{}", self.low, self.high)
-        }
-    }
-}
-
-impl AddAssign<usize> for Interval {
-    fn add_assign(&mut self, rhs: usize) {
-        self.low += rhs;
-        self.high += rhs;
-    }
-}
-
-impl SubAssign<usize> for Interval {
-    fn sub_assign(&mut self, rhs: usize) {
-        self.low -= rhs;
-        self.high -= rhs;
-    }
-}
-
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Block {
-    z: Interval,
-    y: Interval,
-    x: Interval,
-}
-
-impl Debug for Block {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
-    }
-}
+/// One brick, as the cubes it fills.
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Block(Cuboid);
 
 impl Block {
     fn new(corner_1: (usize, usize, usize), corner_2: (usize, usize, usize)) -> Self {
-        Self {
-            x: Interval::new(corner_1.0, corner_2.0),
-            y: Interval::new(corner_1.1, corner_2.1),
-            z: Interval::new(corner_1.2, corner_2.2),
-        }
+        Self(Cuboid::from_inclusive(
+            [corner_1.0, corner_1.1, corner_1.2],
+            [corner_2.0, corner_2.1, corner_2.2],
+        ))
     }
 
-    const fn overlaps_xy(&self, block: &Self) -> bool {
-        !self.x.intersects(&block.x) && !self.y.intersects(&block.y)
+    /// The z, y and x extents in that order, so that sorting puts the lowest blocks first.
+    fn sort_key(&self) -> [usize; 6] {
+        let Cuboid { min, max } = self.0;
+        [min[2], max[2], min[1], max[1], min[0], max[0]]
     }
 
-    const fn overlaps_xyz(&self, block: &Self) -> bool {
-        self.overlaps_xy(block) && !self.z.intersects(&block.z)
+    fn overlaps_xy(&self, block: &Self) -> bool {
+        self.0.overlaps_along(&block.0, 0) && self.0.overlaps_along(&block.0, 1)
     }
 
-    const fn high_point(&self, block: &Self) -> Option<usize> {
-        if self.overlaps_xy(block) {
-            Some(self.z.high)
-        } else {
-            None
-        }
+    /// The lowest level a block above this one could rest at, if they overlap in x and y.
+    fn resting_level(&self, block: &Self) -> Option<usize> {
+        self.overlaps_xy(block).then_some(self.0.max[Z])
     }
 
     fn get_blocks_above<'a>(&self, blocks: &'a [Self]) -> Vec<&'a Self> {
-        let mut one_up = self.clone();
-        one_up.z += 1;
+        let one_up = self.0 + [0, 0, 1];
         blocks
             .iter()
-            .filter(|x| *x != self && one_up.overlaps_xyz(x))
+            .filter(|x| *x != self && one_up.intersects(&x.0))
             .collect()
     }
 
     fn count_supports(&self, blocks: &[Self]) -> usize {
-        let mut one_down = self.clone();
-        one_down.z -= 1;
+        let one_down = self.0 - [0, 0, 1];
         blocks
             .iter()
             .filter(|x| *x != self)
-            .filter(|x| one_down.overlaps_xyz(x))
+            .filter(|x| one_down.intersects(&x.0))
             .count()
     }
 }
 
+impl Ord for Block {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for Block {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 fn lower_blocks(blocks: &mut [Block]) {
-    blocks[0].z -= blocks[0].z.low - 1;
+    blocks[0].0 = blocks[0].0 - [0, 0, blocks[0].0.min[Z] - 1];
     for i in 1..blocks.len() {
         let (blocks_below, blocks_above) = blocks.split_at_mut(i);
         let block = &mut blocks_above[0];
         let level = blocks_below
             .iter()
-            .filter_map(|x| Some(x.high_point(block)? + 1))
+            .filter_map(|x| x.resting_level(block))
             .max()
             .unwrap_or(1);
-        block.z -= block.z.low - level;
+        block.0 = block.0 - [0, 0, block.0.min[Z] - level];
     }
 }
 
@@ -155,15 +115,15 @@ fn parse_input(input: &str) -> Vec<Block> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let mut blocks = parse_input(input);
+fn solve_p1(blocks: &[Block]) -> usize {
+    let mut blocks = blocks.to_vec();
     blocks.sort();
     lower_blocks(&mut blocks);
     removeable_blocks(&blocks).len()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut blocks = parse_input(input);
+fn solve_p2(blocks: &[Block]) -> usize {
+    let mut blocks = blocks.to_vec();
     blocks.sort();
     lower_blocks(&mut blocks);
     blocks
@@ -180,12 +140,27 @@ fn solve_p2(input: &str) -> usize {
         })
         .sum()
 }
+struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<Block>;
+
+    fn parse(input: &str) -> Vec<Block> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2023, 22);
+    aoc_core::run::<Day22>(&input);
 }
 
 #[cfg(test)]
@@ -213,18 +188,20 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let blocks = parse_input(INPUT);
+        let answer = solve_p1(&blocks);
         assert_eq!(answer, 5);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&blocks);
         assert_eq!(answer, 7);
     }
 
     #[test]
     fn test_solve() {
         let input = include_str!("../input.txt");
-        let answer = solve_p1(input);
+        let blocks = parse_input(input);
+        let answer = solve_p1(&blocks);
         assert_eq!(answer, 418);
-        let answer = solve_p2(input);
+        let answer = solve_p2(&blocks);
         assert_eq!(answer, 70702);
     }
 }
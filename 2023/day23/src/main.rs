@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 // Based on solution from:
 // https://github.com/LinAGKar/advent-of-code-2023-rust/blob/master/day23/src/main.rs
 
@@ -195,17 +198,27 @@ fn solve_p2(input: &str) -> u16 {
     find_longest_path(input, false)
 }
 
+struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let start_time = std::time::Instant::now();
-    let answer = solve_p1(input);
-    println!("Part 1 time: {:?}", std::time::Instant::now() - start_time);
-    println!("Part 1: {answer}");
-
-    let start_time = std::time::Instant::now();
-    let answer = solve_p2(input);
-    println!("Part 2 time: {:?}", std::time::Instant::now() - start_time);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2023, 23);
+    aoc_core::run::<Day23>(&input);
 }
 
 #[cfg(test)]
@@ -1,5 +1,13 @@
-use gcd::Gcd;
-use std::{fmt::Debug, fmt::Formatter, ops::RangeInclusive, str::FromStr};
+use aoc_core::{
+    linalg::{BigInt, Matrix, Rational},
+    Solution,
+};
+use std::{
+    fmt::Formatter,
+    fmt::{Debug, Display},
+    ops::RangeInclusive,
+    str::FromStr,
+};
 
 // Solutions heavily influenced by
 // https://github.com/maneatingape/advent-of-code-rust/blob/main/src/year2023/day24.rs
@@ -82,44 +90,37 @@ fn parse_input(input: &str) -> Vec<Hailstone> {
 //     | e  -k ||u|   | h - b |
 // ```
 //
-// Solve by finding the inverse of the 2x2 matrix and premultiplying both sides. The inverse is:
-//
-// ```
-//    ______1______ | -k  j |
-//    d(-k) - (-j)e | -e  d |
-// ```
+// Cramer's rule gives `t` and `u` exactly as fractions, so the intersection point can be
+// checked against the test area without any rounding.
 //
 fn intersect(h1: &Hailstone, h2: &Hailstone, range: &RangeInclusive<i64>) -> bool {
-    let a = h1.x as f64;
-    let b = h1.y as f64;
-    let d = h1.vx as f64;
-    let e = h1.vy as f64;
-    let g = h2.x as f64;
-    let h = h2.y as f64;
-    let j = h2.vx as f64;
-    let k = h2.vy as f64;
-
-    let determinant = e * j - d * k;
-    if determinant == 0.0 {
+    let (a, b, d, e) = (h1.x as i128, h1.y as i128, h1.vx as i128, h1.vy as i128);
+    let (g, h, j, k) = (h2.x as i128, h2.y as i128, h2.vx as i128, h2.vy as i128);
+
+    let coef = Matrix::new(vec![vec![d, -j], vec![e, -k]]);
+    let Some(times) = coef
+        .cramer(&[g - a, h - b])
+        .expect("intersection fits in an i128")
+    else {
+        // Parallel paths never cross
         return false;
-    }
-
-    // Invert the 2x2 matrix then multiply by the respective columns to      find the times.
-    let t = (j * (h - b) - k * (g - a)) / determinant;
-    let u = (d * (h - b) - e * (g - a)) / determinant;
+    };
+    let (t, u) = (&times[0], &times[1]);
 
-    let x = a + t * d;
-    let y = b + t * e;
+    let x = Rational::from_integer(a) + t * d;
+    let y = Rational::from_integer(b) + t * e;
 
-    t > 0.0 && u > 0.0 && bounds(&(x, y), range)
+    let zero = Rational::from_integer(0);
+    *t > zero && *u > zero && bounds(&(x, y), range)
 }
 
-fn bounds(p: &(f64, f64), range: &RangeInclusive<i64>) -> bool {
-    range.contains(&(p.0 as i64)) && range.contains(&(p.1 as i64))
+fn bounds(p: &(Rational<i128>, Rational<i128>), range: &RangeInclusive<i64>) -> bool {
+    let lo = Rational::from_integer(*range.start() as i128);
+    let hi = Rational::from_integer(*range.end() as i128);
+    (lo..=hi).contains(&p.0) && (lo..=hi).contains(&p.1)
 }
 
-fn solve_p1(input: &str, range: RangeInclusive<i64>) -> usize {
-    let hailstones = parse_input(input);
+fn solve_p1(hailstones: &[Hailstone], range: RangeInclusive<i64>) -> usize {
     let mut result = 0usize;
     for h1 in 1..hailstones.len() {
         for h2 in 0..h1 {
@@ -131,9 +132,7 @@ fn solve_p1(input: &str, range: RangeInclusive<i64>) -> usize {
     result
 }
 
-fn solve_p2(input: &str) -> i128 {
-    let hailstones = parse_input(input);
-
+fn solve_p2(hailstones: &[Hailstone]) -> i128 {
     // Find the intersection point of the first threee hailstones.
     let a = hailstones[0].x as i128;
     let b = hailstones[0].y as i128;
@@ -157,8 +156,8 @@ fn solve_p2(input: &str) -> i128 {
     let r = hailstones[2].vz as i128;
 
     // Coefficients for the 6 simulataneous linear equations.
-    // Columns are px, py, pz, vx, vy, vz of the rock equal to a constant.
-    let mut matrix = [
+    // Columns are px, py, pz, vx, vy, vz of the rock, with the constant each is equal to.
+    let equations = [
         [
             0,
             l - f,
@@ -215,88 +214,46 @@ fn solve_p2(input: &str) -> i128 {
         ],
     ];
 
-    // Use Gaussian elimination to solve for the 6 unknowns.
-    // Forward elimination, processing columns from left to right.
-    // This will leave a matrix in row echelon form.
-    for pivot in 0..6 {
-        // Make leading coefficient of each row positive to make subsequent calculations easier.
-        for row in &mut matrix[pivot..] {
-            if row[pivot] < 0 {
-                // Flip signs of each coefficient.
-                row.iter_mut().for_each(|n| *n = -*n);
-            }
-        }
-
-        loop {
-            // Reduce by GCD each time otherwise coefficients will overflow even a `i128`.
-            for row in &mut matrix[pivot..] {
-                let mut factor: u128 = 0;
-
-                for &next in &row[pivot..] {
-                    if next != 0 {
-                        if factor == 0 {
-                            factor = next.unsigned_abs();
-                        } else {
-                            factor = factor.gcd(next.unsigned_abs());
-                        }
-                    }
-                }
-
-                row[pivot..].iter_mut().for_each(|c| *c /= factor as i128);
-            }
-
-            let column = matrix.map(|row| row[pivot]);
+    // Elimination over the rationals grows the working values well past an `i128`, so solve
+    // with big integers. We're told the rock's position and velocity are whole numbers.
+    let coef = Matrix::new(
+        equations
+            .iter()
+            .map(|row| row[..6].iter().map(|&c| BigInt::from(c)).collect())
+            .collect(),
+    );
+    let rhs: Vec<BigInt> = equations.iter().map(|row| BigInt::from(row[6])).collect();
+    let rock = coef
+        .solve(&rhs)
+        .expect("big integers never overflow")
+        .integer()
+        .expect("a single rock with whole number coordinates");
 
-            // If only one non-zero coefficient remaining in the column then we're done.
-            if column[pivot..].iter().filter(|&&c| c > 0).count() == 1 {
-                // Move this row into the pivot location
-                let index = column.iter().rposition(|&c| c > 0).unwrap();
-                matrix.swap(pivot, index);
-                break;
-            }
+    // x + y + z
+    i128::try_from(&rock[0] + &rock[1] + &rock[2]).expect("answer fits in an i128")
+}
 
-            // Find the row with the lowest non-zero leading coefficient.
-            let min = *column[pivot..].iter().filter(|&&c| c > 0).min().unwrap();
-            let index = column.iter().rposition(|&c| c == min).unwrap();
+struct Day24;
 
-            // Subtract as many multiples of this minimum row from each other row as possible
-            // to shrink the coefficients of our column towards zero.
-            for row in pivot..6 {
-                if row != index && column[row] != 0 {
-                    let factor = column[row] / min;
+impl Solution for Day24 {
+    type Input<'a> = Vec<Hailstone>;
 
-                    for col in pivot..7 {
-                        matrix[row][col] -= factor * matrix[index][col];
-                    }
-                }
-            }
-        }
+    fn parse(input: &str) -> Vec<Hailstone> {
+        parse_input(input)
     }
 
-    // Back substitution, processing columns from right to left.
-    // This will leave the matrix in reduced row echelon form.
-    // The solved unknowns are then in the 7th column.
-    for pivot in (0..6).rev() {
-        // We're explicitly told that the results are integers so integer division is safe
-        // and will not mangle result.
-        matrix[pivot][6] /= matrix[pivot][pivot];
-
-        for row in 0..pivot {
-            matrix[row][6] -= matrix[pivot][6] * matrix[row][pivot];
-        }
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, 200_000_000_000_000..=400_000_000_000_000)
     }
 
-    // x + y + z
-    matrix[0][6] + matrix[1][6] + matrix[2][6]
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let range = 200_000_000_000_000..=400_000_000_000_000;
-    let answer = solve_p1(input, range);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer:?}");
+    let input = aoc_core::puzzle_input(2023, 24);
+    aoc_core::run::<Day24>(&input);
 }
 
 #[cfg(test)]
@@ -348,13 +305,13 @@ mod tests {
 
     #[test]
     fn test_solve_p1() {
-        let answer = solve_p1(INPUT, 7..=27);
+        let answer = solve_p1(&parse_input(INPUT), 7..=27);
         assert_eq!(answer, 2)
     }
 
     #[test]
     fn test_solve_p2() {
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&parse_input(INPUT));
         assert_eq!(answer, 24 + 13 + 10);
     }
 
@@ -362,9 +319,10 @@ mod tests {
     fn test_solve() {
         let input = include_str!("../input.txt");
         let range = 200_000_000_000_000..=400_000_000_000_000;
-        let answer = solve_p1(input, range);
+        let hailstones = parse_input(input);
+        let answer = solve_p1(&hailstones, range);
         assert_eq!(answer, 19523);
-        let answer = solve_p2(input);
+        let answer = solve_p2(&hailstones);
         assert_eq!(answer, 566373506408017);
     }
 }
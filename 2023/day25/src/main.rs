@@ -1,7 +1,8 @@
+use aoc_core::Solution;
 use rustworkx_core::connectivity::stoer_wagner_min_cut;
 use rustworkx_core::petgraph::graphmap::UnGraphMap;
 use std::collections::HashSet;
-use std::time::Instant;
+use std::fmt::Display;
 
 fn solve_p1(input: &str) -> usize {
     let mut edges = HashSet::<(&str, &str)>::new();
@@ -26,13 +27,23 @@ fn solve_p1(input: &str) -> usize {
     g1 * g2
 }
 
+struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let start = Instant::now();
-    let answer = solve_p1(input);
-    let elapsed = Instant::now() - start;
-    println!("Part 1: {answer}");
-    println!("{elapsed:.2?}");
+    let input = aoc_core::puzzle_input(2023, 25);
+    aoc_core::run::<Day25>(&input);
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
     let mut left = Vec::new();
@@ -11,8 +13,8 @@ fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
     (left, right)
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let (mut left, mut right) = parse_input(input);
+fn solve_p1((left, right): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let (mut left, mut right) = (left.clone(), right.clone());
     left.sort_unstable();
     right.sort_unstable();
 
@@ -22,8 +24,8 @@ fn solve_p1(input: &str) -> i32 {
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let (left, mut right) = parse_input(input);
+fn solve_p2((left, right): &(Vec<i32>, Vec<i32>)) -> usize {
+    let mut right = right.clone();
     right.sort_unstable();
 
     let mut counts = HashMap::new();
@@ -39,12 +41,27 @@ fn solve_p2(input: &str) -> usize {
         .sum()
 }
 
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let answer = solve_p1(input);
-    println!("Part 1: {answer}");
-    let answer = solve_p2(input);
-    println!("Part 2: {answer}");
+    let input = aoc_core::puzzle_input(2024, 1);
+    aoc_core::run::<Day01>(&input);
 }
 
 #[cfg(test)]
@@ -60,9 +77,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let data = parse_input(INPUT);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 11);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 31);
     }
 }
@@ -1,4 +1,5 @@
-use std::time::Instant;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<Vec<i32>> {
     input
@@ -56,17 +57,27 @@ fn is_safe_with_dampener(report: &[i32]) -> bool {
     false
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let reports = parse_input(input);
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Vec<i32>>;
 
-    let before = Instant::now();
-    let answer = solve_p1(&reports);
-    println!("Part 1: {answer}, elapsed: {:.2?}", before.elapsed());
+    fn parse(input: &str) -> Vec<Vec<i32>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let before = Instant::now();
-    let answer = solve_p2(&reports);
-    println!("Part 2: {answer}, elapsed: {:.2?}", before.elapsed());
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 2);
+    aoc_core::run::<Day02>(&input);
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
-use std::time::Instant;
-
+use aoc_core::Solution;
 use regex::Regex;
+use std::fmt::Display;
 
 fn solve_p1(input: &str) -> isize {
     let pattern = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
@@ -27,18 +27,27 @@ fn solve_p2(input: &str) -> isize {
     solve_p1(&filtered_input)
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
 
-    let start = Instant::now();
-    let answer = solve_p1(input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, {elapsed:.2?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = Instant::now();
-    let answer = solve_p2(input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, {elapsed:.2?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 3);
+    aoc_core::run::<Day03>(&input);
 }
 
 #[cfg(test)]
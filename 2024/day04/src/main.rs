@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use pathfinding::matrix::Matrix;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Matrix<char> {
     Matrix::from_rows(input.lines().map(|line| line.chars().collect::<Vec<_>>())).unwrap()
@@ -79,19 +81,27 @@ fn solve_p2(data: &Matrix<char>) -> usize {
         .count()
 }
 
+struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Matrix<char>;
+
+    fn parse(input: &str) -> Matrix<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let data = parse_input(input);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&data);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed time: {elapsed:.2?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&data);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed time: {elapsed:.2?}");
+    let input = aoc_core::puzzle_input(2024, 4);
+    aoc_core::run::<Day04>(&input);
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
-use std::{collections::HashMap, time::Instant};
+use aoc_core::Solution;
+use std::collections::HashMap;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> (HashMap<usize, Vec<usize>>, Vec<Vec<usize>>) {
     let mut rules = HashMap::new();
@@ -55,19 +57,27 @@ fn solve_p2(rules: &HashMap<usize, Vec<usize>>, updates: &[Vec<usize>]) -> usize
     reordered.iter().map(|u| u[u.len() / 2]).sum()
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (rules, updates) = parse_input(input);
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = (HashMap<usize, Vec<usize>>, Vec<Vec<usize>>);
 
-    let start = Instant::now();
-    let answer = solve_p1(&rules, &updates);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:0.1?}");
+    fn parse(input: &str) -> (HashMap<usize, Vec<usize>>, Vec<Vec<usize>>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(&input.0, &input.1)
+    }
 
-    let start = Instant::now();
-    let answer = solve_p2(&rules, &updates);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:0.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(&input.0, &input.1)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 5);
+    aoc_core::run::<Day05>(&input);
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
+use aoc_core::Solution;
 use pathfinding::matrix::Matrix;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Direction {
@@ -69,12 +71,11 @@ fn walk(grid: &Matrix<char>, start: (usize, usize), dir: Direction) -> Option<Ve
     Some(path)
 }
 
-fn solve_p1(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p1(grid: &Matrix<char>) -> usize {
     let start = grid.values().position(|&c| c == '^').unwrap();
     let start = (start / grid.columns, start % grid.columns);
 
-    let path = walk(&grid, start, Direction::Up).unwrap_or_default();
+    let path = walk(grid, start, Direction::Up).unwrap_or_default();
     let mut unique_positions = HashSet::new();
     path.iter().for_each(|p| {
         unique_positions.insert(p);
@@ -82,8 +83,8 @@ fn solve_p1(input: &str) -> usize {
     unique_positions.len()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut grid = parse_input(input);
+fn solve_p2(grid: &Matrix<char>) -> usize {
+    let mut grid = grid.clone();
     let start = grid.values().position(|&c| c == '^').unwrap();
     let start = (start / grid.columns, start % grid.columns);
 
@@ -100,18 +101,27 @@ fn solve_p2(input: &str) -> usize {
     obstacles.len()
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Matrix<char>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Matrix<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 6);
+    aoc_core::run::<Day06>(&input);
 }
 
 #[cfg(test)]
@@ -131,9 +141,10 @@ mod tests {
 
     #[test]
     fn test_solve_with_test_input() {
-        let answer = solve_p1(INPUT);
+        let grid = parse_input(INPUT);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 41);
-        let answer = solve_p2(INPUT);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 6);
     }
 }
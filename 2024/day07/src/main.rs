@@ -1,3 +1,5 @@
+use aoc_core::Solution;
+use std::fmt::Display;
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -88,19 +90,27 @@ fn solve_p2(equations: &[Equation]) -> usize {
         .sum()
 }
 
+struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Equation>;
+
+    fn parse(input: &str) -> Vec<Equation> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let equations = parse_input(input);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&equations);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&equations);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2024, 7);
+    aoc_core::run::<Day07>(&input);
 }
 
 #[cfg(test)]
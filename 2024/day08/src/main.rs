@@ -1,8 +1,9 @@
 use std::{
     collections::{HashMap, HashSet},
-    fs,
+    fmt::Display,
 };
 
+use aoc_core::Solution;
 use pathfinding::matrix::Matrix;
 
 fn parse_input(input: &str) -> Matrix<char> {
@@ -43,8 +44,7 @@ fn get_antinodes_with_harmonics(
     antinodes
 }
 
-fn solve_p1(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p1(grid: &Matrix<char>) -> usize {
     let mut antennas: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
     grid.items().for_each(|((row, col), &ch)| {
         if ch != '.' {
@@ -56,15 +56,14 @@ fn solve_p1(input: &str) -> usize {
         let num_antennas = positions.len();
         for i in 0..num_antennas {
             for j in i + 1..num_antennas {
-                antinodes.extend(get_antinodes(&grid, positions[i], positions[j]).iter());
+                antinodes.extend(get_antinodes(grid, positions[i], positions[j]).iter());
             }
         }
     });
     antinodes.len()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p2(grid: &Matrix<char>) -> usize {
     let mut antennas: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
     grid.items().for_each(|((row, col), &ch)| {
         if ch != '.' {
@@ -77,25 +76,34 @@ fn solve_p2(input: &str) -> usize {
         for i in 0..num_antennas {
             for j in i + 1..num_antennas {
                 antinodes
-                    .extend(get_antinodes_with_harmonics(&grid, positions[i], positions[j]).iter());
+                    .extend(get_antinodes_with_harmonics(grid, positions[i], positions[j]).iter());
             }
         }
     });
     antinodes.len()
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
+struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Matrix<char>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Matrix<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 8);
+    aoc_core::run::<Day08>(&input);
 }
 
 #[cfg(test)]
@@ -107,7 +115,8 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = fs::read_to_string("test.txt").unwrap();
-        let answer = solve_p1(&input);
+        let grid = parse_input(&input);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 14);
 
         let simple_test = "T.........
@@ -120,9 +129,9 @@ mod tests {
 ..........
 ..........
 ..........";
-        let answer = solve_p2(simple_test);
+        let answer = solve_p2(&parse_input(simple_test));
         assert_eq!(answer, 9);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 34);
     }
 }
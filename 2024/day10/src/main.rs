@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use pathfinding::{matrix::Matrix, prelude::dijkstra_all};
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Matrix<u32> {
     Matrix::from_rows(input.lines().map(|line| {
@@ -82,19 +84,27 @@ fn solve_p2(map: &Matrix<u32>) -> usize {
     trail_rating
 }
 
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Matrix<u32>;
+
+    fn parse(input: &str) -> Matrix<u32> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-    let map = parse_input(&input);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&map);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&map);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2024, 10);
+    aoc_core::run::<Day10>(&input);
 }
 
 #[cfg(test)]
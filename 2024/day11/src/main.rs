@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
+use aoc_core::Solution;
 use count_digits::CountDigits;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> HashMap<usize, usize> {
     input
@@ -25,8 +27,8 @@ fn process_stone(stone: usize) -> Vec<usize> {
     vec![stone * 2024]
 }
 
-fn solve(input: &str, blinks: usize) -> usize {
-    let mut stones = parse_input(input);
+fn solve(stones: &HashMap<usize, usize>, blinks: usize) -> usize {
+    let mut stones = stones.clone();
 
     (0..blinks).for_each(|_| {
         for (stone, n) in stones.drain().collect::<Vec<(usize, usize)>>() {
@@ -38,18 +40,27 @@ fn solve(input: &str, blinks: usize) -> usize {
     stones.values().sum()
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = HashMap<usize, usize>;
 
-    let start = std::time::Instant::now();
-    let answer = solve(&input, 25);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> HashMap<usize, usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve(input, 25)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve(&input, 75);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve(input, 75)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 11);
+    aoc_core::run::<Day11>(&input);
 }
 
 #[cfg(test)]
@@ -59,10 +70,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = "0 1 10 99 999".to_string();
-        let answer = solve(&input, 1);
+        let answer = solve(&parse_input(&input), 1);
         assert_eq!(answer, 7);
         let input = "125 17".to_string();
-        let answer = solve(&input, 25);
+        let answer = solve(&parse_input(&input), 25);
         assert_eq!(answer, 55312);
     }
 }
@@ -1,5 +1,9 @@
-use std::{collections::HashSet, hash::Hash};
+use std::{collections::HashSet, fmt::Display, hash::Hash};
 
+use aoc_core::{
+    geometry::{region_corners, region_perimeter},
+    Point, Solution,
+};
 use pathfinding::matrix::Matrix;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
@@ -40,124 +44,24 @@ fn process_region(
 ) -> Region {
     let region_name = grid[start];
     let mut region = Region::new(region_name);
+    let mut cells = HashSet::new();
     let mut stack = vec![start];
     while let Some(pos) = stack.pop() {
-        if visited.contains(&pos) {
+        if !visited.insert(pos) {
             continue;
         }
-
-        let mut nlen = 0;
-        grid.neighbours(pos, false).for_each(|neighbour| {
-            nlen += 1;
-            if grid[neighbour] == region_name {
-                stack.push(neighbour);
-            } else {
-                region.perimiter += 1;
-            }
-        });
-
-        // Positions at the edge of the grid have less than 4 neighbours and the grid edges still
-        // need to be counted as part of the perimiter.
-        if nlen < 4 {
-            region.perimiter += 4 - nlen;
-        }
-
-        region.corners += count_outside_corners(&grid, pos);
-        region.corners += count_inside_corners(&grid, pos);
-        region.area += 1;
-        visited.insert(pos);
-    }
-    region
-}
-
-fn count_outside_corners(grid: &Matrix<char>, pos: (usize, usize)) -> usize {
-    let (row, col) = (pos.0 as isize, pos.1 as isize);
-    let region_name = grid[pos];
-    let mut corners = 0;
-
-    // Check top left corner
-    let neighbours = [(row - 1, col), (row, col - 1)];
-    if is_corner(grid, region_name, &neighbours) {
-        corners += 1;
-    }
-
-    // Check top right corner
-    let neighbours = [(row - 1, col), (row, col + 1)];
-    if is_corner(grid, region_name, &neighbours) {
-        corners += 1;
-    }
-
-    // Check bottom left corner
-    let neighbours = [(row + 1, col), (row, col - 1)];
-    if is_corner(grid, region_name, &neighbours) {
-        corners += 1;
-    }
-
-    // Check bottom right corner
-    let neighbours = [(row + 1, col), (row, col + 1)];
-    if is_corner(grid, region_name, &neighbours) {
-        corners += 1;
-    }
-    corners
-}
-
-fn is_corner(grid: &Matrix<char>, value: char, neighbours: &[(isize, isize)]) -> bool {
-    neighbours.iter().all(|(row, col)| {
-        if *row < 0 || *row as usize > grid.rows || *col < 0 || *col as usize > grid.columns {
-            return true;
-        }
-        grid.get((*row as usize, *col as usize)) != Some(&value)
-    })
-}
-
-fn count_inside_corners(grid: &Matrix<char>, pos: (usize, usize)) -> usize {
-    let (row, col) = pos;
-    let region_name = grid[pos];
-    let mut corners = 0;
-
-    let nrows = grid.rows;
-    let ncols = grid.columns;
-
-    // Check top left corner
-    if row > 0
-        && col > 0
-        && grid[(row - 1, col)] == region_name
-        && grid[(row, col - 1)] == region_name
-        && grid[(row - 1, col - 1)] != region_name
-    {
-        corners += 1;
-    }
-
-    // Check top right corner
-    if row > 0
-        && col < ncols - 1
-        && grid[(row - 1, col)] == region_name
-        && grid[(row, col + 1)] == region_name
-        && grid[(row - 1, col + 1)] != region_name
-    {
-        corners += 1;
+        cells.insert(Point::new(pos.1 as isize, pos.0 as isize));
+        stack.extend(
+            grid.neighbours(pos, false)
+                .filter(|&neighbour| grid[neighbour] == region_name),
+        );
     }
 
-    // Check bottom left corner
-    if row < nrows - 1
-        && col > 0
-        && grid[(row + 1, col)] == region_name
-        && grid[(row, col - 1)] == region_name
-        && grid[(row + 1, col - 1)] != region_name
-    {
-        corners += 1;
-    }
-
-    // Check bottom right corner
-    if row < nrows - 1
-        && col < ncols - 1
-        && grid[(row + 1, col)] == region_name
-        && grid[(row, col + 1)] == region_name
-        && grid[(row + 1, col + 1)] != region_name
-    {
-        corners += 1;
-    }
-    corners
+    region.area = cells.len();
+    region.perimiter = region_perimeter(&cells);
+    // A region has as many sides as corners
+    region.corners = region_corners(&cells);
+    region
 }
 
 fn solve_p1(grid: &Matrix<char>) -> usize {
@@ -182,19 +86,27 @@ fn solve_p2(grid: &Matrix<char>) -> usize {
     regions.iter().map(|r| r.discount_price()).sum()
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let grid = parse_input(input);
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Matrix<char>;
+
+    fn parse(input: &str) -> Matrix<char> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&grid);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&grid);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 12);
+    aoc_core::run::<Day12>(&input);
 }
 
 #[cfg(test)]
@@ -263,41 +175,31 @@ AAAAAA";
     }
 
     #[test]
-    fn test_is_corner() {
+    fn test_process_region() {
         let grid = parse_input(TEST_INPUT_1);
-        let neighbours = [(-1, 0), (0, -1)];
-        assert_eq!(is_corner(&grid, 'A', &neighbours), true);
-        let neighbours = [(-1, 0), (0, 0)];
-        assert_eq!(is_corner(&grid, 'A', &neighbours), false);
-        let neighbours = [(-1, 3), (0, 4)];
-        assert_eq!(is_corner(&grid, 'A', &neighbours), true);
-
-        let neighbours = [(0, 0), (1, -1)];
-        assert_eq!(is_corner(&grid, 'B', &neighbours), true);
-        let neighbours = [(0, 1), (1, 2)];
-        assert_eq!(is_corner(&grid, 'B', &neighbours), true);
-        let neighbours = [(3, 0), (2, -1)];
-        assert_eq!(is_corner(&grid, 'B', &neighbours), true);
-        let neighbours = [(3, 1), (2, 2)];
-        assert_eq!(is_corner(&grid, 'B', &neighbours), true);
-        let neighbours = [(1, 1), (2, 2)];
-        assert_eq!(is_corner(&grid, 'B', &neighbours), false);
-    }
-
-    #[test]
-    fn test_count_corners() {
-        let grid = parse_input(TEST_INPUT_1);
-        assert_eq!(count_outside_corners(&grid, (0, 0)), 2);
-        assert_eq!(count_outside_corners(&grid, (0, 1)), 0);
-        assert_eq!(count_outside_corners(&grid, (0, 3)), 2);
-        assert_eq!(count_outside_corners(&grid, (1, 0)), 1);
-        assert_eq!(count_outside_corners(&grid, (2, 0)), 1);
-        assert_eq!(count_outside_corners(&grid, (1, 1)), 1);
-        assert_eq!(count_outside_corners(&grid, (2, 1)), 1);
-
-        assert_eq!(count_outside_corners(&grid, (1, 2)), 2);
-        assert_eq!(count_outside_corners(&grid, (2, 2)), 1);
-        assert_eq!(count_outside_corners(&grid, (2, 3)), 1);
-        assert_eq!(count_outside_corners(&grid, (3, 3)), 2);
+        let mut visited = HashSet::new();
+        let regions: Vec<_> = [(0, 0), (1, 0), (1, 2), (1, 3), (3, 0)]
+            .into_iter()
+            .map(|start| process_region(&grid, start, &mut visited))
+            .map(|region| (region.name, region.area, region.perimiter, region.corners))
+            .collect();
+        assert_eq!(
+            regions,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+
+        // The O region surrounds four single-cell X regions
+        let grid = parse_input(TEST_INPUT_2);
+        let region = process_region(&grid, (0, 0), &mut HashSet::new());
+        assert_eq!(
+            (region.area, region.perimiter, region.corners),
+            (21, 36, 20)
+        );
     }
 }
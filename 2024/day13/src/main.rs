@@ -1,5 +1,9 @@
-use fitting::{linalg, ndarray::array};
+use aoc_core::{
+    linalg::{to_integers, Matrix},
+    Solution,
+};
 use regex::Regex;
+use std::fmt::Display;
 
 #[derive(Debug, Default)]
 struct Machine {
@@ -30,27 +34,33 @@ fn parse_input(input: &str) -> Vec<Machine> {
 fn solve(machines: &[Machine], limit: usize, correction: usize) -> usize {
     let mut tokens = 0;
     for m in machines.iter() {
-        let coef = array![[m.a.0 as f64, m.b.0 as f64], [m.a.1 as f64, m.b.1 as f64]];
-        let rhs = array![
-            (m.prize.0 + correction) as f64,
-            (m.prize.1 + correction) as f64
+        let coef = Matrix::new(vec![
+            vec![m.a.0 as i128, m.b.0 as i128],
+            vec![m.a.1 as i128, m.b.1 as i128],
+        ]);
+        let rhs = [
+            (m.prize.0 + correction) as i128,
+            (m.prize.1 + correction) as i128,
         ];
-        let sol = linalg::solve(coef, rhs).unwrap();
-
-        if sol[0] >= 0.0 && sol[1] >= 0.0 {
-            let a_presses = sol[0].round() as usize;
-            let b_presses = sol[1].round() as usize;
-
-            // Check that the solution is correct in case of any float issues.
-            if m.a.0 * a_presses + m.b.0 * b_presses != m.prize.0 + correction
-                || m.a.1 * a_presses + m.b.1 * b_presses != m.prize.1 + correction
-                || (limit > 0 && a_presses > limit)
-                || (limit > 0 && b_presses > limit)
-            {
-                continue;
-            }
-            tokens += a_presses * 3 + b_presses;
+
+        // The prize can only be won with a whole number of presses of each button
+        let Some(presses) = coef
+            .cramer(&rhs)
+            .expect("button presses fit in an i128")
+            .and_then(|sol| to_integers(&sol))
+        else {
+            continue;
+        };
+        let (a_presses, b_presses) = (presses[0], presses[1]);
+
+        if a_presses < 0
+            || b_presses < 0
+            || (limit > 0 && a_presses > limit as i128)
+            || (limit > 0 && b_presses > limit as i128)
+        {
+            continue;
         }
+        tokens += a_presses as usize * 3 + b_presses as usize;
     }
     tokens
 }
@@ -63,19 +73,27 @@ fn solve_p2(machines: &[Machine]) -> usize {
     solve(machines, 0, 10000000000000)
 }
 
+struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Vec<Machine> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
 fn main() {
-    let input = include_str!("../input.txt");
-    let machines = parse_input(input);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&machines);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&machines);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2024, 13);
+    aoc_core::run::<Day13>(&input);
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
+use aoc_core::Solution;
 use regex::Regex;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Robot {
@@ -59,6 +61,8 @@ fn safety_factor(robots: &[Robot], bounds: (usize, usize)) -> usize {
     quadrants.iter().product()
 }
 
+const BOUNDS: (usize, usize) = (101, 103);
+
 fn parse_input(input: &str) -> Vec<Robot> {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
@@ -104,26 +108,37 @@ fn format_robots(robots: &[Robot], bounds: (usize, usize)) -> String {
         .join("\n")
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-    let robots = parse_input(&input);
-    let bounds = (101, 103);
+struct Day14;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&robots, bounds);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+impl Solution for Day14 {
+    type Input<'a> = Vec<Robot>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&robots, bounds);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<Robot> {
+        parse_input(input)
+    }
 
-    let mut robots = robots.to_vec();
-    robots.iter_mut().for_each(|robot| {
-        robot.step_n(answer, bounds);
-    });
-    println!("{}", format_robots(&robots, bounds));
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, BOUNDS)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input, BOUNDS)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 14);
+    if std::env::args().nth(1).as_deref() == Some("--picture") {
+        // Show the robots at the part 2 answer so the tree can be checked by eye.
+        let mut robots = parse_input(&input);
+        let seconds = solve_p2(&robots, BOUNDS);
+        robots.iter_mut().for_each(|robot| {
+            robot.step_n(seconds, BOUNDS);
+        });
+        println!("{}", format_robots(&robots, BOUNDS));
+        return;
+    }
+    aoc_core::run::<Day14>(&input);
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 use core::panic;
 
+use aoc_core::Solution;
 use pathfinding::matrix::Matrix;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> (Matrix<char>, String) {
     let (grid_input, movement_input) = input.split_once("\n\n").unwrap();
@@ -89,8 +91,8 @@ fn move_big_object_vertical(
     true
 }
 
-fn solve_p1(input: &str) -> usize {
-    let (mut grid, movement) = parse_input(input);
+fn solve_p1((grid, movement): &(Matrix<char>, String)) -> usize {
+    let mut grid = grid.clone();
     let robot = grid.values().position(|x| x == &'@').unwrap();
     let mut robot = (robot / grid.columns, robot % grid.columns);
 
@@ -113,9 +115,8 @@ fn solve_p1(input: &str) -> usize {
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let (grid, movement) = parse_input(input);
-    let mut grid = resize_grid(&grid);
+fn solve_p2((grid, movement): &(Matrix<char>, String)) -> usize {
+    let mut grid = resize_grid(grid);
     let robot = grid.values().position(|x| x == &'@').unwrap();
     let mut robot = (robot / grid.columns, robot % grid.columns);
 
@@ -166,18 +167,27 @@ fn display_grid(grid: &Matrix<char>) {
     }
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = (Matrix<char>, String);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (Matrix<char>, String) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 15);
+    aoc_core::run::<Day15>(&input);
 }
 
 #[cfg(test)]
@@ -187,12 +197,12 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input_small.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 2028);
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 10092);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&parse_input(&input));
         assert_eq!(answer, 9021);
     }
 }
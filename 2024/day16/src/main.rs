@@ -1,7 +1,7 @@
-use pathfinding::{
-    matrix::Matrix,
-    prelude::{astar, astar_bag},
-};
+use aoc_core::search::{astar, dijkstra_all};
+use aoc_core::Solution;
+use pathfinding::matrix::Matrix;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Direction {
@@ -70,8 +70,7 @@ fn successors(grid: &Matrix<char>, r: &Reindeer) -> Vec<(Reindeer, usize)> {
     potential_positions
 }
 
-fn solve_p1(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p1(grid: &Matrix<char>) -> usize {
     let start = grid.values().position(|&x| x == 'S').unwrap();
     let start = (start / grid.columns, start % grid.columns);
     let goal = grid.values().position(|&x| x == 'E').unwrap();
@@ -80,17 +79,16 @@ fn solve_p1(input: &str) -> usize {
     let reidneer_start = Reindeer::new(start, Direction::Right);
 
     astar(
-        &reidneer_start,
-        |r| successors(&grid, r),
+        reidneer_start,
+        |r| successors(grid, r),
         |r| distance(r.pos, goal),
         |r| r.pos == goal,
     )
+    .cost()
     .unwrap()
-    .1
 }
 
-fn solve_p2(input: &str) -> usize {
-    let grid = parse_input(input);
+fn solve_p2(grid: &Matrix<char>) -> usize {
     let start = grid.values().position(|&x| x == 'S').unwrap();
     let start = (start / grid.columns, start % grid.columns);
     let goal = grid.values().position(|&x| x == 'E').unwrap();
@@ -98,34 +96,36 @@ fn solve_p2(input: &str) -> usize {
 
     let reidneer_start = Reindeer::new(start, Direction::Right);
 
-    let paths = astar_bag(
-        &reidneer_start,
-        |r| successors(&grid, r),
-        |r| distance(r.pos, goal),
-        |r| r.pos == goal,
-    )
-    .unwrap();
-    let mut unique_positions = std::collections::HashSet::new();
-    for path in paths.0 {
-        for r in path {
-            unique_positions.insert(r.pos);
-        }
-    }
+    let paths = dijkstra_all(reidneer_start, |r| successors(grid, r), |r| r.pos == goal);
+    let unique_positions: std::collections::HashSet<_> = paths
+        .nodes_on_best_paths()
+        .into_iter()
+        .map(|r| r.pos)
+        .collect();
     unique_positions.len()
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Matrix<char>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Matrix<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 16);
+    aoc_core::run::<Day16>(&input);
 }
 
 #[cfg(test)]
@@ -136,13 +136,15 @@ mod tests {
     fn test_solve_with_test_input() {
         let test_input_1 = std::fs::read_to_string("test_input_1.txt").unwrap();
         let test_input_2 = std::fs::read_to_string("test_input_2.txt").unwrap();
-        let answer = solve_p1(&test_input_1);
+        let grid = parse_input(&test_input_1);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 7036);
-        let answer = solve_p1(&test_input_2);
+        let data = parse_input(&test_input_2);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 11048);
-        let answer = solve_p2(&test_input_1);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 45);
-        let answer = solve_p2(&test_input_2);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 64);
     }
 }
@@ -1,98 +1,53 @@
+use aoc_core::vm::{Flow, Machine, Registers, TraceEntry};
+use aoc_core::Solution;
 use std::fmt;
+use std::fmt::Display;
 
-#[derive(Debug, Default, Clone)]
-struct Computer {
-    instructions: Vec<Instruction>,
-    pc: usize,
-    a: usize,
-    b: usize,
-    c: usize,
-}
+const A: usize = 0;
+const B: usize = 1;
+const C: usize = 2;
 
-impl Computer {
-    fn new() -> Self {
-        Self::default()
-    }
+type Computer = Machine<Instruction>;
 
-    fn run(&mut self) -> String {
-        let mut output = String::new();
-        while self.pc < self.instructions.len() {
-            let instr = &self.instructions[self.pc];
-            // print!(
-            //     "[a={} b={} c={}] {}: {};",
-            //     self.a, self.b, self.c, self.pc, instr
-            // );
-            match instr.opcode {
-                Opcode::Adv => {
-                    let operand = self.combo(instr.operand);
-                    self.a /= usize::pow(2, operand as u32);
-                    self.pc += 1;
-                }
-                Opcode::Bxl => {
-                    self.b ^= instr.operand as usize;
-                    self.pc += 1;
-                }
-                Opcode::Bst => {
-                    let operand = self.combo(instr.operand);
-                    self.b = operand % 8;
-                    self.pc += 1;
-                }
-                Opcode::Jnz => {
-                    if self.a != 0 {
-                        self.pc = instr.operand as usize;
-                    } else {
-                        self.pc += 1;
-                    }
-                }
-                Opcode::Bxc => {
-                    self.b ^= self.c;
-                    self.pc += 1;
-                }
-                Opcode::Out => {
-                    let operand = self.combo(instr.operand);
-                    if !output.is_empty() {
-                        output.push(',');
-                    }
-                    output.push_str((operand % 8).to_string().as_str());
-                    self.pc += 1;
-                }
-                Opcode::Bdv => {
-                    let operand = self.combo(instr.operand);
-                    self.b = self.a / usize::pow(2, operand as u32);
-                    self.pc += 1;
-                }
-                Opcode::Cdv => {
-                    let operand = self.combo(instr.operand);
-                    self.c = self.a / usize::pow(2, operand as u32);
-                    self.pc += 1;
-                }
-            }
-        }
-        output
-    }
+/// Run the computer to the end and return its output.
+fn run(computer: &mut Computer) -> String {
+    computer.run();
+    computer
+        .output()
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
 
-    fn combo(&self, operand: u32) -> usize {
-        match operand {
-            0..=3 => operand as usize,
-            4 => self.a,
-            5 => self.b,
-            6 => self.c,
-            _ => panic!("Invalid operand"),
-        }
+fn combo(operand: u32, regs: &Registers<usize, 3>) -> usize {
+    match operand {
+        0..=3 => operand as usize,
+        4 => regs[A],
+        5 => regs[B],
+        6 => regs[C],
+        _ => panic!("Invalid operand"),
     }
 }
 
-impl fmt::Display for Computer {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
-            f,
-            "Computer {{ a: {}, b: {}, c: {}, pc: {} }}",
-            self.a, self.b, self.c, self.pc
-        )?;
-        for instr in &self.instructions {
-            writeln!(f, "{}", instr)?;
+impl aoc_core::vm::Instruction for Instruction {
+    type Registers = Registers<usize, 3>;
+    type Output = usize;
+
+    fn execute(&self, regs: &mut Self::Registers, out: &mut Vec<usize>) -> Flow {
+        let operand = self.operand;
+        match self.opcode {
+            Opcode::Adv => regs[A] /= usize::pow(2, combo(operand, regs) as u32),
+            Opcode::Bxl => regs[B] ^= operand as usize,
+            Opcode::Bst => regs[B] = combo(operand, regs) % 8,
+            Opcode::Jnz if regs[A] != 0 => return Flow::Goto(operand as usize / 2),
+            Opcode::Jnz => {}
+            Opcode::Bxc => regs[B] ^= regs[C],
+            Opcode::Out => out.push(combo(operand, regs) % 8),
+            Opcode::Bdv => regs[B] = regs[A] / usize::pow(2, combo(operand, regs) as u32),
+            Opcode::Cdv => regs[C] = regs[A] / usize::pow(2, combo(operand, regs) as u32),
         }
-        Ok(())
+        Flow::Next
     }
 }
 
@@ -107,6 +62,60 @@ impl Instruction {
         let opcode = Opcode::from(opcode);
         Self { opcode, operand }
     }
+
+    /// The opcode and operand as they appear in the program.
+    fn code(&self) -> [usize; 2] {
+        [self.opcode as usize, self.operand as usize]
+    }
+
+    /// The instruction as a line of pseudo-code, e.g. `a = a >> 3` or `out(b % 8)`.
+    fn pseudo_code(&self) -> String {
+        let literal = self.operand;
+        let combo = match self.operand {
+            0..=3 => self.operand.to_string(),
+            4 => "a".to_string(),
+            5 => "b".to_string(),
+            6 => "c".to_string(),
+            _ => "?".to_string(),
+        };
+        match self.opcode {
+            Opcode::Adv => format!("a = a >> {combo}"),
+            Opcode::Bxl => format!("b = b ^ {literal}"),
+            Opcode::Bst => format!("b = {combo} % 8"),
+            Opcode::Jnz => format!("if a != 0 goto {literal}"),
+            Opcode::Bxc => "b = b ^ c".to_string(),
+            Opcode::Out => format!("out({combo} % 8)"),
+            Opcode::Bdv => format!("b = a >> {combo}"),
+            Opcode::Cdv => format!("c = a >> {combo}"),
+        }
+    }
+}
+
+/// List the program as pseudo-code, one instruction per line with its address.
+fn disassemble(program: &[Instruction]) -> String {
+    program
+        .iter()
+        .enumerate()
+        .map(|(i, instr)| format!("{:>2}: {}\n", 2 * i, instr.pseudo_code()))
+        .collect()
+}
+
+/// Show each executed instruction with the registers before it ran.
+fn format_trace(trace: &[TraceEntry<Instruction>]) -> String {
+    trace
+        .iter()
+        .map(|entry| {
+            format!(
+                "{:>5} {:>2}: {:<18} a={} b={} c={}\n",
+                entry.step,
+                2 * entry.pc,
+                entry.instr.pseudo_code(),
+                entry.regs[A],
+                entry.regs[B],
+                entry.regs[C]
+            )
+        })
+        .collect()
 }
 
 impl fmt::Display for Instruction {
@@ -115,7 +124,7 @@ impl fmt::Display for Instruction {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum Opcode {
     Adv,
     Bxl,
@@ -144,106 +153,121 @@ impl Opcode {
 }
 
 fn parse_input(input: &str) -> Computer {
-    let mut computer = Computer::new();
     let mut line_iter = input.lines();
-    computer.a = line_iter
-        .next()
-        .unwrap()
-        .split_whitespace()
-        .last()
-        .unwrap()
-        .parse()
-        .unwrap();
-    computer.b = line_iter
-        .next()
-        .unwrap()
-        .split_whitespace()
-        .last()
-        .unwrap()
-        .parse()
-        .unwrap();
-    computer.c = line_iter
-        .next()
-        .unwrap()
-        .split_whitespace()
-        .last()
-        .unwrap()
-        .parse()
-        .unwrap();
+    let mut regs = Registers::default();
+    for reg in [A, B, C] {
+        regs[reg] = line_iter
+            .next()
+            .unwrap()
+            .split_whitespace()
+            .last()
+            .unwrap()
+            .parse()
+            .unwrap();
+    }
 
     line_iter.next(); // Blank line separating the registers and instructions
 
     let program_data = line_iter.next().unwrap().split_once(" ").unwrap().1;
-    program_data
+    let instructions = program_data
         .split(',')
         .collect::<Vec<_>>()
         .chunks(2)
-        .for_each(|chunk| {
+        .map(|chunk| {
             let opcode = chunk[0].parse().unwrap();
             let operand = chunk[1].parse().unwrap();
-            computer
-                .instructions
-                .push(Instruction::new(opcode, operand));
-        });
-    computer
+            Instruction::new(opcode, operand)
+        })
+        .collect();
+    Computer::new(instructions, regs)
+}
+
+/// Find the smallest value of register A that makes the program output itself.
+///
+/// This works for programs that loop until A is zero, shifting A right by a constant `k` once
+/// per loop and printing one value each time. The last value printed then only depends on the
+/// top `k` bits of A, the one before it on the top `2k` bits, and so on, so A can be built
+/// up `k` bits at a time by matching the output from the end. Returns `None` if the program
+/// doesn't have that shape or no value works.
+fn find_quine(computer: &Computer) -> Option<usize> {
+    let program = computer.program();
+    let [.., last] = program else {
+        return None;
+    };
+    let shifts: Vec<u32> = program
+        .iter()
+        .filter(|instr| instr.opcode == Opcode::Adv)
+        .map(|instr| instr.operand)
+        .collect();
+    let ([shift @ 1..=3], [3, 0]) = (shifts.as_slice(), last.code()) else {
+        return None;
+    };
+    let target: Vec<usize> = program.iter().flat_map(Instruction::code).collect();
+    let initial = *computer.regs();
+    let mut computer = computer.clone();
+    quine_search(&mut computer, initial, &target, *shift, 0, 1)
+}
+
+fn quine_search(
+    computer: &mut Computer,
+    initial: Registers<usize, 3>,
+    target: &[usize],
+    shift: u32,
+    a: usize,
+    n: usize,
+) -> Option<usize> {
+    if n > target.len() {
+        return Some(a);
+    }
+    (0..1 << shift).find_map(|bits| {
+        let a = a << shift | bits;
+        computer.reset(Registers::new([a, initial[B], initial[C]]));
+        computer.run();
+        if computer.output() != &target[target.len() - n..] {
+            return None;
+        }
+        quine_search(computer, initial, target, shift, a, n + 1)
+    })
 }
 
-fn parse_program(input: &str) -> String {
-    input
-        .lines()
-        .nth(4)
-        .unwrap()
-        .split_once(" ")
-        .unwrap()
-        .1
-        .to_string()
+fn solve_p1(computer: &Computer) -> String {
+    let mut computer = computer.clone();
+    run(&mut computer)
 }
 
-fn solve_p1(input: &str) -> String {
-    let mut computer = parse_input(input);
-    computer.run()
+fn solve_p2(computer: &Computer) -> usize {
+    find_quine(computer).expect("No value of A makes the program output itself")
 }
 
-fn solve_p2(input: &str) -> usize {
-    let computer = parse_input(input);
-    let program_str = parse_program(input);
+struct Day17;
 
-    let program: Vec<usize> = program_str
-        .split(',')
-        .map(|x| x.parse().unwrap())
-        .collect::<Vec<_>>();
-
-    let mut a = 0;
-    for n in 1..=program.len() {
-        let target = program[program.len() - n..].to_vec();
-        let mut new_a = a << 3;
-        loop {
-            let mut comp = computer.clone();
-            comp.a = new_a;
-            let output = comp.run();
-            let output: Vec<usize> = output.split(',').map(|x| x.parse().unwrap()).collect();
-            if output == target {
-                a = new_a;
-                break;
-            }
-            new_a += 1;
-        }
+impl Solution for Day17 {
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Computer {
+        parse_input(input)
     }
-    a
-}
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 17);
+    match std::env::args().nth(1).as_deref() {
+        Some("--disassemble") => print!("{}", disassemble(parse_input(&input).program())),
+        Some("--trace") => {
+            let mut computer = parse_input(&input).with_trace();
+            computer.run();
+            print!("{}", format_trace(computer.trace().unwrap()));
+        }
+        _ => aoc_core::run::<Day17>(&input),
+    }
 }
 
 #[cfg(test)]
@@ -253,65 +277,108 @@ mod tests {
     #[test]
     fn test_solve_p1_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, "4,6,3,5,6,3,5,2,1,0");
     }
 
     #[test]
     fn test_solve_p2_with_test_input() {
         let input = std::fs::read_to_string("test_input_2.txt").unwrap();
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&parse_input(&input));
         assert_eq!(answer, 117440);
     }
 
     #[test]
     fn test_bst() {
-        let mut computer = Computer::new();
-        computer.c = 9;
-        computer.instructions.push(Instruction::new(2, 6));
-        computer.run();
-        assert_eq!(computer.b, 1);
+        let mut computer = Computer::new(vec![Instruction::new(2, 6)], Registers::default());
+        computer.regs_mut()[C] = 9;
+        run(&mut computer);
+        assert_eq!(computer.regs()[B], 1);
     }
 
     #[test]
     fn test_out() {
-        let mut computer = Computer::new();
-        computer.a = 10;
-        computer.instructions.push(Instruction::new(5, 0));
-        computer.instructions.push(Instruction::new(5, 1));
-        computer.instructions.push(Instruction::new(5, 4));
-        let output = computer.run();
+        let mut computer = Computer::new(
+            vec![
+                Instruction::new(5, 0),
+                Instruction::new(5, 1),
+                Instruction::new(5, 4),
+            ],
+            Registers::default(),
+        );
+        computer.regs_mut()[A] = 10;
+        let output = run(&mut computer);
         assert_eq!(output, "0,1,2");
     }
 
     #[test]
     fn test_computer_run1() {
-        let mut computer = Computer::new();
-        computer.a = 2024;
-        computer.instructions.push(Instruction::new(0, 1));
-        computer.instructions.push(Instruction::new(5, 4));
-        computer.instructions.push(Instruction::new(3, 0));
-        let output = computer.run();
+        let mut computer = Computer::new(
+            vec![
+                Instruction::new(0, 1),
+                Instruction::new(5, 4),
+                Instruction::new(3, 0),
+            ],
+            Registers::default(),
+        );
+        computer.regs_mut()[A] = 2024;
+        let output = run(&mut computer);
         assert_eq!(output, "4,2,5,6,7,7,7,7,3,1,0");
-        assert_eq!(computer.a, 0);
+        assert_eq!(computer.regs()[A], 0);
     }
 
     #[test]
     fn test_computer_run2() {
-        let mut computer = Computer::new();
-        computer.b = 29;
-        computer.instructions.push(Instruction::new(1, 7));
-        computer.run();
-        assert_eq!(computer.b, 26);
+        let mut computer = Computer::new(vec![Instruction::new(1, 7)], Registers::default());
+        computer.regs_mut()[B] = 29;
+        run(&mut computer);
+        assert_eq!(computer.regs()[B], 26);
     }
 
     #[test]
     fn test_computer_run3() {
-        let mut computer = Computer::new();
-        computer.b = 2024;
-        computer.c = 43690;
-        computer.instructions.push(Instruction::new(4, 0));
+        let mut computer = Computer::new(vec![Instruction::new(4, 0)], Registers::default());
+        computer.regs_mut()[B] = 2024;
+        computer.regs_mut()[C] = 43690;
+        run(&mut computer);
+        assert_eq!(computer.regs()[B], 44354);
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let computer = parse_input(&std::fs::read_to_string("test_input_2.txt").unwrap());
+        assert_eq!(
+            disassemble(computer.program()),
+            " 0: a = a >> 3\n 2: out(a % 8)\n 4: if a != 0 goto 0\n"
+        );
+        assert_eq!(Instruction::new(7, 5).pseudo_code(), "c = a >> b");
+        assert_eq!(Instruction::new(1, 7).pseudo_code(), "b = b ^ 7");
+    }
+
+    #[test]
+    fn test_trace() {
+        let input = std::fs::read_to_string("test_input.txt").unwrap();
+        let mut computer = parse_input(&input).with_trace();
         computer.run();
-        assert_eq!(computer.b, 44354);
+        let trace = format_trace(computer.trace().unwrap());
+        let lines: Vec<&str> = trace.lines().collect();
+        assert_eq!(lines.len(), 30);
+        assert_eq!(lines[0], "    0  0: a = a >> 1         a=729 b=0 c=0");
+        assert_eq!(lines[1], "    1  2: out(a % 8)         a=364 b=0 c=0");
+    }
+
+    #[test]
+    fn test_find_quine() {
+        let input = std::fs::read_to_string("test_input_2.txt").unwrap();
+        assert_eq!(find_quine(&parse_input(&input)), Some(117440));
+
+        let program = |code: &str| {
+            parse_input(&format!(
+                "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {code}"
+            ))
+        };
+        assert_eq!(find_quine(&program("0,2,5,4,3,0")), None);
+        assert_eq!(find_quine(&program("5,4,3,0")), None);
+        assert_eq!(find_quine(&program("0,3,5,4")), None);
     }
 }
@@ -1,5 +1,7 @@
+use aoc_core::Solution;
 use pathfinding::matrix::Matrix;
 use pathfinding::prelude::astar;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<(usize, usize)> {
     input
@@ -22,8 +24,7 @@ fn distance(pos: &(usize, usize), goal: &(usize, usize)) -> usize {
     pos.0.abs_diff(goal.0) + pos.1.abs_diff(goal.1)
 }
 
-fn solve_p1(input: &str, bound: usize, block_count: usize) -> usize {
-    let data = parse_input(input);
+fn solve_p1(data: &[(usize, usize)], bound: usize, block_count: usize) -> usize {
     let mut matrix: Matrix<char> = Matrix::new(bound + 1, bound + 1, '.');
     for (x, y) in &data[..block_count] {
         matrix[(*y, *x)] = '#';
@@ -40,8 +41,7 @@ fn solve_p1(input: &str, bound: usize, block_count: usize) -> usize {
     path.1
 }
 
-fn solve_p2(input: &str, bound: usize, start_check: usize) -> (usize, usize) {
-    let data = parse_input(input);
+fn solve_p2(data: &[(usize, usize)], bound: usize, start_check: usize) -> (usize, usize) {
     let mut matrix: Matrix<char> = Matrix::new(bound + 1, bound + 1, '.');
     for (x, y) in &data[..start_check] {
         matrix[(*y, *x)] = '#';
@@ -66,18 +66,28 @@ fn solve_p2(input: &str, bound: usize, start_check: usize) -> (usize, usize) {
     (0, 0)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<(usize, usize)>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input, 70, 1024);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<(usize, usize)> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, 70, 1024)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input, 70, 1024);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer:?}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        let (x, y) = solve_p2(input, 70, 1024);
+        format!("{x},{y}")
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 18);
+    aoc_core::run::<Day18>(&input);
 }
 
 #[cfg(test)]
@@ -87,9 +97,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input, 6, 12);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data, 6, 12);
         assert_eq!(answer, 22);
-        let answer = solve_p2(&input, 6, 12);
+        let answer = solve_p2(&data, 6, 12);
         assert_eq!(answer, (6, 1));
     }
 }
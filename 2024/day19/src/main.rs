@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[cfg(windows)]
 const DOUBLE_NEWLINE: &str = "\r\n\r\n";
@@ -56,19 +58,27 @@ fn solve_p2(towels: &[String], designs: &[String]) -> usize {
         .sum()
 }
 
+struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (Vec<String>, Vec<String>);
+
+    fn parse(input: &str) -> (Vec<String>, Vec<String>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(&input.0, &input.1)
+    }
+}
+
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-    let (towels, designs) = parse_input(&input);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&towels, &designs);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&towels, &designs);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2024, 19);
+    aoc_core::run::<Day19>(&input);
 }
 
 #[cfg(test)]
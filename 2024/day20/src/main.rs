@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
+use aoc_core::Solution;
 use pathfinding::{matrix::Matrix, prelude::dijkstra};
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Matrix<char> {
     Matrix::from_rows(
@@ -111,19 +113,27 @@ fn solve_p2(grid: &Matrix<char>, threshold: usize) -> usize {
         .sum()
 }
 
+struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Matrix<char>;
+
+    fn parse(input: &str) -> Matrix<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, 100)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input, 100)
+    }
+}
+
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-    let grid = parse_input(&input);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&grid, 100);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&grid, 100);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2024, 20);
+    aoc_core::run::<Day20>(&input);
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 // 7  8  9     0  1  2
 // 4  5  6     3  4  5
@@ -128,28 +130,35 @@ fn solve(codes: &[String], chain_len: usize) -> usize {
     complexity
 }
 
-fn solve_p1(input: &str) -> usize {
-    let codes = parse_input(input);
-    solve(&codes, 2)
+fn solve_p1(codes: &[String]) -> usize {
+    solve(codes, 2)
 }
 
-fn solve_p2(input: &str) -> usize {
-    let codes = parse_input(input);
-    solve(&codes, 25)
+fn solve_p2(codes: &[String]) -> usize {
+    solve(codes, 25)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Vec<String>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 21);
+    aoc_core::run::<Day21>(&input);
 }
 
 #[cfg(test)]
@@ -159,7 +168,7 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 126384);
     }
 }
@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<usize> {
     input.lines().map(|line| line.parse().unwrap()).collect()
@@ -58,18 +60,16 @@ fn generate_sequences(
     banana_count
 }
 
-fn solve_p1(input: &str) -> usize {
+fn solve_p1(secrets: &[usize]) -> usize {
     let iterations = 2000;
-    let secrets = parse_input(input);
     secrets
         .iter()
         .map(|s| generate_secret(*s, iterations))
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
+fn solve_p2(secrets: &[usize]) -> usize {
     let iterations = 2000;
-    let secrets = parse_input(input);
 
     let mut banana_count = HashMap::<(isize, isize, isize, isize), usize>::new();
 
@@ -84,18 +84,27 @@ fn solve_p2(input: &str) -> usize {
         .1
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<usize>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<usize> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 22);
+    aoc_core::run::<Day22>(&input);
 }
 
 #[cfg(test)]
@@ -105,10 +114,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 37327623);
         let input = std::fs::read_to_string("test_input2.txt").unwrap();
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&parse_input(&input));
         assert_eq!(answer, 23);
     }
 
@@ -1,5 +1,7 @@
+use aoc_core::Solution;
 use std::{
     collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
     iter::once,
 };
 
@@ -51,10 +53,9 @@ fn dfs<'a>(
     }
 }
 
-fn solve_p1(input: &str) -> usize {
-    let (connections, tnames) = parse_input(input);
+fn solve_p1((connections, tnames): &(HashMap<&str, HashSet<&str>>, HashSet<&str>)) -> usize {
     let mut total = HashSet::new();
-    let mut queue: VecDeque<_> = tnames.into_iter().map(|n| (n, vec![n])).collect();
+    let mut queue: VecDeque<_> = tnames.iter().map(|&n| (n, vec![n])).collect();
     while let Some((name, mut path)) = queue.pop_front() {
         if path.len() == 3 {
             path.sort_unstable();
@@ -72,14 +73,13 @@ fn solve_p1(input: &str) -> usize {
     total.len()
 }
 
-fn solve_p2(input: &str) -> String {
-    let (connections, _) = parse_input(input);
+fn solve_p2((connections, _): &(HashMap<&str, HashSet<&str>>, HashSet<&str>)) -> String {
     let mut res = HashSet::new();
     dfs(
         Default::default(),
         connections.keys().copied().collect(),
         Default::default(),
-        &connections,
+        connections,
         &mut res,
     );
     let mut res_vec: Vec<_> = res.into_iter().collect();
@@ -87,18 +87,27 @@ fn solve_p2(input: &str) -> String {
     res_vec.join(",")
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = (HashMap<&'a str, HashSet<&'a str>>, HashSet<&'a str>);
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> (HashMap<&str, HashSet<&str>>, HashSet<&str>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 23);
+    aoc_core::run::<Day23>(&input);
 }
 
 #[cfg(test)]
@@ -108,9 +117,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 7);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, "co,de,ka,ta");
     }
 }
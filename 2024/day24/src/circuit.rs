@@ -0,0 +1,387 @@
+//! Boolean circuits built from the puzzle's gates, with tools to check that a circuit is a
+//! ripple-carry adder and to repair one whose gate outputs have been swapped.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use aoc_core::rng::XorShift;
+
+use crate::{Gate, Operation};
+
+/// A gate, with its wires as indices into the circuit's wire names.
+#[derive(Debug, Clone, Copy)]
+struct Connection {
+    a: usize,
+    b: usize,
+    operation: Operation,
+    out: usize,
+}
+
+/// A gate-level netlist. The inputs are the `x` and `y` wires, read as binary numbers with
+/// bit 0 in `x00`, and the output is the number on the `z` wires.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    names: Vec<String>,
+    gates: Vec<Connection>,
+    /// The gate driving each wire, if any.
+    drivers: Vec<Option<usize>>,
+    /// The gate for each pair of inputs, smaller index first, and operation.
+    by_inputs: HashMap<(usize, usize, Operation), usize>,
+    xs: Vec<usize>,
+    ys: Vec<usize>,
+    zs: Vec<usize>,
+}
+
+/// An output swap that put a full-adder stage right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair {
+    pub stage: usize,
+    pub wires: [String; 2],
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stage {}: swapped {} and {}",
+            self.stage, self.wires[0], self.wires[1]
+        )
+    }
+}
+
+/// Number of random additions tried before a repaired circuit is accepted.
+const TRIALS: usize = 100;
+
+impl Circuit {
+    pub fn new(gates: &HashMap<String, Gate>) -> Self {
+        let mut names: Vec<String> = gates.keys().cloned().collect();
+        names.sort();
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        let connections: Vec<Connection> = names
+            .iter()
+            .filter_map(|name| {
+                let gate = &gates[name];
+                Some(Connection {
+                    a: index[gate.in_a.as_deref()?],
+                    b: index[gate.in_b.as_deref()?],
+                    operation: gate.operation?,
+                    out: index[name.as_str()],
+                })
+            })
+            .collect();
+        let by_inputs = connections
+            .iter()
+            .enumerate()
+            .map(|(g, c)| ((c.a.min(c.b), c.a.max(c.b), c.operation), g))
+            .collect();
+        let starting = |prefix: char| -> Vec<usize> {
+            (0..names.len())
+                .filter(|&i| names[i].starts_with(prefix))
+                .collect()
+        };
+        let (xs, ys, zs) = (starting('x'), starting('y'), starting('z'));
+        assert!(
+            xs.len().max(ys.len()) <= 64 && zs.len() <= 128,
+            "inputs wider than 64 bits"
+        );
+
+        let mut circuit = Circuit {
+            drivers: vec![None; names.len()],
+            names,
+            gates: connections,
+            by_inputs,
+            xs,
+            ys,
+            zs,
+        };
+        circuit.update_drivers();
+        circuit
+    }
+
+    fn update_drivers(&mut self) {
+        self.drivers.fill(None);
+        for (g, gate) in self.gates.iter().enumerate() {
+            self.drivers[gate.out] = Some(g);
+        }
+    }
+
+    /// The number of bits in each input.
+    pub fn bits(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Swaps the outputs of the gates driving two wires. Panics if either isn't a gate output.
+    #[cfg(test)]
+    fn swap_outputs(&mut self, a: &str, b: &str) {
+        let driver = |name: &str| {
+            let wire = self.names.iter().position(|n| n == name);
+            wire.and_then(|w| self.drivers[w])
+                .unwrap_or_else(|| panic!("{name} isn't driven by a gate"))
+        };
+        let (a, b) = (driver(a), driver(b));
+        self.swap_gates(a, b);
+    }
+
+    fn swap_gates(&mut self, a: usize, b: usize) {
+        let out = self.gates[a].out;
+        self.gates[a].out = self.gates[b].out;
+        self.gates[b].out = out;
+        self.drivers[self.gates[a].out] = Some(a);
+        self.drivers[self.gates[b].out] = Some(b);
+    }
+
+    /// Adds `x` and `y` by simulating the gates. Returns `None` if an output depends on a loop
+    /// of gates or on a wire that nothing drives.
+    pub fn evaluate(&self, x: u64, y: u64) -> Option<u128> {
+        let mut values = vec![None; self.names.len()];
+        for (i, (&xw, &yw)) in self.xs.iter().zip(&self.ys).enumerate() {
+            values[xw] = Some(x >> i & 1 == 1);
+            values[yw] = Some(y >> i & 1 == 1);
+        }
+        let mut visiting = vec![false; self.names.len()];
+        self.zs.iter().enumerate().try_fold(0, |z, (i, &wire)| {
+            let bit = self.value(wire, &mut values, &mut visiting)?;
+            Some(z | (bit as u128) << i)
+        })
+    }
+
+    fn value(
+        &self,
+        wire: usize,
+        values: &mut [Option<bool>],
+        visiting: &mut [bool],
+    ) -> Option<bool> {
+        if let Some(value) = values[wire] {
+            return Some(value);
+        }
+        if visiting[wire] {
+            return None;
+        }
+        visiting[wire] = true;
+        let gate = self.gates[self.drivers[wire]?];
+        let a = self.value(gate.a, values, visiting)?;
+        let b = self.value(gate.b, values, visiting)?;
+        let value = match gate.operation {
+            Operation::And => a & b,
+            Operation::Or => a | b,
+            Operation::Xor => a ^ b,
+        };
+        values[wire] = Some(value);
+        Some(value)
+    }
+
+    /// Whether the circuit adds correctly, on the edge cases and a run of pseudo-random inputs.
+    pub fn adds_correctly(&self) -> bool {
+        let mask = u64::MAX.checked_shr(64 - self.bits() as u32).unwrap_or(0);
+        let mut rng = XorShift::new(0x2545f4914f6cdd1d);
+        let mut random = move || rng.next_u64() & mask;
+        [(0, 0), (mask, mask), (mask, 1), (1, mask)]
+            .into_iter()
+            .chain((0..TRIALS).map(|_| (random(), random())))
+            .all(|(x, y)| self.evaluate(x, y) == Some(x as u128 + y as u128))
+    }
+
+    /// The output of the gate combining `a` and `b` with `operation`.
+    fn find(&self, a: usize, b: usize, operation: Operation) -> Option<usize> {
+        let gate = self.by_inputs.get(&(a.min(b), a.max(b), operation))?;
+        Some(self.gates[*gate].out)
+    }
+
+    /// Checks one full-adder stage, given its carry in, and returns its carry out. Stage 0 is
+    /// a half adder, `z = x ^ y` and `carry = x & y`. The other stages compute
+    /// `sum = x ^ y`, `z = sum ^ carry` and `carry = (x & y) | (sum & carry)`.
+    fn check_stage(&self, i: usize, carry: Option<usize>) -> Option<usize> {
+        let (x, y, z) = (self.xs[i], self.ys[i], *self.zs.get(i)?);
+        let sum = self.find(x, y, Operation::Xor)?;
+        let generate = self.find(x, y, Operation::And)?;
+        let Some(carry) = carry else {
+            return (sum == z).then_some(generate);
+        };
+        if self.find(sum, carry, Operation::Xor)? != z {
+            return None;
+        }
+        let propagate = self.find(sum, carry, Operation::And)?;
+        self.find(generate, propagate, Operation::Or)
+    }
+
+    /// Checks the stages in order, returning the first one that isn't wired as a ripple-carry
+    /// adder along with its carry in. Stage `bits()` is the final carry, which should be the
+    /// top `z` bit.
+    fn check(&self) -> Result<(), (usize, Option<usize>)> {
+        let mut carry = None;
+        for i in 0..self.bits() {
+            carry = Some(self.check_stage(i, carry).ok_or((i, carry))?);
+        }
+        if carry.is_some() && carry == self.zs.get(self.bits()).copied() {
+            Ok(())
+        } else {
+            Err((self.bits(), carry))
+        }
+    }
+
+    /// The first full-adder stage that is wired wrongly, if any.
+    pub fn first_faulty_stage(&self) -> Option<usize> {
+        self.check().err().map(|(stage, _)| stage)
+    }
+
+    /// The gates a stage is built from: those reading its input bits or carry in, the gates
+    /// reading their outputs in turn, and whatever drives its `z` bit.
+    fn stage_gates(&self, stage: usize, carry: Option<usize>) -> BTreeSet<usize> {
+        let mut wires: BTreeSet<usize> = self.xs.get(stage).into_iter().copied().collect();
+        wires.extend(self.ys.get(stage));
+        wires.extend(carry);
+        let mut gates: BTreeSet<usize> = self
+            .zs
+            .get(stage)
+            .and_then(|&z| self.drivers[z])
+            .into_iter()
+            .collect();
+        for _ in 0..3 {
+            let next: Vec<usize> = (0..self.gates.len())
+                .filter(|&g| wires.contains(&self.gates[g].a) || wires.contains(&self.gates[g].b))
+                .collect();
+            wires.extend(next.iter().map(|&g| self.gates[g].out));
+            gates.extend(next);
+        }
+        gates
+    }
+
+    /// Finds the fewest output swaps, up to `max_swaps`, that turn the circuit into a
+    /// ripple-carry adder which adds correctly. Each swap fixes the first faulty stage at
+    /// the time it is made.
+    pub fn repair(&self, max_swaps: usize) -> Option<Vec<Repair>> {
+        (0..=max_swaps).find_map(|limit| {
+            let mut circuit = self.clone();
+            let mut repairs = vec![];
+            circuit.search(limit, &mut repairs).then_some(repairs)
+        })
+    }
+
+    fn search(&mut self, swaps_left: usize, repairs: &mut Vec<Repair>) -> bool {
+        let (stage, carry) = match self.check() {
+            Ok(()) => return self.adds_correctly(),
+            Err(fault) => fault,
+        };
+        if swaps_left == 0 {
+            return false;
+        }
+        for a in self.stage_gates(stage, carry) {
+            for b in (0..self.gates.len()).filter(|&b| b != a) {
+                self.swap_gates(a, b);
+                let fixed = self.first_faulty_stage().is_none_or(|next| next > stage);
+                if fixed {
+                    let mut wires =
+                        [self.gates[a].out, self.gates[b].out].map(|wire| self.names[wire].clone());
+                    wires.sort();
+                    repairs.push(Repair { stage, wires });
+                    if self.search(swaps_left - 1, repairs) {
+                        return true;
+                    }
+                    repairs.pop();
+                }
+                self.swap_gates(a, b);
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    /// A ripple-carry adder in puzzle format, with `s`, `d`, `e` and `c` wires for each stage's
+    /// sum, generate, propagate and carry.
+    fn adder(bits: usize) -> String {
+        let mut input = String::new();
+        for i in 0..bits {
+            input += &format!("x{i:02}: 0\ny{i:02}: 0\n");
+        }
+        input += "\n";
+        input += "x00 XOR y00 -> z00\nx00 AND y00 -> c00\n";
+        for i in 1..bits {
+            let p = i - 1;
+            let carry = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            input += &format!(
+                "x{i:02} XOR y{i:02} -> s{i:02}\n\
+                 y{i:02} AND x{i:02} -> d{i:02}\n\
+                 c{p:02} XOR s{i:02} -> z{i:02}\n\
+                 s{i:02} AND c{p:02} -> e{i:02}\n\
+                 d{i:02} OR e{i:02} -> {carry}\n"
+            );
+        }
+        input
+    }
+
+    fn circuit(bits: usize) -> Circuit {
+        Circuit::new(&parse_input(&adder(bits)))
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let circuit = circuit(8);
+        assert_eq!(circuit.bits(), 8);
+        assert_eq!(circuit.evaluate(0, 0), Some(0));
+        assert_eq!(circuit.evaluate(200, 100), Some(300));
+        assert_eq!(circuit.evaluate(255, 255), Some(510));
+        assert!(circuit.adds_correctly());
+        assert_eq!(circuit.first_faulty_stage(), None);
+    }
+
+    #[test]
+    fn test_evaluate_64_bits() {
+        let circuit = circuit(64);
+        assert_eq!(circuit.evaluate(u64::MAX, 1), Some(1 << 64));
+        assert!(circuit.adds_correctly());
+    }
+
+    #[test]
+    fn test_evaluate_loop() {
+        let mut circuit = circuit(4);
+        // Stage 2's carry out now feeds back into its own AND gate, which z02 reads.
+        circuit.swap_outputs("s02", "c02");
+        assert_eq!(circuit.evaluate(7, 7), None);
+        assert!(!circuit.adds_correctly());
+    }
+
+    #[test]
+    fn test_first_faulty_stage() {
+        let mut circuit = circuit(8);
+        circuit.swap_outputs("z05", "c05");
+        assert_eq!(circuit.first_faulty_stage(), Some(5));
+        let mut circuit = self::circuit(8);
+        circuit.swap_outputs("s02", "d02");
+        assert_eq!(circuit.first_faulty_stage(), Some(2));
+    }
+
+    #[test]
+    fn test_repair() {
+        let mut broken = circuit(12);
+        broken.swap_outputs("s03", "d03");
+        broken.swap_outputs("z06", "c06");
+        broken.swap_outputs("z09", "e09");
+        assert!(!broken.adds_correctly());
+
+        let repairs = broken.repair(4).unwrap();
+        let expected = [(3, "d03", "s03"), (6, "c06", "z06"), (9, "e09", "z09")];
+        assert_eq!(repairs.len(), expected.len());
+        for (repair, (stage, a, b)) in repairs.iter().zip(expected) {
+            assert_eq!(repair.stage, stage);
+            assert_eq!(repair.wires, [a.to_string(), b.to_string()]);
+        }
+        assert_eq!(repairs[1].to_string(), "stage 6: swapped c06 and z06");
+
+        assert_eq!(broken.repair(2), None);
+        assert_eq!(circuit(12).repair(4), Some(vec![]));
+    }
+}
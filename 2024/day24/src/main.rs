@@ -1,12 +1,16 @@
 use std::{
     cmp::Reverse,
     collections::{HashMap, VecDeque},
+    fmt::Display,
     str::FromStr,
 };
 
-use regex::Regex;
+use aoc_core::Solution;
+use circuit::Circuit;
 
-#[derive(Debug, Default, PartialEq)]
+mod circuit;
+
+#[derive(Debug, Default, Clone, PartialEq)]
 struct Gate {
     id: String,
     in_a: Option<String>,
@@ -52,7 +56,7 @@ impl Gate {
     }
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 enum Operation {
     And,
     Or,
@@ -99,8 +103,8 @@ fn parse_input(input: &str) -> HashMap<String, Gate> {
     gates
 }
 
-fn solve_p1(input: &str) -> usize {
-    let mut gates = parse_input(input);
+fn solve_p1(gates: &HashMap<String, Gate>) -> usize {
+    let mut gates = gates.clone();
     let mut queue = VecDeque::new();
     gates.iter().for_each(|(name, gate)| {
         if gate.out.is_none() {
@@ -131,92 +135,51 @@ fn solve_p1(input: &str) -> usize {
         .fold(0, |acc, gate| acc << 1 | gate.out.unwrap() as usize)
 }
 
-fn parse_input_p2(input: &str) -> (&str, Vec<[&str; 4]>) {
-    let (wire_values, gate_connections) = input.split_once("\n\n").unwrap();
-    let gate_re = Regex::new(r"(.{3}) (AND|OR|XOR) (.{3}) -> (.{3})").unwrap();
-    let gate_connections = gate_connections
-        .lines()
-        .map(|line| {
-            let caps = gate_re.captures(line).unwrap();
-            let (_, s) = caps.extract::<4>();
-            s
-        })
-        .collect::<Vec<_>>();
-    (wire_values, gate_connections)
+/// The puzzle's circuit has four pairs of swapped outputs.
+const MAX_SWAPS: usize = 4;
+
+fn solve_p2(gates: &HashMap<String, Gate>) -> String {
+    let circuit = Circuit::new(gates);
+    let repairs = circuit
+        .repair(MAX_SWAPS)
+        .expect("The circuit should be an adder with a few outputs swapped");
+    let mut wires: Vec<&str> = repairs
+        .iter()
+        .flat_map(|repair| repair.wires.iter().map(String::as_str))
+        .collect();
+    wires.sort_unstable();
+    wires.join(",")
 }
 
-fn solve_p2(input: &str) -> String {
-    let (_, gate_connections) = parse_input_p2(input);
-    let mut wire_map: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+struct Day24;
 
-    for &[a, op, b, ret] in gate_connections.iter() {
-        wire_map.entry(a).or_default().push((op, ret));
-        wire_map.entry(b).or_default().push((op, ret));
+impl Solution for Day24 {
+    type Input<'a> = HashMap<String, Gate>;
+
+    fn parse(input: &str) -> HashMap<String, Gate> {
+        parse_input(input)
     }
 
-    let mut wrong_wires = vec![];
-    for &[lhs, op, rhs, ret] in gate_connections.iter() {
-        // basically we ensure the adder looks like this:
-        // https://en.wikipedia.org/wiki/Adder_(electronics)#/media/File:Fulladder.gif
-        let chained_ops = wire_map.get(&ret);
-        let chained_ops_contain =
-            |op| chained_ops.is_some_and(|v| v.iter().any(|a| a.0 == op));
-
-        let has_chained_xor = chained_ops_contain("XOR");
-        let has_chained_and = chained_ops_contain("AND");
-        let has_chained_or = chained_ops_contain("OR");
-        let takes_first_input = lhs.ends_with("00") && rhs.ends_with("00");
-        let takes_input_bit = (lhs.starts_with('x') && rhs.starts_with('y'))
-            || (rhs.starts_with('x') && lhs.starts_with('y'));
-        let outputs_bit = ret.starts_with('z');
-        let outputs_last_bit = ret == "z45";
-
-        let valid = match op {
-            "XOR" => {
-                // XOR only outputs a bit if it doesn't take an input bit
-                if !takes_input_bit && outputs_bit {
-                    true
-                // XOR only takes an input bit if a XOR follows it
-                } else if takes_input_bit && has_chained_xor {
-                    true
-                // unless the input bits are the first bits (no carryover bit exists)
-                } else { takes_first_input && outputs_bit }
-            }
-            "OR" => {
-                // OR either outputs into z45 or an AND and XOR (carryover bit)
-                outputs_last_bit || (has_chained_and && has_chained_xor)
-            }
-            "AND" => {
-                // ANDs only lead into ORs
-                if has_chained_or {
-                    true
-                // unless the input bits are the first bits (no carryover bit exists)
-                } else { takes_first_input }
-            }
-            _ => {
-                unreachable!()
-            }
-        };
-        if !valid {
-            wrong_wires.push(ret);
-        }
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
     }
-    wrong_wires.sort_unstable();
-    wrong_wires.join(",").to_string()
 }
 
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    let input = aoc_core::puzzle_input(2024, 24);
+    if std::env::args().nth(1).as_deref() == Some("--explain") {
+        let circuit = Circuit::new(&parse_input(&input));
+        match circuit.repair(MAX_SWAPS) {
+            Some(repairs) => repairs.iter().for_each(|repair| println!("{repair}")),
+            None => println!("No repair found with up to {MAX_SWAPS} swaps"),
+        }
+        return;
+    }
+    aoc_core::run::<Day24>(&input);
 }
 
 #[cfg(test)]
@@ -226,10 +189,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input1 = std::fs::read_to_string("test_input1.txt").unwrap();
-        let answer = solve_p1(&input1);
+        let answer = solve_p1(&parse_input(&input1));
         assert_eq!(answer, 4);
         let input2 = std::fs::read_to_string("test_input2.txt").unwrap();
-        let answer = solve_p1(&input2);
+        let answer = solve_p1(&parse_input(&input2));
         assert_eq!(answer, 2024);
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
 fn parse_input(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
     let items = input.split("\n\n").collect::<Vec<_>>();
     let mut locks = Vec::new();
@@ -29,11 +32,10 @@ fn parse_input(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
     (locks, keys)
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let (locks, keys) = parse_input(input);
+fn solve_p1((locks, keys): &(Vec<Vec<usize>>, Vec<Vec<usize>>)) -> i32 {
     let mut fit_count = 0;
     for lock in locks {
-        for key in &keys {
+        for key in keys {
             let mut a_fit = true;
             for i in 0..lock.len() {
                 if lock[i] + key[i] >= 6 {
@@ -49,13 +51,23 @@ fn solve_p1(input: &str) -> i32 {
     fit_count
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+}
+
+fn main() {
+    let input = aoc_core::puzzle_input(2024, 25);
+    aoc_core::run::<Day25>(&input);
 }
 
 #[cfg(test)]
@@ -65,7 +77,7 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = std::fs::read_to_string("test_input.txt").unwrap();
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 3);
     }
 }
@@ -20,118 +20,145 @@ fn create_grid_with_obstacles(size: usize) -> Vec<Vec<u32>> {
     grid
 }
 
-fn bench_dijkstra_uniform_grid(c: &mut Criterion) {
-    let mut group = c.benchmark_group("dijkstra_uniform");
-
-    for size in [10, 25, 50, 100].iter() {
-        let grid = create_grid(*size);
-        let start = (0, 0);
+type CostFn = fn(&u32, &u32) -> Option<usize>;
+type Variant = fn(&[Vec<u32>], (usize, usize), CostFn) -> usize;
+
+/// Each variant returns the number of reached cells so the results can't be optimised away
+const VARIANTS: [(&str, Variant); 3] = [
+    ("hashmap", |g, s, f| grid::dijkstra(g, s, f).len()),
+    ("dense", |g, s, f| grid::dijkstra_dense(g, s, f).len()),
+    ("radix", |g, s, f| grid::dijkstra_radix(g, s, f).len()),
+];
+
+const BFS_01: (&str, Variant) = ("bfs_01", |g, s, f| grid::bfs_01(g, s, f).len());
+
+type PathVariant = fn(&[Vec<u32>], (usize, usize), (usize, usize), CostFn) -> Option<usize>;
+
+/// Each path variant returns the length of the path it found. The path benchmarks only use
+/// unit costs, so the 0-1 BFS is included.
+const PATH_VARIANTS: [(&str, PathVariant); 4] = [
+    ("hashmap", |g, s, t, f| {
+        grid::dijkstra_path(g, s, t, f).map(|(_, p)| p.len())
+    }),
+    ("dense", |g, s, t, f| {
+        grid::dijkstra_dense_path(g, s, t, f).map(|(_, p)| p.len())
+    }),
+    ("radix", |g, s, t, f| {
+        grid::dijkstra_radix_path(g, s, t, f).map(|(_, p)| p.len())
+    }),
+    ("bfs_01", |g, s, t, f| {
+        grid::bfs_01_path(g, s, t, f).map(|(_, p)| p.len())
+    }),
+];
+
+fn unit_cost(_: &u32, _: &u32) -> Option<usize> {
+    Some(1)
+}
 
-        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
-            b.iter(|| {
-                grid::dijkstra(black_box(&grid), black_box(start), |_, _| Some(1))
-            });
-        });
+fn obstacle_cost(_: &u32, next: &u32) -> Option<usize> {
+    if *next == 9 {
+        None
+    } else {
+        Some(1)
     }
+}
 
-    group.finish();
+fn terrain_cost(_: &u32, next: &u32) -> Option<usize> {
+    Some(*next as usize)
 }
 
-fn bench_dijkstra_with_obstacles(c: &mut Criterion) {
-    let mut group = c.benchmark_group("dijkstra_obstacles");
+/// Compare the distance-only variants on the same grids
+fn bench_variants(
+    c: &mut Criterion,
+    name: &str,
+    create: fn(usize) -> Vec<Vec<u32>>,
+    cost_fn: CostFn,
+    variants: &[(&str, Variant)],
+) {
+    let mut group = c.benchmark_group(name);
 
     for size in [10, 25, 50, 100].iter() {
-        let grid = create_grid_with_obstacles(*size);
+        let grid = create(*size);
         let start = (0, 0);
 
-        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
-            b.iter(|| {
-                grid::dijkstra(black_box(&grid), black_box(start), |_, next| {
-                    if *next == 9 {
-                        None
-                    } else {
-                        Some(1)
-                    }
-                })
+        for (variant, run) in variants {
+            group.bench_with_input(BenchmarkId::new(*variant, size), size, |b, _| {
+                b.iter(|| run(black_box(&grid), black_box(start), cost_fn));
             });
-        });
+        }
     }
 
     group.finish();
 }
 
-fn bench_dijkstra_path(c: &mut Criterion) {
-    let mut group = c.benchmark_group("dijkstra_path");
-
-    for size in [10, 25, 50, 100].iter() {
-        let grid = create_grid(*size);
-        let start = (0, 0);
-        let target = (size - 1, size - 1);
+fn bench_dijkstra_uniform_grid(c: &mut Criterion) {
+    let variants = [VARIANTS[0], VARIANTS[1], VARIANTS[2], BFS_01];
+    bench_variants(c, "dijkstra_uniform", create_grid, unit_cost, &variants);
+}
 
-        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
-            b.iter(|| {
-                grid::dijkstra_path(
-                    black_box(&grid),
-                    black_box(start),
-                    black_box(target),
-                    |_, _| Some(1),
-                )
-            });
-        });
-    }
+fn bench_dijkstra_with_obstacles(c: &mut Criterion) {
+    let variants = [VARIANTS[0], VARIANTS[1], VARIANTS[2], BFS_01];
+    bench_variants(
+        c,
+        "dijkstra_obstacles",
+        create_grid_with_obstacles,
+        obstacle_cost,
+        &variants,
+    );
+}
 
-    group.finish();
+fn bench_dijkstra_variable_costs(c: &mut Criterion) {
+    // Grid with values 1-9 representing different terrain costs
+    let create = |size: usize| {
+        (0..size)
+            .map(|i| (0..size).map(|j| ((i + j) % 9 + 1) as u32).collect())
+            .collect()
+    };
+    bench_variants(
+        c,
+        "dijkstra_variable_costs",
+        create,
+        terrain_cost,
+        &VARIANTS,
+    );
 }
 
-fn bench_dijkstra_path_with_obstacles(c: &mut Criterion) {
-    let mut group = c.benchmark_group("dijkstra_path_obstacles");
+fn bench_paths(c: &mut Criterion, name: &str, create: fn(usize) -> Vec<Vec<u32>>, cost_fn: CostFn) {
+    let mut group = c.benchmark_group(name);
 
     for size in [10, 25, 50, 100].iter() {
-        let grid = create_grid_with_obstacles(*size);
+        let grid = create(*size);
         let start = (0, 0);
         let target = (size - 1, size - 1);
 
-        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
-            b.iter(|| {
-                grid::dijkstra_path(
-                    black_box(&grid),
-                    black_box(start),
-                    black_box(target),
-                    |_, next| {
-                        if *next == 9 {
-                            None
-                        } else {
-                            Some(1)
-                        }
-                    },
-                )
+        for (variant, run) in PATH_VARIANTS {
+            group.bench_with_input(BenchmarkId::new(variant, size), size, |b, _| {
+                b.iter(|| {
+                    run(
+                        black_box(&grid),
+                        black_box(start),
+                        black_box(target),
+                        cost_fn,
+                    )
+                });
             });
-        });
+        }
     }
 
     group.finish();
 }
 
-fn bench_dijkstra_variable_costs(c: &mut Criterion) {
-    let mut group = c.benchmark_group("dijkstra_variable_costs");
-
-    for size in [10, 25, 50, 100].iter() {
-        // Grid with values 1-9 representing different terrain costs
-        let grid: Vec<Vec<u32>> = (0..*size)
-            .map(|i| (0..*size).map(|j| ((i + j) % 9 + 1) as u32).collect())
-            .collect();
-        let start = (0, 0);
-
-        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
-            b.iter(|| {
-                grid::dijkstra(black_box(&grid), black_box(start), |_, next| {
-                    Some(*next as usize)
-                })
-            });
-        });
-    }
+fn bench_dijkstra_path(c: &mut Criterion) {
+    bench_paths(c, "dijkstra_path", create_grid, unit_cost);
+}
 
-    group.finish();
+fn bench_dijkstra_path_with_obstacles(c: &mut Criterion) {
+    bench_paths(
+        c,
+        "dijkstra_path_obstacles",
+        create_grid_with_obstacles,
+        obstacle_cost,
+    );
 }
 
 criterion_group!(
@@ -0,0 +1,94 @@
+use aoc_core::gf2::{BitMatrix, BitVec};
+use aoc_core::rng::XorShift;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use itertools::Itertools;
+
+/// Buttons that each toggle a random third or so of `lights` lights, and a target reachable by
+/// pressing a random subset of them
+struct Panel {
+    lights: usize,
+    buttons: Vec<Vec<usize>>,
+    target: Vec<usize>,
+}
+
+fn panel(lights: usize, buttons: usize, seed: u64) -> Panel {
+    let mut rng = XorShift::new(seed);
+    let buttons: Vec<Vec<usize>> = (0..buttons)
+        .map(|_| (0..lights).filter(|_| rng.one_in(3)).collect())
+        .collect();
+    let mut on = vec![false; lights];
+    for button in buttons.iter().filter(|_| rng.one_in(2)) {
+        for &light in button {
+            on[light] = !on[light];
+        }
+    }
+    let target = (0..lights).filter(|&light| on[light]).collect();
+    Panel {
+        lights,
+        buttons,
+        target,
+    }
+}
+
+/// The search 2025 day 10 used before: every combination of buttons, fewest first, with the
+/// lights as a `u16`
+fn combinations(panel: &Panel) -> usize {
+    let mask = |lights: &[usize]| lights.iter().fold(0u16, |mask, &light| mask | 1 << light);
+    let buttons: Vec<u16> = panel.buttons.iter().map(|b| mask(b)).collect();
+    let target = mask(&panel.target);
+    (0..=buttons.len())
+        .find(|&n| {
+            buttons
+                .iter()
+                .combinations(n)
+                .any(|combo| combo.into_iter().fold(0, |acc, b| acc ^ b) == target)
+        })
+        .unwrap_or(0)
+}
+
+fn gf2(panel: &Panel) -> usize {
+    let toggles: Vec<BitVec> = panel
+        .buttons
+        .iter()
+        .map(|button| BitVec::from_indices(panel.lights, button.iter().copied()))
+        .collect();
+    let target = BitVec::from_indices(panel.lights, panel.target.iter().copied());
+    BitMatrix::from_columns(panel.lights, &toggles)
+        .solve(&target)
+        .map_or(0, |presses| presses.min_weight().count_ones())
+}
+
+fn bench_fewest_presses(c: &mut Criterion) {
+    // Day 10's machines have up to 10 lights and 13 buttons; the combinatorial search is
+    // limited to 16 lights
+    let mut group = c.benchmark_group("fewest_presses");
+    for (lights, buttons) in [(6, 8), (10, 13), (16, 20)] {
+        let panel = panel(lights, buttons, 0x2545f4914f6cdd1d);
+        assert_eq!(combinations(&panel), gf2(&panel));
+        let size = format!("{lights}x{buttons}");
+        group.bench_with_input(
+            BenchmarkId::new("combinations", &size),
+            &panel,
+            |b, panel| b.iter(|| combinations(black_box(panel))),
+        );
+        group.bench_with_input(BenchmarkId::new("gf2", &size), &panel, |b, panel| {
+            b.iter(|| gf2(black_box(panel)))
+        });
+    }
+    group.finish();
+
+    // Only the GF(2) solver handles more than 16 lights
+    let mut group = c.benchmark_group("fewest_presses_wide");
+    for (lights, buttons) in [(64, 72), (200, 210)] {
+        let panel = panel(lights, buttons, 0x9e3779b97f4a7c15);
+        group.bench_with_input(
+            BenchmarkId::new("gf2", format!("{lights}x{buttons}")),
+            &panel,
+            |b, panel| b.iter(|| gf2(black_box(panel))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_fewest_presses);
+criterion_main!(benches);
@@ -1,4 +1,6 @@
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 const CIRCLE_SIZE: i32 = 100;
 const START_POS: i32 = 50;
@@ -14,8 +16,7 @@ fn parse_input(input: &str) -> Vec<(char, i32)> {
         .collect()
 }
 
-fn solve_p1(input: &str) -> i32 {
-    let rotations = parse_input(input);
+fn solve_p1(rotations: &[(char, i32)]) -> i32 {
     let mut at_zero = 0;
     let mut pos = START_POS;
 
@@ -32,8 +33,7 @@ fn solve_p1(input: &str) -> i32 {
     at_zero
 }
 
-fn solve_p2(input: &str) -> i32 {
-    let rotations = parse_input(input);
+fn solve_p2(rotations: &[(char, i32)]) -> i32 {
     let mut zero_crossings = 0;
     let mut pos = START_POS;
 
@@ -61,18 +61,27 @@ fn solve_p2(input: &str) -> i32 {
     zero_crossings
 }
 
-fn main() {
-    let input = read_input(1);
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<(char, i32)>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<(char, i32)> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(1);
+    aoc_core::run::<Day01>(&input);
 }
 
 #[cfg(test)]
@@ -82,9 +91,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(1);
-        let answer = solve_p1(&input);
+        let rotations = parse_input(&input);
+        let answer = solve_p1(&rotations);
         assert_eq!(answer, 3);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&rotations);
         assert_eq!(answer, 6);
     }
 }
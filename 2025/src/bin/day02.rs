@@ -1,4 +1,6 @@
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<(String, String)> {
     // Input is in the form of lines with multiple ranges like "2-4,6-8"
@@ -65,34 +67,41 @@ fn get_invalid_ids(start: &str, end: &str) -> Vec<usize> {
     invalid_ids
 }
 
-fn solve_p1(input: &str) -> usize {
-    let ranges = parse_input(input);
+fn solve_p1(ranges: &[(String, String)]) -> usize {
     ranges
         .iter()
         .flat_map(|(start, end)| get_simple_invalid_ids(start, end))
         .sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let ranges = parse_input(input);
+fn solve_p2(ranges: &[(String, String)]) -> usize {
     ranges
         .iter()
         .flat_map(|(start, end)| get_invalid_ids(start, end))
         .sum()
 }
 
-fn main() {
-    let input = read_input(2);
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<(String, String)>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<(String, String)> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(2);
+    aoc_core::run::<Day02>(&input);
 }
 
 #[cfg(test)]
@@ -102,9 +111,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(2);
-        let answer = solve_p1(&input);
+        let ranges = parse_input(&input);
+        let answer = solve_p1(&ranges);
         assert_eq!(answer, 1227775554);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&ranges);
         assert_eq!(answer, 4174379265);
     }
 
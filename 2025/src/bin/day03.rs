@@ -1,4 +1,6 @@
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Vec<Vec<usize>> {
     input.lines().map(digit_str_to_vec).collect()
@@ -40,28 +42,35 @@ fn max_n_digits(bank: &[usize], n: usize) -> usize {
     digits.iter().fold(0, |acc, &d| acc * 10 + d)
 }
 
-fn solve_p1(input: &str) -> usize {
-    let banks = parse_input(input);
+fn solve_p1(banks: &[Vec<usize>]) -> usize {
     banks.iter().map(|bank| max_pairs(bank)).sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let banks = parse_input(input);
+fn solve_p2(banks: &[Vec<usize>]) -> usize {
     banks.iter().map(|bank| max_n_digits(bank, 12)).sum()
 }
 
-fn main() {
-    let input = read_input(3);
+struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Vec<usize>>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<Vec<usize>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(3);
+    aoc_core::run::<Day03>(&input);
 }
 
 #[cfg(test)]
@@ -71,9 +80,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(3);
-        let answer = solve_p1(&input);
+        let banks = parse_input(&input);
+        let answer = solve_p1(&banks);
         assert_eq!(answer, 357);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&banks);
         assert_eq!(answer, 3121910778619);
     }
 
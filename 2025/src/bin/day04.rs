@@ -1,4 +1,6 @@
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> Grid<char> {
     parse_grid(input)
@@ -23,13 +25,12 @@ fn remove_tps(grid: &mut Grid<char>, tps: &[(usize, usize)]) {
     }
 }
 
-fn solve_p1(input: &str) -> usize {
-    let grid = parse_input(input);
-    find_moveable_tps(&grid).len()
+fn solve_p1(grid: &Grid<char>) -> usize {
+    find_moveable_tps(grid).len()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut grid = parse_input(input);
+fn solve_p2(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
     let mut total = 0;
     loop {
         let moveable_tps = find_moveable_tps(&grid);
@@ -42,18 +43,27 @@ fn solve_p2(input: &str) -> usize {
     total
 }
 
-fn main() {
-    let input = read_input(4);
+struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<char>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Grid<char> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(4);
+    aoc_core::run::<Day04>(&input);
 }
 
 #[cfg(test)]
@@ -63,9 +73,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(4);
-        let answer = solve_p1(&input);
+        let grid = parse_input(&input);
+        let answer = solve_p1(&grid);
         assert_eq!(answer, 13);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&grid);
         assert_eq!(answer, 43);
     }
 }
@@ -1,62 +1,49 @@
 use aoc2025::*;
+use aoc_core::{IntervalSet, Solution};
+use std::fmt::Display;
 
-fn parse_input(input: &str) -> (Vec<(usize, usize)>, Vec<usize>) {
+fn parse_input(input: &str) -> (IntervalSet<usize>, Vec<usize>) {
     let (range_part, id_part) = input.split_once("\n\n").unwrap();
-    let ranges = range_part
+    let fresh = range_part
         .lines()
         .map(|line| {
             let (start, end) = line.split_once('-').unwrap();
-            (start.parse().unwrap(), end.parse().unwrap())
+            start.parse().unwrap()..=end.parse().unwrap()
         })
         .collect();
     let ids = id_part.lines().map(|line| line.parse().unwrap()).collect();
-    (ranges, ids)
+    (fresh, ids)
 }
 
-fn merge_ranges(ranges: &mut [(usize, usize)]) -> Vec<(usize, usize)> {
-    ranges.sort_by_key(|&(start, _)| start);
-    let mut merged: Vec<(usize, usize)> = Vec::new();
-    for &(start, end) in ranges.iter() {
-        if let Some((_last_start, last_end)) = merged.last_mut() {
-            if start <= *last_end + 1 {
-                // The +1 above allows merging adjacent ranges
-                *last_end = (*last_end).max(end);
-            } else {
-                merged.push((start, end));
-            }
-        } else {
-            merged.push((start, end));
-        }
-    }
-    merged
+fn solve_p1((fresh, ids): &(IntervalSet<usize>, Vec<usize>)) -> usize {
+    ids.iter().filter(|&&id| fresh.contains(id)).count()
 }
 
-fn solve_p1(input: &str) -> usize {
-    let (ranges, ids) = parse_input(input);
-    ids.iter()
-        .filter(|id| ranges.iter().any(|(start, end)| (start..=end).contains(id)))
-        .count()
+fn solve_p2((fresh, _ids): &(IntervalSet<usize>, Vec<usize>)) -> usize {
+    fresh.len() as usize
 }
 
-fn solve_p2(input: &str) -> usize {
-    let (mut ranges, _ids) = parse_input(input);
-    let merged = merge_ranges(&mut ranges);
-    // Count the numbers covered by the merged ranges
-    merged.iter().map(|(start, end)| end - start + 1).sum()
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = (IntervalSet<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> (IntervalSet<usize>, Vec<usize>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
 }
 
 fn main() {
     let input = read_input(5);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
-
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    aoc_core::run::<Day05>(&input);
 }
 
 #[cfg(test)]
@@ -66,9 +53,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(5);
-        let answer = solve_p1(&input);
+        let data = parse_input(&input);
+        let answer = solve_p1(&data);
         assert_eq!(answer, 3);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&data);
         assert_eq!(answer, 14);
     }
 }
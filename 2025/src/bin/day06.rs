@@ -1,4 +1,6 @@
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Operator {
@@ -137,18 +139,27 @@ fn solve_p2(input: &str) -> usize {
     results
 }
 
-fn main() {
-    let input = read_input(6);
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(6);
+    aoc_core::run::<Day06>(&input);
 }
 
 #[cfg(test)]
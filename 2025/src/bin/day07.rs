@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn find_start(manifold: &[Vec<char>]) -> (usize, usize) {
     let start_col = manifold[0]
@@ -88,18 +90,27 @@ fn solve_p2(input: &str) -> usize {
     count_paths(&manifold, start, target_row, &mut memo)
 }
 
-fn main() {
-    let input = read_input(7);
+struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = &'a str;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(7);
+    aoc_core::run::<Day07>(&input);
 }
 
 #[cfg(test)]
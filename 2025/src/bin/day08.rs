@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Coord3D {
@@ -117,16 +119,14 @@ fn find_circuit_sizes(boxes: &[Coord3D], num_connections: usize) -> Vec<usize> {
     uf.component_sizes()
 }
 
-fn solve_p1(input: &str, num_connections: usize) -> usize {
-    let boxes = parse_input(input);
-    let mut circuit_sizes = find_circuit_sizes(&boxes, num_connections);
+fn solve_p1(boxes: &[Coord3D], num_connections: usize) -> usize {
+    let mut circuit_sizes = find_circuit_sizes(boxes, num_connections);
     circuit_sizes.sort_unstable_by(|a, b| b.cmp(a));
     circuit_sizes.iter().take(3).product()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let boxes = parse_input(input);
-    let distances = pairwise_distances(&boxes);
+fn solve_p2(boxes: &[Coord3D]) -> usize {
+    let distances = pairwise_distances(boxes);
 
     // Build connected components using Union-Find
     let mut uf = UnionFind::new(boxes.len());
@@ -139,18 +139,27 @@ fn solve_p2(input: &str) -> usize {
     0
 }
 
-fn main() {
-    let input = read_input(8);
+struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<Coord3D>;
+
+    fn parse(input: &str) -> Vec<Coord3D> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input, 1000);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input, 1000)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+fn main() {
+    let input = read_input(8);
+    aoc_core::run::<Day08>(&input);
 }
 
 #[cfg(test)]
@@ -160,9 +169,10 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(8);
-        let answer = solve_p1(&input, 10);
+        let boxes = parse_input(&input);
+        let answer = solve_p1(&boxes, 10);
         assert_eq!(answer, 40);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&boxes);
         assert_eq!(answer, 25272);
     }
 }
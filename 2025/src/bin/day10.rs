@@ -1,35 +1,30 @@
-use std::str::FromStr;
-
 use aoc2025::*;
-use good_lp::*;
-use itertools::Itertools;
+use aoc_core::gf2::{BitMatrix, BitVec};
+use aoc_core::ilp::{IlpSolver, Problem};
+use aoc_core::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
 struct Button {
-    wires: Vec<u16>,
-    value: u16,
+    // The lights, and joltage counters, that the button is wired to
+    wires: BitVec,
 }
 
-impl FromStr for Button {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let wires: Vec<u16> = s
+impl Button {
+    fn parse(s: &str, lights: usize) -> Self {
+        let wires = s
             .trim_matches(|c| c == '(' || c == ')')
             .split(',')
-            .map(|num_str| num_str.parse::<u16>().unwrap())
-            .collect();
-        let mut value = 0;
-        for wire in &wires {
-            value |= 1 << wire;
+            .map(|num_str| num_str.parse::<usize>().unwrap());
+        Button {
+            wires: BitVec::from_indices(lights, wires),
         }
-        Ok(Button { wires, value })
     }
 }
 
 #[derive(Debug, Clone)]
 struct Machine {
-    indicator: u16,
+    indicator: BitVec,
     buttons: Vec<Button>,
     joltage: Vec<u16>,
 }
@@ -40,7 +35,7 @@ impl std::str::FromStr for Machine {
         let parts = s.split(' ').collect::<Vec<&str>>();
 
         let indicator = parse_indicator(parts[0]);
-        let buttons = parse_buttons(&parts[1..parts.len() - 1]);
+        let buttons = parse_buttons(&parts[1..parts.len() - 1], indicator.len());
         let joltage = parse_joltage(parts.last().unwrap());
 
         Ok(Machine {
@@ -51,22 +46,21 @@ impl std::str::FromStr for Machine {
     }
 }
 
-fn parse_indicator(input: &str) -> u16 {
+fn parse_indicator(input: &str) -> BitVec {
     // [..#..##..#.]
-    let mut indicator = 0;
-    for c in input.chars().rev() {
-        indicator = match c {
-            '#' => indicator << 1 | 1,
-            '.' => indicator << 1,
-            '[' | ']' => indicator,
+    input
+        .trim_matches(|c| c == '[' || c == ']')
+        .chars()
+        .map(|c| match c {
+            '#' => true,
+            '.' => false,
             _ => panic!("Invalid character in indicator: {}", c),
-        };
-    }
-    indicator
+        })
+        .collect()
 }
 
-fn parse_buttons(input: &[&str]) -> Vec<Button> {
-    input.iter().map(|s| s.parse().unwrap()).collect()
+fn parse_buttons(input: &[&str], lights: usize) -> Vec<Button> {
+    input.iter().map(|s| Button::parse(s, lights)).collect()
 }
 
 fn parse_joltage(input: &str) -> Vec<u16> {
@@ -86,106 +80,99 @@ fn parse_input(input: &str) -> Vec<Machine> {
 }
 
 fn min_presses_for_indicator(machine: &Machine) -> usize {
-    for i in 0..=machine.buttons.len() {
-        for combo in machine.buttons.iter().combinations(i) {
-            let combined = combo.iter().fold(0u16, |acc, &b| acc ^ b.value);
-            if combined == machine.indicator {
-                return i;
-            }
-        }
-    }
-    0
-}
-
-fn min_presses_for_joltage(machine: &Machine) -> usize {
-    let mut vars = ProblemVariables::new();
-
-    // Press Counts represents the number of times each button is pressed
-    // Without specifying a floor, the minimum number of presses is
-    // negative infinity.
-    let mut button_presses = Vec::new();
-    for _ in 0..machine.buttons.len() {
-        // register each button's press count as a variable with the solver
-        let variable = vars.add(variable().min(0).integer());
-        // keep track of the variables so we can state the objective in terms
-        // of them below
-        button_presses.push(variable);
-    }
+    // Pressing a button twice undoes it, so each is pressed at most once and the presses that
+    // light the indicator solve a linear system over GF(2).
+    let toggles: Vec<BitVec> = machine
+        .buttons
+        .iter()
+        .map(|button| button.wires.clone())
+        .collect();
+    BitMatrix::from_columns(machine.indicator.len(), &toggles)
+        .solve(&machine.indicator)
+        .map_or(0, |presses| presses.min_weight().count_ones())
+}
 
-    // We state the problem: smallest sum of all button presses
-    let mut problem = good_lp::highs(vars.minimise(button_presses.iter().sum::<Expression>()));
-
-    // Available solvers listed at https://docs.rs/crate/good_lp/latest
-    // Use of the `highs`` solver as it:
-    // - Requires no extra libraries (ruling out coin_cbc)
-    // - Supports integers (clarabel doesn't support these, despite the
-    //   docstring, highs does)
-    // - Gives the right answer (sorry microlp)
-    // - Is "fast"
-    // - Doesn't require any additional mucking about to get it working
-    //   (most of the others)
-
-    // The value of each joltage counter is derived from the buttons pressed
-    // we have one expression per counter
-    let mut expressions =
-        vec![Expression::with_capacity(machine.buttons.len()); machine.joltage.len()];
-
-    for (i, button) in machine.buttons.iter().enumerate() {
-        for x in button.wires.iter() {
-            // for each button pressed, add the number of times it is pressed
-            // to the total for the joltage counters it increments
-            expressions[*x as usize] += button_presses[i];
-        }
-    }
-    for (e, j) in expressions.into_iter().zip(machine.joltage.clone()) {
-        // for each of the expressions for a given joltage counter's value,
-        // add the constraint that the result of the expression must be the desired
-        // joltage
-        problem.add_constraint(e.eq(j as f64));
-    }
-    match problem.solve() {
-        Ok(solution) => button_presses
-            .iter()
-            .map(|&v| solution.value(v))
-            .sum::<f64>() as usize,
-        Err(_) => 0, // No solution exists
+/// The HiGHS solver if it's been built in, otherwise the pure-Rust one
+#[cfg(feature = "good_lp")]
+type Solver = aoc_core::ilp::GoodLp;
+#[cfg(not(feature = "good_lp"))]
+type Solver = aoc_core::ilp::BranchAndBound;
+
+fn joltage_problem(machine: &Machine) -> Problem {
+    // One variable per button, for the number of times it's pressed, and the smallest total
+    // of presses wanted. Each joltage counter ends up at the sum of the presses of the buttons
+    // wired to it, which must be its target.
+    let presses = Problem::new(vec![1; machine.buttons.len()]);
+    machine
+        .joltage
+        .iter()
+        .enumerate()
+        .fold(presses, |problem, (counter, &joltage)| {
+            let wired = machine
+                .buttons
+                .iter()
+                .map(|button| button.wires.get(counter) as i64)
+                .collect();
+            problem.with_constraint(wired, joltage as i64)
+        })
+}
+
+fn min_presses_for_joltage(machine: &Machine, solver: &impl IlpSolver) -> usize {
+    let presses = solver
+        .minimise(&joltage_problem(machine))
+        .expect("every button is wired to a counter");
+    match presses {
+        Some(presses) => presses.iter().sum::<i64>() as usize,
+        None => 0, // No solution exists
     }
 }
 
-fn solve_p1(input: &str) -> usize {
-    let machines = parse_input(input);
+fn solve_p1(machines: &[Machine]) -> usize {
     machines.iter().map(min_presses_for_indicator).sum()
 }
 
-fn solve_p2(input: &str) -> usize {
-    let machines = parse_input(input);
-    machines.iter().map(min_presses_for_joltage).sum()
+fn solve_p2(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .map(|machine| min_presses_for_joltage(machine, &Solver::default()))
+        .sum()
 }
 
-fn main() {
-    let input = read_input(10);
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Machine>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> Vec<Machine> {
+        parse_input(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(10);
+    aoc_core::run::<Day10>(&input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use itertools::Itertools;
 
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(10);
-        let answer = solve_p1(&input);
+        let machines = parse_input(&input);
+        let answer = solve_p1(&machines);
         assert_eq!(answer, 7);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&machines);
         assert_eq!(answer, 33);
     }
 
@@ -193,11 +180,14 @@ mod tests {
     fn test_parse_machine() {
         let input = "[..#.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
         let machine: Machine = input.parse().unwrap();
-        assert_eq!(machine.indicator, 0b0100 as u16); // Binary for ..#.
-                                                      // Buttons: (3)=0b1000, (1,3)=0b1010, (2)=0b0100, (2,3)=0b1100, (0,2)=0b0101, (0,1)=0b0011
+        assert_eq!(machine.indicator.to_string(), "0010");
         assert_eq!(
-            machine.buttons.iter().map(|b| b.value).collect_vec(),
-            vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011]
+            machine
+                .buttons
+                .iter()
+                .map(|b| b.wires.to_string())
+                .collect_vec(),
+            vec!["0001", "0101", "0010", "0011", "1010", "1100"]
         );
         assert_eq!(machine.joltage, vec![3, 5, 4, 7]);
     }
@@ -214,7 +204,93 @@ mod tests {
     fn test_fewest_joltage_button_presses() {
         let input = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
         let machine: Machine = input.parse().unwrap();
-        let presses = min_presses_for_joltage(&machine);
+        let presses = min_presses_for_joltage(&machine, &Solver::default());
         assert_eq!(presses, 11);
     }
+
+    #[test]
+    fn test_more_than_sixteen_lights() {
+        let input = "[###.################] (0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19) \
+                     (3) (2,3) (16,17,18,19) {1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1}";
+        let machine: Machine = input.parse().unwrap();
+        assert_eq!(machine.indicator.len(), 20);
+        assert_eq!(min_presses_for_indicator(&machine), 2);
+    }
+
+    mod proptests {
+        use super::*;
+        use aoc_core::ilp::BranchAndBound;
+        use proptest::prelude::*;
+
+        /// A machine with up to five counters and six buttons. Its joltages come from pressing
+        /// each button a few times, then nudging some up by one, which may leave no solution.
+        fn machine() -> impl Strategy<Value = Machine> {
+            (1usize..=5)
+                .prop_flat_map(|counters| {
+                    let button = prop::collection::btree_set(0..counters, 1..=counters);
+                    (
+                        prop::collection::vec((button, 0u16..=3), 1..=6),
+                        prop::collection::vec(prop::bool::weighted(0.1), counters),
+                    )
+                })
+                .prop_map(|(buttons, nudges)| {
+                    let mut joltage: Vec<u16> = nudges.iter().map(|&nudge| nudge as u16).collect();
+                    let buttons = buttons
+                        .into_iter()
+                        .map(|(wires, presses)| {
+                            for &wire in &wires {
+                                joltage[wire] += presses;
+                            }
+                            Button {
+                                wires: BitVec::from_indices(joltage.len(), wires),
+                            }
+                        })
+                        .collect();
+                    Machine {
+                        indicator: BitVec::zeros(joltage.len()),
+                        buttons,
+                        joltage,
+                    }
+                })
+        }
+
+        /// The fewest presses found by trying every number of presses of every button
+        fn brute_force(machine: &Machine) -> usize {
+            fn presses(buttons: &[Button], remaining: &mut [u16]) -> Option<usize> {
+                let Some((button, rest)) = buttons.split_first() else {
+                    return remaining.iter().all(|&j| j == 0).then_some(0);
+                };
+                let most = button
+                    .wires
+                    .ones()
+                    .map(|wire| remaining[wire])
+                    .min()
+                    .unwrap();
+                (0..=most)
+                    .filter_map(|n| {
+                        button.wires.ones().for_each(|w| remaining[w] -= n);
+                        let found = presses(rest, remaining).map(|p| p + n as usize);
+                        button.wires.ones().for_each(|w| remaining[w] += n);
+                        found
+                    })
+                    .min()
+            }
+            presses(&machine.buttons, &mut machine.joltage.clone()).unwrap_or(0)
+        }
+
+        proptest! {
+            #[test]
+            fn branch_and_bound_finds_fewest_presses(machine in machine()) {
+                let presses = min_presses_for_joltage(&machine, &BranchAndBound);
+                prop_assert_eq!(presses, brute_force(&machine));
+            }
+
+            #[cfg(feature = "good_lp")]
+            #[test]
+            fn backends_agree(machine in machine()) {
+                let presses = min_presses_for_joltage(&machine, &BranchAndBound);
+                prop_assert_eq!(presses, min_presses_for_joltage(&machine, &aoc_core::ilp::GoodLp));
+            }
+        }
+    }
 }
@@ -2,6 +2,8 @@ use pathfinding::prelude::count_paths;
 use std::collections::HashMap;
 
 use aoc2025::*;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 fn parse_input(input: &str) -> HashMap<String, Vec<String>> {
     let mut data = HashMap::new();
@@ -18,8 +20,7 @@ fn parse_input(input: &str) -> HashMap<String, Vec<String>> {
     data
 }
 
-fn solve_p1(input: &str) -> usize {
-    let devices = parse_input(input);
+fn solve_p1(devices: &HashMap<String, Vec<String>>) -> usize {
     let start = "you".to_string();
     let target = "out".to_string();
 
@@ -30,8 +31,8 @@ fn solve_p1(input: &str) -> usize {
     )
 }
 
-fn solve_p2(input: &str) -> usize {
-    let mut devices = parse_input(input);
+fn solve_p2(devices: &HashMap<String, Vec<String>>) -> usize {
+    let mut devices = devices.clone();
     let start = "svr".to_string();
     let target = "out".to_string();
     devices.insert(target.clone(), vec![]); // Ensure target is in the map
@@ -54,18 +55,27 @@ fn solve_p2(input: &str) -> usize {
     )
 }
 
-fn main() {
-    let input = read_input(11);
+struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = HashMap<String, Vec<String>>;
 
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    fn parse(input: &str) -> HashMap<String, Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
 
-    let start = std::time::Instant::now();
-    let answer = solve_p2(&input);
-    let elapsed = start.elapsed();
-    println!("Part 2: {answer}, elapsed: {elapsed:.1?}");
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        solve_p2(input)
+    }
+}
+
+fn main() {
+    let input = read_input(11);
+    aoc_core::run::<Day11>(&input);
 }
 
 #[cfg(test)]
@@ -75,11 +85,11 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(11);
-        let answer = solve_p1(&input);
+        let answer = solve_p1(&parse_input(&input));
         assert_eq!(answer, 5);
 
         let input = read_test_input_2(11);
-        let answer = solve_p2(&input);
+        let answer = solve_p2(&parse_input(&input));
         assert_eq!(answer, 2);
     }
 }
@@ -2,9 +2,13 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use aoc2025::*;
+use aoc_core::packing::{pack, Packing, Polyomino};
+use aoc_core::Solution;
+use std::fmt::Display;
 
 struct Region {
-    area: usize,
+    width: usize,
+    height: usize,
     shape_counts: Vec<usize>,
 }
 
@@ -20,7 +24,8 @@ impl FromStr for Region {
         let shapes: Vec<usize> = parts[1].split(' ').map(|n| n.parse().unwrap()).collect();
 
         Ok(Region {
-            area: width * height,
+            width,
+            height,
             shape_counts: shapes,
         })
     }
@@ -28,7 +33,7 @@ impl FromStr for Region {
 
 struct Shape {
     id: usize,
-    area: usize,
+    polyomino: Polyomino,
 }
 
 impl FromStr for Shape {
@@ -37,12 +42,12 @@ impl FromStr for Shape {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts = s.split_once(":").unwrap();
         let id = parts.0.trim().parse().unwrap();
-        let area = parts.1.chars().filter(|&c| c == '#').count();
-        Ok(Shape { id, area })
+        let polyomino = parts.1.parse().unwrap();
+        Ok(Shape { id, polyomino })
     }
 }
 
-fn parse_input(input: &str) -> (HashMap<usize, usize>, Vec<Region>) {
+fn parse_input(input: &str) -> (HashMap<usize, Polyomino>, Vec<Region>) {
     let mut shapes = HashMap::new();
     let mut regions = Vec::new();
 
@@ -55,37 +60,49 @@ fn parse_input(input: &str) -> (HashMap<usize, usize>, Vec<Region>) {
             }
         } else {
             let shape: Shape = part.parse().unwrap();
-            shapes.insert(shape.id, shape.area);
+            shapes.insert(shape.id, shape.polyomino);
         }
     }
 
     (shapes, regions)
 }
 
-fn solve_p1(input: &str) -> usize {
-    let (shapes, regions) = parse_input(input);
+/// A way to fit all the presents the region lists under its tree, if there is one
+fn pack_region(shapes: &HashMap<usize, Polyomino>, region: &Region) -> Option<Packing> {
+    let presents: Vec<(Polyomino, usize)> = region
+        .shape_counts
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(shape_id, &count)| (shapes[&shape_id].clone(), count))
+        .collect();
+    pack(region.width, region.height, &presents)
+}
 
+fn solve_p1((shapes, regions): &(HashMap<usize, Polyomino>, Vec<Region>)) -> usize {
     regions
         .iter()
-        .filter(|region| {
-            let total_area: usize = region
-                .shape_counts
-                .iter()
-                .enumerate()
-                .map(|(shape_id, &count)| shapes.get(&shape_id).unwrap_or(&0) * count)
-                .sum();
-            total_area <= region.area
-        })
+        .filter(|region| pack_region(shapes, region).is_some())
         .count()
 }
 
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = (HashMap<usize, Polyomino>, Vec<Region>);
+
+    fn parse(input: &str) -> (HashMap<usize, Polyomino>, Vec<Region>) {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        solve_p1(input)
+    }
+}
+
 fn main() {
     let input = read_input(12);
-
-    let start = std::time::Instant::now();
-    let answer = solve_p1(&input);
-    let elapsed = start.elapsed();
-    println!("Part 1: {answer}, elapsed: {elapsed:.1?}");
+    aoc_core::run::<Day12>(&input);
 }
 
 #[cfg(test)]
@@ -95,7 +112,24 @@ mod tests {
     #[test]
     fn test_solve_with_test_input() {
         let input = read_test_input(12);
-        let answer = solve_p1(&input);
-        assert_eq!(answer, 3);
+        let answer = solve_p1(&parse_input(&input));
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn test_pack_region() {
+        let input = read_test_input(12);
+        let (shapes, regions) = parse_input(&input);
+
+        // Two of shape 4 fit side by side with their gaps interlocking
+        let packing = pack_region(&shapes, &regions[0]).unwrap();
+        assert_eq!(packing.to_string().matches('.').count(), 16 - 2 * 7);
+        assert_eq!(packing.pieces.len(), 2);
+
+        let packing = pack_region(&shapes, &regions[1]).unwrap();
+        assert_eq!(packing.pieces.len(), 6);
+
+        // The last region has room for the presents' area, but not their shapes
+        assert!(pack_region(&shapes, &regions[2]).is_none());
     }
 }
@@ -0,0 +1,104 @@
+use aoc_core::automaton::{Automaton, BitPlane, Bits, Cell, Dense, Neighborhood, Rule, Sparse};
+use aoc_core::interval;
+use aoc_core::rng::XorShift;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Roughly a third of the cells of a `rows` × `cols` grid, picked by xorshift
+fn soup(rows: i32, cols: i32) -> Vec<Cell<2>> {
+    let mut rng = XorShift::new(0x2545f4914f6cdd1d);
+    (0..rows)
+        .flat_map(|row| (0..cols).map(move |col| [row, col]))
+        .filter(|_| rng.one_in(3))
+        .collect()
+}
+
+fn run(mut automaton: impl Automaton<2>, generations: usize) -> Option<usize> {
+    automaton.run(generations);
+    automaton.population()
+}
+
+fn bench_life(c: &mut Criterion) {
+    let bounds = interval::Box::new([0, 0], [256, 256]);
+    let cells = soup(256, 256);
+    let life: Rule = "B3/S23".parse().unwrap();
+
+    // The sparse automaton has no edges, so its soup spreads a little beyond them
+    let mut group = c.benchmark_group("life_256x256");
+    group.bench_function("sparse", |b| {
+        b.iter(|| {
+            let sparse = Sparse::new(Neighborhood::moore(1), life.clone(), cells.clone());
+            run(black_box(sparse), 10)
+        })
+    });
+    group.bench_function("dense", |b| {
+        b.iter(|| {
+            let dense = Dense::bounded(Neighborhood::moore(1), life.clone(), bounds, cells.clone());
+            run(black_box(dense), 10)
+        })
+    });
+    group.bench_function("bits", |b| {
+        b.iter(|| {
+            let bits = Bits::new(Neighborhood::moore(1), life.clone(), bounds, cells.clone());
+            run(black_box(bits.unwrap()), 10)
+        })
+    });
+    group.finish();
+}
+
+/// 2020 day 17: Life in four dimensions, growing out from a flat soup
+fn bench_life_4d(c: &mut Criterion) {
+    let cells: Vec<Cell<4>> = soup(8, 8)
+        .into_iter()
+        .map(|[row, col]| [row, col, 0, 0])
+        .collect();
+    let life: Rule = "B3/S23".parse().unwrap();
+
+    let mut group = c.benchmark_group("life_4d");
+    group.bench_function("sparse", |b| {
+        b.iter(|| {
+            let mut sparse = Sparse::new(Neighborhood::moore(1), life.clone(), cells.clone());
+            sparse.run(6);
+            sparse.population()
+        })
+    });
+    group.bench_function("dense", |b| {
+        b.iter(|| {
+            let mut dense = Dense::new(Neighborhood::moore(1), life.clone(), cells.clone());
+            dense.run(6);
+            dense.population()
+        })
+    });
+    group.finish();
+}
+
+/// 2021 day 25: two herds taking turns to move east and south around a torus, each sea
+/// cucumber only when the cell ahead is free
+fn bench_herds(c: &mut Criterion) {
+    let mut east = BitPlane::torus(256, 256);
+    let mut south = BitPlane::torus(256, 256);
+    let mut rng = XorShift::new(0x9e3779b97f4a7c15);
+    for cell in soup(256, 256) {
+        let herd = if rng.one_in(2) { &mut east } else { &mut south };
+        herd.set(cell, true);
+    }
+
+    let step = |herd: &mut BitPlane, other: &BitPlane, offset: Cell<2>| {
+        let free = !(&*herd | other);
+        let moving = &*herd & &free.shifted(offset.map(|v| -v));
+        *herd ^= &moving;
+        *herd |= moving.shifted(offset);
+    };
+    c.bench_function("herds_256x256/bit_plane", |b| {
+        b.iter(|| {
+            let (mut east, mut south) = (east.clone(), south.clone());
+            for _ in 0..10 {
+                step(&mut east, &south, [0, 1]);
+                step(&mut south, &east, [1, 0]);
+            }
+            black_box(east.count() + south.count())
+        })
+    });
+}
+
+criterion_group!(benches, bench_life, bench_life_4d, bench_herds);
+criterion_main!(benches);
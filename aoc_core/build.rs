@@ -0,0 +1,50 @@
+//! Generates one regression test per entry in the years' `answers.toml` files.
+//! The tests themselves live in `tests/answers.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "src/answers.rs"]
+mod answers;
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = manifest_dir.parent().unwrap();
+    println!("cargo:rerun-if-changed=src/answers.rs");
+    // Picks up answers files added to years that didn't have one.
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut tests = String::new();
+    for (year, dir) in year_dirs(root) {
+        let path = dir.join(answers::FILE_NAME);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let answers = answers::Answers::load(&dir).unwrap_or_else(|e| panic!("{e}"));
+        for (day, part, _) in answers.iter() {
+            writeln!(
+                tests,
+                "#[test]\n#[ignore = \"needs the puzzle input\"]\n\
+                 fn y{year}_day{day:02}_part{part}() {{\n    check({year}, {day}, {part});\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("answer_tests.rs");
+    fs::write(out, tests).unwrap();
+}
+
+fn year_dirs(root: &Path) -> Vec<(u16, PathBuf)> {
+    let mut years: Vec<_> = fs::read_dir(root)
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let year = entry.file_name().into_string().ok()?.parse().ok()?;
+            Some((year, entry.path()))
+        })
+        .collect();
+    years.sort();
+    years
+}
@@ -0,0 +1,201 @@
+//! Expected answers for the real puzzle inputs.
+//!
+//! Each year keeps an `answers.toml` next to its `Cargo.toml`, with one table per day:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 70720
+//! part2 = "207148"
+//! ```
+//!
+//! Values are integers or quoted strings (`\n` for multi-line answers). Only this subset of
+//! TOML is understood. The file is read by the `aoc` runner and by `build.rs`, which generates
+//! the regression tests in `tests/answers.rs`, so it must not depend on the rest of the crate.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Name of the answers file in each year's directory.
+pub const FILE_NAME: &str = "answers.toml";
+
+/// Result of checking an answer against the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+        };
+        f.pad(s)
+    }
+}
+
+/// Known answers for one year, keyed by (day, part).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// Load `answers.toml` from a year's directory. A missing file is an empty database.
+    pub fn load(year_dir: &Path) -> Result<Self, String> {
+        let path = year_dir.join(FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(s) => Self::parse(&s).map_err(|e| format!("{}: {e}", path.display())),
+            Err(_) if !path.exists() => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Parse the contents of an answers file.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::new();
+        let mut day = None;
+        for (n, line) in s.lines().enumerate() {
+            let err = |msg: &str| format!("line {}: {msg}", n + 1);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let name = table
+                    .strip_suffix(']')
+                    .ok_or_else(|| err("unclosed table"))?;
+                let d = name
+                    .trim()
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse::<u8>().ok())
+                    .filter(|d| (1..=25).contains(d))
+                    .ok_or_else(|| err("expected a [dayNN] table"))?;
+                day = Some(d);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| err("answer outside of a [dayNN] table"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| err("expected key = value"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => return Err(err(&format!("unknown key {key}"))),
+            };
+            let answer = parse_value(value.trim()).ok_or_else(|| err("invalid value"))?;
+            if entries.insert((day, part), answer).is_some() {
+                return Err(err("duplicate answer"));
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    /// The expected answer for a day and part, if known.
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.entries.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare an answer with the expected one, ignoring trailing whitespace.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected.trim_end() == answer.trim_end() => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// All known answers as (day, part, answer), in order.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, u8, &str)> {
+        self.entries
+            .iter()
+            .map(|(&(day, part), answer)| (day, part, answer.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Parse an integer or a basic quoted string.
+fn parse_value(value: &str) -> Option<String> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        let digits = value.strip_prefix('-').unwrap_or(value);
+        let digits = digits.replace('_', "");
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        return Some(value.replace('_', ""));
+    };
+
+    let mut answer = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return chars.as_str().trim().is_empty().then_some(answer),
+            '\\' => match chars.next()? {
+                'n' => answer.push('\n'),
+                't' => answer.push('\t'),
+                '"' => answer.push('"'),
+                '\\' => answer.push('\\'),
+                _ => return None,
+            },
+            c => answer.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "# comment\n\
+             [day01]\n\
+             part1 = 70_720\n\
+             part2 = \"207148\"\n\
+             \n\
+             [day10]\n\
+             part2 = \"#..#\\n.##.\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(1, 1), Some("70720"));
+        assert_eq!(answers.get(1, 2), Some("207148"));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(10, 2), Some("#..#\n.##."));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("part1 = 1").is_err());
+        assert!(Answers::parse("[day26]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1 = abc").is_err());
+        assert!(Answers::parse("[day01]\npart1 = \"abc").is_err());
+        assert!(Answers::parse("[day01]\npart1 = 1\npart1 = 2").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[day03]\npart1 = 157").unwrap();
+        assert_eq!(answers.check(3, 1, "157"), Verdict::Pass);
+        assert_eq!(answers.check(3, 1, "158"), Verdict::Fail);
+        assert_eq!(answers.check(3, 2, "70"), Verdict::Unknown);
+        assert_eq!(Verdict::Unknown.to_string(), "UNKNOWN");
+    }
+}
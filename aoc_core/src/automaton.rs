@@ -0,0 +1,443 @@
+//! Cellular automata: cells that are alive or dead, all updated at once from the states of
+//! their neighbors.
+//!
+//! An automaton is a [`Neighborhood`], the offsets of the cells each cell looks at, and a
+//! [`Rule`] giving its next state from theirs. There are three ways of storing the cells, all
+//! behind the [`Automaton`] trait:
+//!
+//! - [`Sparse`] keeps the live cells in a `HashSet`, in any number of dimensions and with no
+//!   edges. It suits a few live cells spreading through a lot of space.
+//! - [`Dense`] keeps every cell of a box in a `Vec`. The box either grows to follow the cells
+//!   or is fixed with dead cells all around, and cells can be void: never alive, and seen past
+//!   by a line-of-sight neighborhood.
+//! - [`Bits`] packs a fixed 2-D grid 64 cells to a word and steps whole words at once with
+//!   bitwise operations. It takes counting rules over neighbors within the surrounding 3×3
+//!   block, which covers Life and its relatives.
+//!
+//! Automata whose cells move rather than live and die, such as herds or crowds taking turns,
+//! don't fit a [`Rule`]. For those a [`BitPlane`] holds a 2-D grid of bits that can be shifted
+//! and combined with bitwise operators, stepping 64 cells at once all the same.
+//!
+//! Everything outside the cells stored is the background, which starts out dead. A rule can
+//! bring a dead cell with no live neighbors to life, as in 2021 day 20, after which the
+//! infinite background is alive; [`Sparse`] and [`Dense`] then store the dead cells instead,
+//! and the background flips back and forth as the rule dictates.
+//!
+//! For 2-D grids use `[row, column]` cells, so that neighborhoods list offsets in reading
+//! order.
+
+mod bits;
+mod dense;
+mod plane;
+mod sparse;
+
+use std::str::FromStr;
+
+pub use bits::Bits;
+pub use dense::Dense;
+pub use plane::BitPlane;
+pub use sparse::Sparse;
+
+/// A cell's coordinates
+pub type Cell<const D: usize> = [i32; D];
+
+/// Stepping and inspecting an automaton, whichever way its cells are stored.
+pub trait Automaton<const D: usize> {
+    /// Advances one generation, returning whether any cell changed
+    fn step(&mut self) -> bool;
+
+    fn is_alive(&self, cell: Cell<D>) -> bool;
+
+    /// Whether the cells beyond those stored are alive
+    fn background(&self) -> bool;
+
+    /// The number of live cells, or `None` while the background is alive
+    fn population(&self) -> Option<usize>;
+
+    /// Advances `generations` generations
+    fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Steps until a generation changes nothing, returning how many generations did
+    fn run_until_stable(&mut self) -> usize {
+        let mut generations = 0;
+        while self.step() {
+            generations += 1;
+        }
+        generations
+    }
+}
+
+/// The cells whose states decide a cell's next state, as offsets from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighborhood<const D: usize> {
+    offsets: Vec<Cell<D>>,
+    line_of_sight: bool,
+}
+
+impl<const D: usize> Neighborhood<D> {
+    /// The given offsets, in the order given
+    pub fn new(offsets: Vec<Cell<D>>) -> Self {
+        Self {
+            offsets,
+            line_of_sight: false,
+        }
+    }
+
+    /// Every cell within `radius` along each axis, including the cell itself, in reading order
+    pub fn block(radius: i32) -> Self {
+        let side = (2 * radius + 1) as usize;
+        // Counting in base `side` with the first axis most significant gives reading order
+        let offsets = (0..side.pow(D as u32))
+            .map(|mut n| {
+                let mut offset = [0; D];
+                for axis in (0..D).rev() {
+                    offset[axis] = (n % side) as i32 - radius;
+                    n /= side;
+                }
+                offset
+            })
+            .collect();
+        Self::new(offsets)
+    }
+
+    /// Every cell within `radius` along each axis except the cell itself: the 8 surrounding
+    /// cells of a 2-D grid for a radius of 1
+    pub fn moore(radius: i32) -> Self {
+        let mut neighborhood = Self::block(radius);
+        neighborhood.offsets.retain(|offset| *offset != [0; D]);
+        neighborhood
+    }
+
+    /// Every cell within Manhattan distance `radius` except the cell itself
+    pub fn von_neumann(radius: i32) -> Self {
+        let mut neighborhood = Self::moore(radius);
+        neighborhood
+            .offsets
+            .retain(|offset| offset.iter().map(|v| v.abs()).sum::<i32>() <= radius);
+        neighborhood
+    }
+
+    /// Looks along each offset to the first cell that isn't void, rather than at the cell at
+    /// the offset. Only [`Dense`] has void cells; elsewhere this makes no difference.
+    pub fn line_of_sight(mut self) -> Self {
+        self.line_of_sight = true;
+        self
+    }
+
+    pub fn offsets(&self) -> &[Cell<D>] {
+        &self.offsets
+    }
+
+    /// How far the furthest offset reaches along any axis
+    pub fn radius(&self) -> i32 {
+        self.offsets
+            .iter()
+            .flatten()
+            .map(|v| v.abs())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// How a cell's next state follows from its own and its neighbors'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// Counts the live cells in the neighborhood: bit `n` of `born` says whether a dead cell
+    /// with `n` of them comes alive, and bit `n` of `survive` whether a live one stays alive.
+    Totalistic { born: u128, survive: u128 },
+    /// Reads the neighborhood as a binary number, live cells as 1 and the first neighbor as
+    /// the most significant bit, and looks the next state up in the table.
+    Lookup(Vec<bool>),
+}
+
+impl Rule {
+    /// A counting rule from the numbers of live neighbors that bring a dead cell to life and
+    /// that keep a live one alive
+    pub fn life_like(born: &[usize], survive: &[usize]) -> Rule {
+        let mask = |counts: &[usize]| counts.iter().fold(0u128, |mask, n| mask | 1 << n);
+        Rule::Totalistic {
+            born: mask(born),
+            survive: mask(survive),
+        }
+    }
+
+    /// The next state of a cell given its own and its neighbors', in neighborhood order
+    pub fn next(&self, alive: bool, neighbors: impl Iterator<Item = bool>) -> bool {
+        match self {
+            Rule::Totalistic { born, survive } => {
+                let count = neighbors.filter(|&n| n).count();
+                let mask = if alive { survive } else { born };
+                count < 128 && mask >> count & 1 == 1
+            }
+            Rule::Lookup(table) => table[neighbors.fold(0, |index, n| index << 1 | n as usize)],
+        }
+    }
+
+    /// The next state of the background, which is a cell whose neighbors are all like it
+    fn next_background<const D: usize>(
+        &self,
+        neighborhood: &Neighborhood<D>,
+        background: bool,
+    ) -> bool {
+        let neighbors = std::iter::repeat_n(background, neighborhood.offsets.len());
+        self.next(background, neighbors)
+    }
+}
+
+/// Rules in B/S notation, such as "B3/S23" for Life.
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (born, survive) = s
+            .split_once('/')
+            .ok_or_else(|| format!("Expected B.../S... in rule: {}", s))?;
+        let counts = |part: &str, prefix: char| {
+            part.strip_prefix(prefix)
+                .ok_or_else(|| format!("Expected {} in rule: {}", prefix, s))?
+                .chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|n| n as usize)
+                        .ok_or_else(|| format!("Invalid count '{}' in rule: {}", c, s))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Rule::life_like(&counts(born, 'B')?, &counts(survive, 'S')?))
+    }
+}
+
+fn add<const D: usize>(a: Cell<D>, b: Cell<D>) -> Cell<D> {
+    std::array::from_fn(|axis| a[axis] + b[axis])
+}
+
+fn sub<const D: usize>(a: Cell<D>, b: Cell<D>) -> Cell<D> {
+    std::array::from_fn(|axis| a[axis] - b[axis])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval;
+    use crate::rng::XorShift;
+
+    fn life() -> Rule {
+        "B3/S23".parse().unwrap()
+    }
+
+    /// Cells marked '#', as `[row, column]`
+    fn parse(pattern: &str) -> Vec<Cell<2>> {
+        pattern
+            .lines()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.trim()
+                    .chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(col, _)| [row as i32, col as i32])
+            })
+            .collect()
+    }
+
+    fn live_cells<const D: usize>(
+        automaton: &impl Automaton<D>,
+        bounds: interval::Box<i32, D>,
+    ) -> Vec<Cell<D>> {
+        let mut cells = vec![bounds.min];
+        for axis in 0..D {
+            cells = cells
+                .into_iter()
+                .flat_map(|cell| {
+                    bounds.interval(axis).map(move |v| {
+                        let mut cell = cell;
+                        cell[axis] = v;
+                        cell
+                    })
+                })
+                .collect();
+        }
+        cells.retain(|&cell| automaton.is_alive(cell));
+        cells
+    }
+
+    #[test]
+    fn test_neighborhoods() {
+        assert_eq!(
+            Neighborhood::<2>::block(1).offsets()[..4],
+            [[-1, -1], [-1, 0], [-1, 1], [0, -1]]
+        );
+        assert_eq!(Neighborhood::<2>::moore(1).offsets().len(), 8);
+        assert_eq!(Neighborhood::<4>::moore(1).offsets().len(), 80);
+        assert_eq!(
+            Neighborhood::<2>::von_neumann(1).offsets(),
+            [[-1, 0], [0, -1], [0, 1], [1, 0]]
+        );
+        assert_eq!(Neighborhood::<3>::von_neumann(2).offsets().len(), 24);
+        assert_eq!(Neighborhood::<2>::moore(2).radius(), 2);
+    }
+
+    #[test]
+    fn test_rules() {
+        let life = life();
+        assert_eq!(life, Rule::life_like(&[3], &[2, 3]));
+        assert!(life.next(false, [true, true, true, false].into_iter()));
+        assert!(!life.next(false, [true, true].into_iter()));
+        assert!(life.next(true, [true, true].into_iter()));
+        assert!("B3S23".parse::<Rule>().is_err());
+        assert!("B3/S2x".parse::<Rule>().is_err());
+
+        // Alive when exactly the first of three neighbors is
+        let lookup = Rule::Lookup(vec![false, false, false, false, true, false, false, false]);
+        assert!(lookup.next(false, [true, false, false].into_iter()));
+        assert!(!lookup.next(false, [false, false, true].into_iter()));
+    }
+
+    #[test]
+    fn test_glider() {
+        let glider = parse(
+            ".#.
+             ..#
+             ###",
+        );
+        let moved: Vec<_> = glider.iter().map(|&cell| add(cell, [1, 1])).collect();
+        let bounds = interval::Box::new([-2, -2], [10, 10]);
+
+        let mut sparse = Sparse::new(Neighborhood::moore(1), life(), glider.clone());
+        let mut dense = Dense::new(Neighborhood::moore(1), life(), glider.clone());
+        let mut bits = Bits::new(Neighborhood::moore(1), life(), bounds, glider).unwrap();
+        sparse.run(4);
+        dense.run(4);
+        bits.run(4);
+        for cells in [
+            live_cells(&sparse, bounds),
+            live_cells(&dense, bounds),
+            live_cells(&bits, bounds),
+        ] {
+            let mut cells = cells;
+            cells.sort();
+            assert_eq!(cells, moved);
+        }
+        assert_eq!(sparse.population(), Some(5));
+    }
+
+    #[test]
+    fn test_backends_agree() {
+        // A random soup across a word boundary, stepped in a bounded world
+        let bounds = interval::Box::new([0, 0], [20, 100]);
+        let mut rng = XorShift::new(0x2545f4914f6cdd1d);
+        let soup: Vec<_> = (0..20)
+            .flat_map(|row| (0..100).map(move |col| [row, col]))
+            .filter(|_| rng.one_in(3))
+            .collect();
+        let voids = [[5, 63], [5, 64], [10, 10]];
+        let rule = Rule::life_like(&[3, 6], &[2, 3]);
+
+        let mut dense = Dense::bounded(Neighborhood::moore(1), rule.clone(), bounds, soup.clone())
+            .with_voids(voids);
+        let mut bits = Bits::new(Neighborhood::moore(1), rule, bounds, soup)
+            .unwrap()
+            .with_voids(voids);
+        for _ in 0..30 {
+            assert_eq!(dense.step(), bits.step());
+            assert_eq!(live_cells(&dense, bounds), live_cells(&bits, bounds));
+        }
+        assert_eq!(dense.population(), bits.population());
+    }
+
+    #[test]
+    fn test_background_flips() {
+        // Everything comes alive when all three cells are dead and dies when all are alive,
+        // so the background flips every generation. Otherwise a cell copies its left neighbor.
+        let table = (0..8).map(|pattern| match pattern {
+            0b000 => true,
+            0b111 => false,
+            _ => pattern & 0b100 != 0,
+        });
+        let rule = Rule::Lookup(table.collect());
+        let neighborhood = Neighborhood::new(vec![[-1], [0], [1]]);
+
+        let mut sparse = Sparse::new(neighborhood.clone(), rule.clone(), [[0]]);
+        let mut dense = Dense::new(neighborhood, rule, [[0]]);
+        for generation in 1..=4 {
+            sparse.step();
+            dense.step();
+            let background = generation % 2 == 1;
+            assert_eq!(sparse.background(), background);
+            assert_eq!(dense.background(), background);
+            assert_eq!(sparse.population().is_none(), background);
+            for cell in -10..10 {
+                assert_eq!(
+                    sparse.is_alive([cell]),
+                    dense.is_alive([cell]),
+                    "cell {cell}"
+                );
+            }
+            assert_eq!(sparse.is_alive([100]), background);
+        }
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        // A live cell sees the cells across the void, but not past the first one
+        let bounds = interval::Box::new([0, 0], [1, 5]);
+        let rule = Rule::life_like(&[1], &[]);
+        let mut dense = Dense::bounded(
+            Neighborhood::moore(1).line_of_sight(),
+            rule,
+            bounds,
+            [[0, 0]],
+        )
+        .with_voids([[0, 1], [0, 2]]);
+        dense.step();
+        assert_eq!(live_cells(&dense, bounds), [[0, 3]]);
+        dense.step();
+        assert_eq!(live_cells(&dense, bounds), [[0, 0], [0, 4]]);
+    }
+
+    #[test]
+    fn test_bit_plane() {
+        // 70 columns, so that rows take two words and the second isn't full
+        let mut plane = BitPlane::new(3, 70);
+        for cell in [[0, 0], [1, 63], [2, 69]] {
+            plane.set(cell, true);
+        }
+        assert_eq!(plane.count(), 3);
+        assert!(!plane.get([3, 0]) && !plane.get([0, -1]));
+
+        let moved = plane.shifted([1, 1]);
+        assert_eq!(moved.cells().collect::<Vec<_>>(), [[1, 1], [2, 64]]);
+        assert_eq!(moved.shifted([-1, -1]).count(), 2);
+        assert_eq!((!&plane).count(), 3 * 70 - 3);
+        assert_eq!(&plane & &moved, BitPlane::new(3, 70));
+        assert_eq!((&plane | &moved).count(), 5);
+
+        let mut torus = BitPlane::torus(3, 70);
+        torus.set([2, 69], true);
+        assert_eq!(torus.shifted([1, 1]).cells().collect::<Vec<_>>(), [[0, 0]]);
+        assert_eq!(
+            torus.shifted([-5, 140]).cells().collect::<Vec<_>>(),
+            [[0, 69]]
+        );
+    }
+
+    #[test]
+    fn test_bits_limits() {
+        let bounds = interval::Box::new([0, 0], [3, 3]);
+        let lookup = Rule::Lookup(vec![false; 512]);
+        assert!(Bits::new(Neighborhood::block(1), lookup, bounds, []).is_err());
+        assert!(Bits::new(Neighborhood::moore(2), life(), bounds, []).is_err());
+        assert!(Bits::new(Neighborhood::moore(1).line_of_sight(), life(), bounds, []).is_err());
+        let bits = Bits::new(
+            Neighborhood::von_neumann(1),
+            life(),
+            bounds,
+            [[1, 1], [7, 7]],
+        );
+        assert_eq!(bits.unwrap().population(), Some(1));
+    }
+}
@@ -0,0 +1,128 @@
+use super::{sub, Automaton, BitPlane, Cell, Neighborhood, Rule};
+use crate::interval;
+
+/// A fixed 2-D automaton with dead cells all around, packed 64 cells to a word so that a
+/// generation steps 64 cells at once.
+///
+/// A generation adds the neighbors' [`BitPlane`]s, shifted into line, into a 4-bit count per
+/// cell held across four planes, and the rule picks out the counts that give a live cell.
+#[derive(Debug, Clone)]
+pub struct Bits {
+    bounds: interval::Box<i32, 2>,
+    cells: BitPlane,
+    // The cells that can be alive: those in bounds that aren't void
+    mask: BitPlane,
+    offsets: Vec<Cell<2>>,
+    born: u16,
+    survive: u16,
+}
+
+impl Bits {
+    /// An automaton of the cells in `bounds`. Only counting rules and neighborhoods within
+    /// the surrounding 3×3 block will do.
+    pub fn new(
+        neighborhood: Neighborhood<2>,
+        rule: Rule,
+        bounds: interval::Box<i32, 2>,
+        live: impl IntoIterator<Item = Cell<2>>,
+    ) -> Result<Self, String> {
+        let Rule::Totalistic { born, survive } = rule else {
+            return Err("Only counting rules can be bit-packed".to_string());
+        };
+        if neighborhood.line_of_sight || neighborhood.radius() > 1 {
+            return Err("Only neighbors next to the cell can be bit-packed".to_string());
+        }
+
+        let [rows, cols] = [0, 1].map(|axis| bounds.interval(axis).len());
+        let cells = BitPlane::new(rows, cols);
+        let mut bits = Self {
+            bounds,
+            mask: !&cells,
+            cells,
+            offsets: neighborhood.offsets().to_vec(),
+            // A block has at most 9 cells, so higher counts never come up
+            born: born as u16 & 0x3ff,
+            survive: survive as u16 & 0x3ff,
+        };
+        for cell in live {
+            if let Some(cell) = bits.position(cell) {
+                bits.cells.set(cell, true);
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Makes cells void: never alive
+    pub fn with_voids(mut self, voids: impl IntoIterator<Item = Cell<2>>) -> Self {
+        for cell in voids {
+            if let Some(cell) = self.position(cell) {
+                self.mask.set(cell, false);
+                self.cells.set(cell, false);
+            }
+        }
+        self
+    }
+
+    /// Where `cell` is on the plane
+    fn position(&self, cell: Cell<2>) -> Option<Cell<2>> {
+        self.bounds
+            .contains(cell)
+            .then(|| sub(cell, self.bounds.min))
+    }
+}
+
+/// The cells whose 4-bit count, bit `i` of the count in `count[i]`, is one of those in `set`
+fn counts_in(count: &[u64; 4], set: u16) -> u64 {
+    (0..10)
+        .filter(|n| set >> n & 1 == 1)
+        .map(|n| {
+            (0..4).fold(!0, |cells, i| {
+                cells & if n >> i & 1 == 1 { count[i] } else { !count[i] }
+            })
+        })
+        .fold(0, |cells, matching| cells | matching)
+}
+
+impl Automaton<2> for Bits {
+    fn step(&mut self) -> bool {
+        // Each neighbor's cells, moved onto the cell they neighbor
+        let neighbors: Vec<BitPlane> = self
+            .offsets
+            .iter()
+            .map(|&offset| self.cells.shifted(sub([0, 0], offset)))
+            .collect();
+
+        let mut next = self.mask.clone();
+        for (i, word) in next.words.iter_mut().enumerate() {
+            let mut count = [0u64; 4];
+            for neighbor in &neighbors {
+                // Add the neighbor's bits with a ripple carry through the count
+                let mut carry = neighbor.words[i];
+                for bit in &mut count {
+                    let sum = *bit ^ carry;
+                    carry &= *bit;
+                    *bit = sum;
+                }
+            }
+            let alive = self.cells.words[i];
+            *word &=
+                alive & counts_in(&count, self.survive) | !alive & counts_in(&count, self.born);
+        }
+
+        let changed = next != self.cells;
+        self.cells = next;
+        changed
+    }
+
+    fn is_alive(&self, cell: Cell<2>) -> bool {
+        self.position(cell).is_some_and(|cell| self.cells.get(cell))
+    }
+
+    fn background(&self) -> bool {
+        false
+    }
+
+    fn population(&self) -> Option<usize> {
+        Some(self.cells.count())
+    }
+}
@@ -0,0 +1,185 @@
+use super::{add, Automaton, Cell, Neighborhood, Rule};
+use crate::interval;
+
+/// An automaton that keeps every cell of a box in a `Vec`, the last axis varying fastest.
+#[derive(Debug, Clone)]
+pub struct Dense<const D: usize> {
+    neighborhood: Neighborhood<D>,
+    rule: Rule,
+    bounds: interval::Box<i32, D>,
+    cells: Vec<bool>,
+    // Whether each cell is void; empty when none are
+    voids: Vec<bool>,
+    background: bool,
+    // Whether the box grows to follow the cells, rather than having dead cells all around
+    infinite: bool,
+}
+
+impl<const D: usize> Dense<D> {
+    /// An unbounded automaton. The box starts around the live cells and grows by the
+    /// neighborhood's radius each generation.
+    pub fn new(
+        neighborhood: Neighborhood<D>,
+        rule: Rule,
+        live: impl IntoIterator<Item = Cell<D>>,
+    ) -> Self {
+        let live: Vec<_> = live.into_iter().collect();
+        let bounds = match live.first() {
+            None => interval::Box::new([0; D], [0; D]),
+            Some(&first) => live.iter().fold(
+                interval::Box::new(first, first.map(|v| v + 1)),
+                |bounds, cell| {
+                    interval::Box::new(
+                        std::array::from_fn(|axis| bounds.min[axis].min(cell[axis])),
+                        std::array::from_fn(|axis| bounds.max[axis].max(cell[axis] + 1)),
+                    )
+                },
+            ),
+        };
+        Self::with_bounds(neighborhood, rule, bounds, live, true)
+    }
+
+    /// An automaton of the cells in `bounds`, with dead cells all around. Live cells outside
+    /// the bounds are ignored.
+    pub fn bounded(
+        neighborhood: Neighborhood<D>,
+        rule: Rule,
+        bounds: interval::Box<i32, D>,
+        live: impl IntoIterator<Item = Cell<D>>,
+    ) -> Self {
+        Self::with_bounds(neighborhood, rule, bounds, live, false)
+    }
+
+    fn with_bounds(
+        neighborhood: Neighborhood<D>,
+        rule: Rule,
+        bounds: interval::Box<i32, D>,
+        live: impl IntoIterator<Item = Cell<D>>,
+        infinite: bool,
+    ) -> Self {
+        let mut cells = vec![false; bounds.volume() as usize];
+        for cell in live {
+            if let Some(i) = index(&bounds, cell) {
+                cells[i] = true;
+            }
+        }
+        Self {
+            neighborhood,
+            rule,
+            bounds,
+            cells,
+            voids: Vec::new(),
+            background: false,
+            infinite,
+        }
+    }
+
+    /// Makes cells void: never alive, and seen past by line-of-sight neighborhoods
+    pub fn with_voids(mut self, voids: impl IntoIterator<Item = Cell<D>>) -> Self {
+        if self.voids.is_empty() {
+            self.voids = vec![false; self.cells.len()];
+        }
+        for cell in voids {
+            if let Some(i) = index(&self.bounds, cell) {
+                self.voids[i] = true;
+                self.cells[i] = false;
+            }
+        }
+        self
+    }
+
+    /// The box of cells stored
+    pub fn bounds(&self) -> interval::Box<i32, D> {
+        self.bounds
+    }
+
+    fn is_void(&self, cell: Cell<D>) -> bool {
+        index(&self.bounds, cell).is_some_and(|i| self.voids.get(i) == Some(&true))
+    }
+
+    fn neighbor(&self, cell: Cell<D>, offset: Cell<D>) -> bool {
+        let mut seen = add(cell, offset);
+        if self.neighborhood.line_of_sight && offset != [0; D] {
+            // Voids all lie within the bounds, so this stops at the edge at the latest
+            while self.is_void(seen) {
+                seen = add(seen, offset);
+            }
+        }
+        self.is_alive(seen)
+    }
+}
+
+impl<const D: usize> Automaton<D> for Dense<D> {
+    fn step(&mut self) -> bool {
+        let bounds = if self.infinite {
+            let radius = self.neighborhood.radius();
+            interval::Box::new(
+                self.bounds.min.map(|v| v - radius),
+                self.bounds.max.map(|v| v + radius),
+            )
+        } else {
+            self.bounds
+        };
+        let background = self.infinite
+            && self
+                .rule
+                .next_background(&self.neighborhood, self.background);
+
+        let mut changed = background != self.background;
+        let offsets = self.neighborhood.offsets();
+        let next = cells(bounds)
+            .map(|cell| {
+                let alive = self.is_alive(cell);
+                let neighbors = offsets.iter().map(|&o| self.neighbor(cell, o));
+                let state = !self.is_void(cell) && self.rule.next(alive, neighbors);
+                changed |= state != alive;
+                state
+            })
+            .collect();
+        if !self.voids.is_empty() && bounds != self.bounds {
+            self.voids = cells(bounds).map(|cell| self.is_void(cell)).collect();
+        }
+
+        self.bounds = bounds;
+        self.cells = next;
+        self.background = background;
+        changed
+    }
+
+    fn is_alive(&self, cell: Cell<D>) -> bool {
+        match index(&self.bounds, cell) {
+            Some(i) => self.cells[i],
+            None => self.background,
+        }
+    }
+
+    fn background(&self) -> bool {
+        self.background
+    }
+
+    fn population(&self) -> Option<usize> {
+        (!self.background).then(|| self.cells.iter().filter(|&&alive| alive).count())
+    }
+}
+
+fn index<const D: usize>(bounds: &interval::Box<i32, D>, cell: Cell<D>) -> Option<usize> {
+    bounds.contains(cell).then(|| {
+        (0..D).fold(0, |index, axis| {
+            let size = (bounds.max[axis] - bounds.min[axis]) as usize;
+            index * size + (cell[axis] - bounds.min[axis]) as usize
+        })
+    })
+}
+
+/// The cells of `bounds` in index order
+fn cells<const D: usize>(bounds: interval::Box<i32, D>) -> impl Iterator<Item = Cell<D>> {
+    (0..bounds.volume() as usize).map(move |mut n| {
+        let mut cell = [0; D];
+        for axis in (0..D).rev() {
+            let size = (bounds.max[axis] - bounds.min[axis]) as usize;
+            cell[axis] = bounds.min[axis] + (n % size) as i32;
+            n /= size;
+        }
+        cell
+    })
+}
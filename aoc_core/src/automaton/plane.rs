@@ -0,0 +1,242 @@
+use super::Cell;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+/// A fixed 2-D grid of bits, 64 cells to a word, for automata that aren't counting rules:
+/// a generation is a handful of whole-grid shifts and bitwise operations.
+///
+/// Cells are `[row, column]` from `[0, 0]`. Beyond the edges the cells are dead, or, on a
+/// [torus](BitPlane::torus), those of the opposite edge. The words are plain `u64`s rather
+/// than SIMD vectors, which keeps this on stable Rust.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitPlane {
+    rows: usize,
+    cols: usize,
+    // Words in each row, the last with its bits past `cols` clear
+    stride: usize,
+    wrap: bool,
+    pub(super) words: Vec<u64>,
+}
+
+impl BitPlane {
+    /// A `rows` × `cols` plane of dead cells with dead cells all around
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let stride = cols.div_ceil(64);
+        Self {
+            rows,
+            cols,
+            stride,
+            wrap: false,
+            words: vec![0; rows * stride],
+        }
+    }
+
+    /// A `rows` × `cols` plane of dead cells whose edges wrap around
+    pub fn torus(rows: usize, cols: usize) -> Self {
+        Self {
+            wrap: true,
+            ..Self::new(rows, cols)
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, [row, col]: Cell<2>) -> bool {
+        (0..self.rows as i32).contains(&row) && (0..self.cols as i32).contains(&col)
+    }
+
+    /// The word holding `cell` and its bit within it
+    fn position(&self, [row, col]: Cell<2>) -> (usize, u64) {
+        let col = col as usize;
+        (row as usize * self.stride + col / 64, 1 << (col % 64))
+    }
+
+    /// Whether `cell` is alive, dead for cells off the plane
+    pub fn get(&self, cell: Cell<2>) -> bool {
+        self.contains(cell) && {
+            let (i, bit) = self.position(cell);
+            self.words[i] & bit != 0
+        }
+    }
+
+    /// Panics if `cell` is off the plane
+    pub fn set(&mut self, cell: Cell<2>, alive: bool) {
+        assert!(self.contains(cell), "{cell:?} is off the plane");
+        let (i, bit) = self.position(cell);
+        if alive {
+            self.words[i] |= bit;
+        } else {
+            self.words[i] &= !bit;
+        }
+    }
+
+    /// The number of live cells
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// The live cells in reading order
+    pub fn cells(&self) -> impl Iterator<Item = Cell<2>> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let row = (i / self.stride) as i32;
+            let first = (i % self.stride * 64) as i32;
+            (0..64)
+                .filter(move |bit| word >> bit & 1 == 1)
+                .map(move |bit| [row, first + bit])
+        })
+    }
+
+    /// The plane with every cell moved by `offset`: cell `c` of the result is cell
+    /// `c - offset` of this one
+    pub fn shifted(&self, [dr, dc]: Cell<2>) -> Self {
+        if !self.wrap {
+            return self.shifted_off_edges(dr as isize, dc as isize);
+        }
+
+        // The cells that wrap around are those shifted the other way by the rest of the way
+        let dr = dr.rem_euclid(self.rows as i32) as isize;
+        let dc = dc.rem_euclid(self.cols as i32) as isize;
+        let (rows, cols) = (self.rows as isize, self.cols as isize);
+        self.shifted_off_edges(dr, dc)
+            | self.shifted_off_edges(dr - rows, dc)
+            | self.shifted_off_edges(dr, dc - cols)
+            | self.shifted_off_edges(dr - rows, dc - cols)
+    }
+
+    /// Shifts by `dr` rows and `dc` columns, dropping the cells that go off the edges
+    fn shifted_off_edges(&self, dr: isize, dc: isize) -> Self {
+        let mut shifted = Self {
+            words: vec![0; self.words.len()],
+            ..*self
+        };
+        let (skip, bits) = (dc.div_euclid(64), dc.rem_euclid(64) as u32);
+        for row in 0..self.rows {
+            let from = row as isize - dr;
+            if !(0..self.rows as isize).contains(&from) {
+                continue;
+            }
+            let source = &self.words[from as usize * self.stride..][..self.stride];
+            let target = &mut shifted.words[row * self.stride..][..self.stride];
+            if dc == 0 {
+                target.copy_from_slice(source);
+                continue;
+            }
+            let word = |index: isize| {
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| source.get(index))
+                    .copied()
+                    .unwrap_or(0)
+            };
+            for (index, shifted_word) in target.iter_mut().enumerate() {
+                let from = index as isize - skip;
+                *shifted_word = word(from) << bits;
+                if bits > 0 {
+                    *shifted_word |= word(from - 1) >> (64 - bits);
+                }
+            }
+        }
+        shifted.clear_padding();
+        shifted
+    }
+
+    /// Clears the bits past the last column
+    fn clear_padding(&mut self) {
+        if !self.cols.is_multiple_of(64) {
+            let mask = (1 << (self.cols % 64)) - 1;
+            for row in self.words.chunks_mut(self.stride) {
+                row[self.stride - 1] &= mask;
+            }
+        }
+    }
+
+    fn combine(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "planes of different sizes"
+        );
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word = op(*word, other);
+        }
+    }
+}
+
+impl Not for BitPlane {
+    type Output = BitPlane;
+
+    fn not(mut self) -> BitPlane {
+        for word in &mut self.words {
+            *word = !*word;
+        }
+        self.clear_padding();
+        self
+    }
+}
+
+impl Not for &BitPlane {
+    type Output = BitPlane;
+
+    fn not(self) -> BitPlane {
+        !self.clone()
+    }
+}
+
+/// Implements a bitwise operator, and its assigning form, cell by cell for planes and
+/// references to them
+macro_rules! bitwise {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $f:expr) => {
+        impl $assign<&BitPlane> for BitPlane {
+            fn $assign_method(&mut self, rhs: &BitPlane) {
+                self.combine(rhs, $f);
+            }
+        }
+
+        impl $assign for BitPlane {
+            fn $assign_method(&mut self, rhs: BitPlane) {
+                self.combine(&rhs, $f);
+            }
+        }
+
+        impl $op<&BitPlane> for BitPlane {
+            type Output = BitPlane;
+
+            fn $method(mut self, rhs: &BitPlane) -> BitPlane {
+                self.combine(rhs, $f);
+                self
+            }
+        }
+
+        impl $op for BitPlane {
+            type Output = BitPlane;
+
+            fn $method(self, rhs: BitPlane) -> BitPlane {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $op for &BitPlane {
+            type Output = BitPlane;
+
+            fn $method(self, rhs: &BitPlane) -> BitPlane {
+                self.clone().$method(rhs)
+            }
+        }
+    };
+}
+
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use std::iter::once;
+
+use super::{add, sub, Automaton, Cell, Neighborhood, Rule};
+
+/// An unbounded automaton that keeps the cells differing from the background in a `HashSet`.
+#[derive(Debug, Clone)]
+pub struct Sparse<const D: usize> {
+    neighborhood: Neighborhood<D>,
+    rule: Rule,
+    cells: HashSet<Cell<D>>,
+    background: bool,
+}
+
+impl<const D: usize> Sparse<D> {
+    pub fn new(
+        neighborhood: Neighborhood<D>,
+        rule: Rule,
+        live: impl IntoIterator<Item = Cell<D>>,
+    ) -> Self {
+        Self {
+            neighborhood,
+            rule,
+            cells: live.into_iter().collect(),
+            background: false,
+        }
+    }
+
+    /// The cells that differ from the background: the live ones, unless the background is
+    /// alive
+    pub fn cells(&self) -> impl Iterator<Item = &Cell<D>> {
+        self.cells.iter()
+    }
+}
+
+impl<const D: usize> Automaton<D> for Sparse<D> {
+    fn step(&mut self) -> bool {
+        let offsets = self.neighborhood.offsets();
+        // Only the cells with a stored cell in their neighborhood can differ from the
+        // background next time
+        let candidates: HashSet<_> = self
+            .cells
+            .iter()
+            .flat_map(|&cell| once(cell).chain(offsets.iter().map(move |&o| sub(cell, o))))
+            .collect();
+        let background = self
+            .rule
+            .next_background(&self.neighborhood, self.background);
+        let cells: HashSet<_> = candidates
+            .into_iter()
+            .filter(|&cell| {
+                let neighbors = offsets.iter().map(|&o| self.is_alive(add(cell, o)));
+                self.rule.next(self.is_alive(cell), neighbors) != background
+            })
+            .collect();
+
+        let changed = background != self.background || cells != self.cells;
+        self.cells = cells;
+        self.background = background;
+        changed
+    }
+
+    fn is_alive(&self, cell: Cell<D>) -> bool {
+        self.cells.contains(&cell) != self.background
+    }
+
+    fn background(&self) -> bool {
+        self.background
+    }
+
+    fn population(&self) -> Option<usize> {
+        (!self.background).then_some(self.cells.len())
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_core::runner::{self, DayReport};
+use aoc_core::{Answers, Part, Verdict};
+
+const USAGE: &str =
+    "Usage: aoc [--year <YYYY>] [--day <N>] [--part <1|2|both>] [--debug] [--root <DIR>]";
+
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Part,
+    release: bool,
+    root: PathBuf,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        year: None,
+        day: None,
+        part: Part::Both,
+        release: true,
+        root: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/..")),
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or(format!("Missing value for {arg}"));
+        match arg.as_str() {
+            "--year" => {
+                let v = value()?;
+                args.year = Some(v.parse().map_err(|_| format!("Invalid year: {v}"))?);
+            }
+            "--day" => {
+                let v = value()?;
+                args.day = Some(v.parse().map_err(|_| format!("Invalid day: {v}"))?);
+            }
+            "--part" => args.part = Part::parse(&value()?)?,
+            "--root" => args.root = PathBuf::from(value()?),
+            "--debug" => args.release = false,
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+    Ok(args)
+}
+
+#[derive(Default)]
+struct Tally {
+    pass: usize,
+    fail: usize,
+    unknown: usize,
+}
+
+impl Tally {
+    fn add(&mut self, verdict: Verdict) {
+        match verdict {
+            Verdict::Pass => self.pass += 1,
+            Verdict::Fail => self.fail += 1,
+            Verdict::Unknown => self.unknown += 1,
+        }
+    }
+}
+
+fn print_report(report: &DayReport, answers: &Answers, tally: &mut Tally) {
+    for result in &report.parts {
+        let verdict = answers.check(report.day, result.part, &result.answer);
+        tally.add(verdict);
+        let mut lines = result.answer.lines();
+        let first = lines.next().unwrap_or("");
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:>10}  {}",
+            report.year,
+            report.day,
+            result.part,
+            first,
+            format!("{:.1?}", result.elapsed),
+            verdict
+        );
+        for line in lines {
+            println!("{:16}{line}", "");
+        }
+        if verdict == Verdict::Fail {
+            let expected = answers.get(report.day, result.part).unwrap_or_default();
+            println!("{:16}expected: {}", "", expected.replace('\n', "\\n"));
+        }
+    }
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    });
+
+    let years: Vec<_> = runner::years(&args.root)
+        .into_iter()
+        .filter(|y| args.year.is_none_or(|year| y.year == year))
+        .collect();
+    if years.is_empty() {
+        eprintln!("No matching years found under {}", args.root.display());
+        std::process::exit(1);
+    }
+
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:>10}  Check",
+        "Year", "Day", "Part", "Answer", "Time"
+    );
+    let mut total = Duration::ZERO;
+    let mut failures = 0;
+    let mut tally = Tally::default();
+    for year in &years {
+        let answers = year.answers().unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(2);
+        });
+        for day in year.days() {
+            if args.day.is_some_and(|d| d != day) {
+                continue;
+            }
+            match runner::run_day(year, day, args.part, args.release) {
+                Ok(report) => {
+                    total +=
+                        report.parse + report.parts.iter().map(|p| p.elapsed).sum::<Duration>();
+                    print_report(&report, &answers, &mut tally);
+                }
+                Err(e) => {
+                    failures += 1;
+                    println!("{:>4}  {:>3}  {:>4}  ERROR: {e}", year.year, day, "-");
+                }
+            }
+        }
+    }
+    println!("Total time: {total:.1?}");
+    println!(
+        "Answers: {} passed, {} failed, {} unknown",
+        tally.pass, tally.fail, tally.unknown
+    );
+
+    if failures > 0 || tally.fail > 0 {
+        std::process::exit(1);
+    }
+}
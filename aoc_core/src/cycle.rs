@@ -0,0 +1,222 @@
+//! Finding where a simulation starts repeating, so it can be skipped ahead to a far-off step.
+//!
+//! A simulation is a start state and a step function, giving the sequence `x0, f(x0),
+//! f(f(x0)), ...`. If the states are finite the sequence ends up in a loop: after a prefix of
+//! `prefix` states it repeats every `length` states. [`floyd`] and [`brent`] find that loop in
+//! constant memory by comparing states. [`find_repeat`] and [`find_repeat_by_key`] hash the
+//! states instead, keeping every state seen, which is usually quicker and lets the state at a
+//! far-off step be looked up directly.
+//!
+//! Hashing a key rather than the whole state helps when the state carries something that
+//! keeps growing, like the height of a tower: the key is what decides the future, and
+//! [`Cycle::extrapolate`] carries the growing value forward.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence starts repeating: the state at step `prefix + length` is the same as the
+/// one at step `prefix`, and `prefix` and `length` are as small as possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step before `prefix + length` whose state matches step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+
+    /// The state at step `n`, found by running the simulation only as far as the equivalent
+    /// step.
+    pub fn state_at<S>(&self, start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.equivalent(n)).fold(start, |state, _| step(&state))
+    }
+
+    /// Extends a value that grows by the same amount every time round the cycle, like the
+    /// height of a tower, to step `n`. `value_at` gives the value at each step up to
+    /// `prefix + length`.
+    pub fn extrapolate(&self, n: usize, value_at: impl Fn(usize) -> i64) -> i64 {
+        if n < self.prefix + self.length {
+            return value_at(n);
+        }
+        let per_cycle = value_at(self.prefix + self.length) - value_at(self.prefix);
+        let cycles = ((n - self.prefix) / self.length) as i64;
+        value_at(self.equivalent(n)) + cycles * per_cycle
+    }
+}
+
+/// Floyd's tortoise and hare. Runs the step function about `3 * (prefix + length)` times.
+pub fn floyd<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    // The hare is now a whole number of cycles ahead, so walking the tortoise from the start
+    // and the hare from where it is, in step, they meet where the cycle begins.
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { prefix, length }
+}
+
+/// Brent's algorithm: finds the cycle length first, by teleporting the tortoise to the hare
+/// at each power of two, and needs fewer steps than [`floyd`].
+pub fn brent<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Start the hare `length` steps ahead; they then meet where the cycle begins.
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = (0..length).fold(start, |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, length }
+}
+
+/// The states of a sequence from the start up to the first repeat.
+#[derive(Debug, Clone)]
+pub struct Repeating<S> {
+    pub cycle: Cycle,
+    /// The states at steps `0..=prefix + length`. The last one repeats the one at `prefix`.
+    pub states: Vec<S>,
+}
+
+impl<S> Repeating<S> {
+    /// The state at step `n`
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.equivalent(n)]
+    }
+
+    /// Extends a value that grows by the same amount every time round the cycle to step `n`.
+    /// See [`Cycle::extrapolate`].
+    pub fn extrapolate(&self, n: usize, value: impl Fn(&S) -> i64) -> i64 {
+        self.cycle.extrapolate(n, |i| value(&self.states[i]))
+    }
+}
+
+/// Runs the simulation until a state repeats, keeping the states seen.
+pub fn find_repeat<S: Clone + Hash + Eq>(start: S, step: impl FnMut(&S) -> S) -> Repeating<S> {
+    find_repeat_by_key(start, step, S::clone)
+}
+
+/// Runs the simulation until a state's key repeats, keeping the states seen. Two states with
+/// the same key are taken to have the same future.
+pub fn find_repeat_by_key<S, K: Hash + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Repeating<S> {
+    let mut seen = HashMap::new();
+    let mut states = vec![start];
+    loop {
+        let n = states.len() - 1;
+        if let Some(prefix) = seen.insert(key(&states[n]), n) {
+            let cycle = Cycle {
+                prefix,
+                length: n - prefix,
+            };
+            return Repeating { cycle, states };
+        }
+        let next = step(&states[n]);
+        states.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 3, 4, ... with a prefix of 3 and a cycle of 4
+    fn step(n: &u32) -> u32 {
+        if *n == 6 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    const EXPECTED: Cycle = cycle(3, 4);
+
+    const fn cycle(prefix: usize, length: usize) -> Cycle {
+        Cycle { prefix, length }
+    }
+
+    #[test]
+    fn test_finders_agree() {
+        assert_eq!(floyd(0, step), EXPECTED);
+        assert_eq!(brent(0, step), EXPECTED);
+        assert_eq!(find_repeat(0, step).cycle, EXPECTED);
+        assert_eq!(floyd(4, step), cycle(0, 4));
+        assert_eq!(brent(5, step), cycle(0, 4));
+        assert_eq!(brent(7u32, |_| 7), cycle(0, 1));
+        assert_eq!(floyd(0u32, |n| (n + 1).min(9)), cycle(9, 1));
+    }
+
+    #[test]
+    fn test_state_at() {
+        let repeating = find_repeat(0, step);
+        assert_eq!(repeating.states, [0, 1, 2, 3, 4, 5, 6, 3]);
+        for n in 0..50 {
+            let expected = (0..n).fold(0, |s, _| step(&s));
+            assert_eq!(*repeating.nth(n), expected);
+            assert_eq!(EXPECTED.state_at(0, step, n), expected);
+        }
+        // 10^12 - 3 leaves 1 over after whole cycles of 4, one step past the cycle's start
+        assert_eq!(*repeating.nth(1_000_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // A position on the cycle and how far it has travelled in total. Only the position
+        // goes in the key.
+        let walk = |&(n, total): &(u32, i64)| (step(&n), total + 1 + n as i64);
+        let simulate = |steps: usize| (0..steps).fold((0, 0), |s, _| walk(&s)).1;
+
+        let repeating = find_repeat_by_key((0, 0), walk, |&(n, _)| n);
+        assert_eq!(repeating.cycle, EXPECTED);
+        for n in 0..60 {
+            assert_eq!(repeating.extrapolate(n, |s| s.1), simulate(n), "step {n}");
+            assert_eq!(EXPECTED.extrapolate(n, simulate), simulate(n));
+        }
+        let n = 1_000_000_000_000;
+        let per_cycle = simulate(7) - simulate(3);
+        let expected = simulate(EXPECTED.equivalent(n)) + (n as i64 - 3) / 4 * per_cycle;
+        assert_eq!(repeating.extrapolate(n, |s| s.1), expected);
+    }
+}
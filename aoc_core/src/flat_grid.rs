@@ -0,0 +1,454 @@
+//! An owned 2D grid with flat, row-major storage.
+//!
+//! Cells are addressed with a [`Point`] (`x` is the column, `y` the row) or a `(row, col)`
+//! tuple. It replaces the `Vec<Vec<T>>` grids from [`crate::grid`], `pathfinding`'s `Matrix`
+//! (feature `pathfinding`) and the `grid` crate's `Grid` (feature `grid`), and converts from
+//! all three.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::Point;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from row-major cells.
+    ///
+    /// # Panics
+    ///
+    /// If `cells` doesn't hold exactly `width * height` values.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid size mismatch");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Build a grid of `width` by `height` cells from a function of each position.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+            .map(&mut f)
+            .collect();
+        Self::from_vec(width, height, cells)
+    }
+
+    /// Parse one row per line, converting each character with `f`.
+    ///
+    /// # Panics
+    ///
+    /// If the lines have different lengths.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        input
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect::<Vec<Vec<T>>>()
+            .into()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.in_bounds(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    fn point(&self, offset: usize) -> Point {
+        Point::new(
+            (offset % self.width) as isize,
+            (offset / self.width) as isize,
+        )
+    }
+
+    /// The cell at `p`, or `None` if it's outside the grid.
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point(i))
+    }
+
+    /// All cells with their positions, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point(i), cell))
+    }
+
+    /// The cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Position of the first cell matching `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(predicate).map(|i| self.point(i))
+    }
+
+    /// Positions of all cells matching `predicate`.
+    pub fn find_all(&self, mut predicate: impl FnMut(&T) -> bool) -> Vec<Point> {
+        self.iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    /// The orthogonal neighbors of `p` that are inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().into_iter().filter(|&n| self.in_bounds(n))
+    }
+
+    /// The orthogonal and diagonal neighbors of `p` that are inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().into_iter().filter(|&n| self.in_bounds(n))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks(0) panics, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals running down and to the right, starting from the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (w, h) = (self.width as isize, self.height as isize);
+        (0..w + h - 1).map(move |d| {
+            let start = Point::new((d - h + 1).max(0), (h - 1 - d).max(0));
+            self.walk(start, Point::new(1, 1))
+        })
+    }
+
+    /// Diagonals running down and to the left, starting from the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (w, h) = (self.width as isize, self.height as isize);
+        (0..w + h - 1).map(move |d| {
+            let start = Point::new(d.min(w - 1), (d - w + 1).max(0));
+            self.walk(start, Point::new(-1, 1))
+        })
+    }
+
+    /// Cells from `start` in steps of `step` until leaving the grid.
+    pub fn walk(&self, start: Point, step: Point) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |p| Some(p.add(&step))).map_while(|p| self.get(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_vec(width, height, vec![value; width * height])
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let h = self.height as isize;
+        Self::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, h - 1 - p.x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn anticlockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let w = self.width as isize;
+        Self::from_fn(self.height, self.width, |p| {
+            self[Point::new(w - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let w = self.width as isize;
+        Self::from_fn(self.width, self.height, |p| {
+            self[Point::new(w - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let h = self.height as isize;
+        Self::from_fn(self.width, self.height, |p| {
+            self[Point::new(p.x, h - 1 - p.y)].clone()
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters, one row per line.
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p} is outside the grid"))
+    }
+}
+
+/// Index by `(row, col)`, matching the other grid types.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.width, "Column {col} out of bounds");
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.width, "Column {col} out of bounds");
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// # Panics
+///
+/// If the rows have different lengths.
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows have different lengths"
+        );
+        Self::from_vec(width, height, rows.into_iter().flatten().collect())
+    }
+}
+
+impl<T> From<Grid<T>> for Vec<Vec<T>> {
+    fn from(grid: Grid<T>) -> Self {
+        let mut cells = grid.cells.into_iter();
+        (0..grid.height)
+            .map(|_| cells.by_ref().take(grid.width).collect())
+            .collect()
+    }
+}
+
+#[cfg(feature = "pathfinding")]
+impl<T: Clone> From<&pathfinding::matrix::Matrix<T>> for Grid<T> {
+    fn from(matrix: &pathfinding::matrix::Matrix<T>) -> Self {
+        Self::from_vec(
+            matrix.columns,
+            matrix.rows,
+            matrix.values().cloned().collect(),
+        )
+    }
+}
+
+#[cfg(feature = "pathfinding")]
+impl<T: Clone> From<pathfinding::matrix::Matrix<T>> for Grid<T> {
+    fn from(matrix: pathfinding::matrix::Matrix<T>) -> Self {
+        Self::from(&matrix)
+    }
+}
+
+#[cfg(feature = "pathfinding")]
+impl<T: Clone> From<Grid<T>> for pathfinding::matrix::Matrix<T> {
+    fn from(grid: Grid<T>) -> Self {
+        Self::from_vec(grid.height, grid.width, grid.cells).unwrap()
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<T: Clone> From<&grid::Grid<T>> for Grid<T> {
+    fn from(other: &grid::Grid<T>) -> Self {
+        let cells = other.iter_rows().flatten().cloned().collect();
+        Self::from_vec(other.cols(), other.rows(), cells)
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<T: Clone> From<grid::Grid<T>> for Grid<T> {
+    fn from(other: grid::Grid<T>) -> Self {
+        if other.order() == grid::Order::RowMajor {
+            let (width, height) = (other.cols(), other.rows());
+            Self::from_vec(width, height, other.into_vec())
+        } else {
+            Self::from(&other)
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<T> From<Grid<T>> for grid::Grid<T> {
+    fn from(other: Grid<T>) -> Self {
+        grid::Grid::from_vec(other.cells, other.width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef")
+    }
+
+    fn collect<'a>(iters: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        iters.map(|it| it.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], 'c');
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&'e'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = sample();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+        assert_eq!(grid.find(|&c| c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn test_vec_conversions() {
+        let rows = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+        let grid = Grid::from(rows.clone());
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Point::new(1, 2)], 6);
+        assert_eq!(Vec::<Vec<i32>>::from(grid), rows);
+        assert!(Grid::<u8>::from(Vec::new()).is_empty());
+    }
+
+    #[cfg(feature = "pathfinding")]
+    #[test]
+    fn test_matrix_conversions() {
+        use pathfinding::matrix::Matrix;
+
+        let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let grid = Grid::from(&matrix);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(0, 1)], matrix[(1, 0)]);
+        assert_eq!(Matrix::from(grid), matrix);
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn test_grid_crate_conversions() {
+        let mut other = grid::grid![[1, 2, 3][4, 5, 6]];
+        let grid = Grid::from(&other);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid::Grid::from(grid.clone()), other);
+
+        other.transpose();
+        other.transpose();
+        assert_eq!(Grid::from(other), grid);
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn test_grid_crate_rows_pushed() {
+        // The way the 2023 days build theirs, a row at a time or from a flat Vec
+        let mut other = grid::Grid::new(0, 0);
+        other.push_row(vec!['#', '.', '.']);
+        other.push_row(vec!['.', '#', '.']);
+        assert_eq!(Grid::from(&other).to_string(), "#..\n.#.\n");
+        assert_eq!(
+            Grid::from(grid::Grid::from_vec("#...#.".chars().collect(), 3)),
+            Grid::from(other)
+        );
+    }
+}
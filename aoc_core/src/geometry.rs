@@ -0,0 +1,403 @@
+//! Polygons with integer vertices: areas, lattice point counts and containment.
+//!
+//! A [`Polygon`]'s area comes from the shoelace formula, and Pick's theorem turns that and the
+//! lattice points on its boundary into a count of the lattice points inside, which is how a
+//! loop through a grid's tile centres (2023 day 10) or a dug trench (2023 day 18) gets its
+//! size. Asking whether rectangles lie inside a rectilinear polygon (2025 day 09) is quicker
+//! on a [`CompressedRegion`], which squeezes huge coordinates down to the handful its vertices
+//! use. Regions made of whole grid cells are rectilinear polygons too, and
+//! [`region_corners`] counts their sides.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::Point;
+
+/// Where a point lies relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple polygon, its vertices listed in order either way round. Each vertex joins the
+/// next, and the last joins the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    /// Twice the area, which is always a whole number
+    pub fn doubled_area(&self) -> usize {
+        // Shoelace formula
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<isize>()
+            .unsigned_abs()
+    }
+
+    /// The area, rounded down
+    pub fn area(&self) -> usize {
+        self.doubled_area() / 2
+    }
+
+    /// The number of lattice points on the edges
+    pub fn boundary_points(&self) -> usize {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs()))
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside
+    pub fn interior_points(&self) -> usize {
+        // Pick's theorem: area = interior + boundary / 2 - 1
+        (self.doubled_area() + 2 - self.boundary_points()) / 2
+    }
+
+    /// The number of lattice points inside or on the boundary
+    pub fn lattice_points(&self) -> usize {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn locate(&self, point: Point) -> Location {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let cross = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+            let within = |p: isize, a: isize, b: isize| a.min(b) <= p && p <= a.max(b);
+            if cross == 0 && within(point.x, a.x, b.x) && within(point.y, a.y, b.y) {
+                return Location::Boundary;
+            }
+            // Count the edges crossing the ray running right from the point. The crossing is
+            // to the right when the point is on the left of an upward edge, or the right of a
+            // downward one.
+            if (a.y > point.y) != (b.y > point.y) && (cross > 0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// Whether the point is inside or on the boundary
+    pub fn contains(&self, point: Point) -> bool {
+        self.locate(point) != Location::Outside
+    }
+
+    /// The polygon's inside on a grid compressed to the coordinates of its vertices. Only
+    /// rectilinear polygons, whose edges are all horizontal or vertical, can be compressed.
+    pub fn compress(&self) -> Result<CompressedRegion, String> {
+        if let Some((a, b)) = self.edges().find(|(a, b)| a.x != b.x && a.y != b.y) {
+            return Err(format!("Edge from {} to {} is diagonal", a, b));
+        }
+        Ok(CompressedRegion::new(self))
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// A rectilinear polygon, inside and boundary, on a grid with a row and column for each
+/// coordinate its vertices use and one for each gap between them. The grid's size depends on
+/// the number of vertices rather than how far apart they are.
+#[derive(Debug, Clone)]
+pub struct CompressedRegion {
+    xs: Vec<isize>,
+    ys: Vec<isize>,
+    width: usize,
+    // Cells outside the region in the rectangle from (0, 0) up to each cell, exclusive, in
+    // rows of `width + 1`
+    outside_before: Vec<usize>,
+}
+
+impl CompressedRegion {
+    fn new(polygon: &Polygon) -> Self {
+        let axis = |coord: fn(&Point) -> isize| {
+            let mut values: Vec<_> = polygon.vertices.iter().map(coord).collect();
+            values.sort_unstable();
+            values.dedup();
+            values
+        };
+        let xs = axis(|p| p.x);
+        let ys = axis(|p| p.y);
+        let width = (2 * xs.len()).saturating_sub(1);
+        let height = (2 * ys.len()).saturating_sub(1);
+
+        // Draw the boundary on a grid with a border of empty cells, then fill in the outside
+        // from the border
+        let (padded_width, padded_height) = (width + 2, height + 2);
+        let mut outside = vec![false; padded_width * padded_height];
+        let mut boundary = vec![false; padded_width * padded_height];
+        let index = |values: &[isize], v: isize| 2 * values.binary_search(&v).unwrap() + 1;
+        for (a, b) in polygon.edges() {
+            let (x1, x2) = (index(&xs, a.x), index(&xs, b.x));
+            let (y1, y2) = (index(&ys, a.y), index(&ys, b.y));
+            for y in y1.min(y2)..=y1.max(y2) {
+                for x in x1.min(x2)..=x1.max(x2) {
+                    boundary[y * padded_width + x] = true;
+                }
+            }
+        }
+        let mut queue = VecDeque::from([0]);
+        outside[0] = true;
+        while let Some(i) = queue.pop_front() {
+            let (x, y) = (i % padded_width, i / padded_width);
+            let neighbors = [
+                (x > 0).then(|| i - 1),
+                (x + 1 < padded_width).then_some(i + 1),
+                (y > 0).then(|| i - padded_width),
+                (y + 1 < padded_height).then_some(i + padded_width),
+            ];
+            for j in neighbors.into_iter().flatten() {
+                if !outside[j] && !boundary[j] {
+                    outside[j] = true;
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        let mut outside_before = vec![0; (width + 1) * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                let cell = outside[(y + 1) * padded_width + x + 1] as usize;
+                outside_before[(y + 1) * (width + 1) + x + 1] = cell
+                    + outside_before[y * (width + 1) + x + 1]
+                    + outside_before[(y + 1) * (width + 1) + x]
+                    - outside_before[y * (width + 1) + x];
+            }
+        }
+
+        Self {
+            xs,
+            ys,
+            width,
+            outside_before,
+        }
+    }
+
+    /// The compressed row or column holding `v`, if it's within the bounds
+    fn compressed(values: &[isize], v: isize) -> Option<usize> {
+        match values.binary_search(&v) {
+            Ok(i) => Some(2 * i),
+            Err(i) if i > 0 && i < values.len() => Some(2 * i - 1),
+            Err(_) => None,
+        }
+    }
+
+    /// Whether the point is inside or on the boundary
+    pub fn contains(&self, point: Point) -> bool {
+        self.contains_rectangle(point, point)
+    }
+
+    /// Whether the rectangle with opposite corners `a` and `b` lies entirely inside the region
+    /// or on its boundary
+    pub fn contains_rectangle(&self, a: Point, b: Point) -> bool {
+        let corners = (
+            Self::compressed(&self.xs, a.x.min(b.x)),
+            Self::compressed(&self.xs, a.x.max(b.x)),
+            Self::compressed(&self.ys, a.y.min(b.y)),
+            Self::compressed(&self.ys, a.y.max(b.y)),
+        );
+        let (Some(x1), Some(x2), Some(y1), Some(y2)) = corners else {
+            return false;
+        };
+        let at = |x: usize, y: usize| self.outside_before[y * (self.width + 1) + x];
+        at(x2 + 1, y2 + 1) + at(x1, y1) - at(x1, y2 + 1) - at(x2 + 1, y1) == 0
+    }
+}
+
+/// The number of corners of a region made of whole grid cells, which is also its number of
+/// sides, counting those round any holes
+pub fn region_corners(cells: &HashSet<Point>) -> usize {
+    // Every corner is at a grid point where the four cells round it aren't two halves, so
+    // look at the points at the corners of each cell
+    let points: HashSet<_> = cells
+        .iter()
+        .flat_map(|cell| {
+            [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(dx, dy)| Point::new(cell.x + dx, cell.y + dy))
+        })
+        .collect();
+    points
+        .into_iter()
+        .map(|point| {
+            let [up_left, up_right, down_left, down_right] = [(-1, -1), (0, -1), (-1, 0), (0, 0)]
+                .map(|(dx, dy)| cells.contains(&Point::new(point.x + dx, point.y + dy)));
+            match [up_left, up_right, down_left, down_right]
+                .iter()
+                .filter(|&&c| c)
+                .count()
+            {
+                1 | 3 => 1,
+                // Cells touching only diagonally make two corners
+                2 if up_left == down_right => 2,
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+/// The length of the boundary of a region made of whole grid cells, counting that round any
+/// holes
+pub fn region_perimeter(cells: &HashSet<Point>) -> usize {
+    cells
+        .iter()
+        .flat_map(|cell| cell.neighbors4())
+        .filter(|neighbor| !cells.contains(neighbor))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(isize, isize)]) -> Polygon {
+        Polygon::new(vertices.iter().map(|&(x, y)| Point::new(x, y)).collect())
+    }
+
+    /// An L shape: a 6×6 square with the 3×3 top right quarter cut out
+    fn l_shape() -> Polygon {
+        polygon(&[(0, 0), (3, 0), (3, 3), (6, 3), (6, 6), (0, 6)])
+    }
+
+    fn cells(grid: &str) -> HashSet<Point> {
+        grid.lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.trim()
+                    .chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| Point::new(x as isize, y as isize))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_areas() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.area(), 16);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.lattice_points(), 25);
+
+        let l_shape = l_shape();
+        assert_eq!(l_shape.area(), 27);
+        assert_eq!(l_shape.boundary_points(), 24);
+        assert_eq!(l_shape.lattice_points(), 49 - 9);
+
+        // Running the other way round makes no difference
+        let mut reversed = l_shape.vertices().to_vec();
+        reversed.reverse();
+        assert_eq!(Polygon::new(reversed).doubled_area(), 54);
+
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 3)]);
+        assert_eq!(triangle.doubled_area(), 12);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[test]
+    fn test_locate() {
+        let l_shape = l_shape();
+        assert_eq!(l_shape.locate(Point::new(1, 1)), Location::Inside);
+        assert_eq!(l_shape.locate(Point::new(5, 5)), Location::Inside);
+        assert_eq!(l_shape.locate(Point::new(3, 1)), Location::Boundary);
+        assert_eq!(l_shape.locate(Point::new(6, 3)), Location::Boundary);
+        assert_eq!(l_shape.locate(Point::new(4, 1)), Location::Outside);
+        assert_eq!(l_shape.locate(Point::new(-1, 3)), Location::Outside);
+        // Level with a vertex
+        assert_eq!(l_shape.locate(Point::new(-1, 0)), Location::Outside);
+        assert_eq!(l_shape.locate(Point::new(1, 3)), Location::Inside);
+
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(triangle.locate(Point::new(2, 2)), Location::Boundary);
+        assert_eq!(triangle.locate(Point::new(1, 2)), Location::Inside);
+        assert_eq!(triangle.locate(Point::new(3, 2)), Location::Outside);
+    }
+
+    #[test]
+    fn test_compressed_region() {
+        let l_shape = l_shape();
+        let region = l_shape.compress().unwrap();
+        for x in -2..8 {
+            for y in -2..8 {
+                let point = Point::new(x, y);
+                assert_eq!(region.contains(point), l_shape.contains(point), "{point}");
+            }
+        }
+        let rectangle = |a: (isize, isize), b: (isize, isize)| {
+            region.contains_rectangle(Point::new(a.0, a.1), Point::new(b.0, b.1))
+        };
+        assert!(rectangle((0, 0), (3, 6)));
+        assert!(rectangle((6, 6), (0, 3)));
+        assert!(rectangle((3, 3), (3, 0)));
+        assert!(!rectangle((0, 0), (6, 6)));
+        assert!(!rectangle((0, 0), (4, 1)));
+        // Corners on the boundary, but spanning the cut-out quarter
+        assert!(!rectangle((3, 0), (6, 3)));
+
+        // A U shape: the gap between the arms is outside, though no edge crosses a rectangle
+        // joining the tips of the arms
+        let u_shape = polygon(&[
+            (0, 0),
+            (1_000_000, 0),
+            (1_000_000, 9_000_000),
+            (800_000, 9_000_000),
+            (800_000, 1_000_000),
+            (200_000, 1_000_000),
+            (200_000, 9_000_000),
+            (0, 9_000_000),
+        ]);
+        let region = u_shape.compress().unwrap();
+        assert!(region.contains_rectangle(Point::new(0, 0), Point::new(1_000_000, 1_000_000)));
+        assert!(
+            !region.contains_rectangle(Point::new(0, 9_000_000), Point::new(1_000_000, 1_000_000))
+        );
+        assert!(region.contains_rectangle(Point::new(800_000, 9_000_000), Point::new(1_000_000, 0)));
+
+        assert!(polygon(&[(0, 0), (2, 0), (0, 2)]).compress().is_err());
+    }
+
+    #[test]
+    fn test_regions() {
+        let region = cells(
+            "####
+             #..#
+             ##.#
+             ####",
+        );
+        assert_eq!(region_corners(&region), 4 + 6);
+        assert_eq!(region_perimeter(&region), 16 + 8);
+
+        // Touching only at a corner
+        let region = cells(
+            "#.
+             .#",
+        );
+        assert_eq!(region_corners(&region), 8);
+        assert_eq!(region_perimeter(&region), 8);
+    }
+}
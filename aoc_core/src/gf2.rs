@@ -0,0 +1,414 @@
+//! Linear algebra over GF(2), the field of bits where adding is XOR.
+//!
+//! Toggle puzzles are linear systems over GF(2): pressing a button flips a fixed set of lights,
+//! pressing it twice undoes it, and the order of presses doesn't matter. So which buttons to
+//! press is a [`BitVec`] `x` with `A x = b`, where column `j` of the [`BitMatrix`] `A` is the set
+//! of lights button `j` flips and `b` is the lights to turn on. [`BitMatrix::solve`] finds every
+//! such `x` by Gaussian elimination, and [`Solutions::min_weight`] the one with fewest presses.
+//!
+//! Vectors are packed 64 bits to a word and can be any length.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::{BitXor, BitXorAssign};
+
+const WORD: usize = u64::BITS as usize;
+
+/// A fixed-length vector of bits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitVec {
+    /// `len` zero bits.
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            len,
+            words: vec![0; len.div_ceil(WORD)],
+        }
+    }
+
+    /// `len` bits, of which those at `ones` are set. Panics if an index is out of range.
+    pub fn from_indices(len: usize, ones: impl IntoIterator<Item = usize>) -> Self {
+        let mut v = Self::zeros(len);
+        for i in ones {
+            v.set(i, true);
+        }
+        v
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {i} out of range for length {}", self.len);
+        self.words[i / WORD] >> (i % WORD) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "bit {i} out of range for length {}", self.len);
+        let mask = 1 << (i % WORD);
+        if value {
+            self.words[i / WORD] |= mask;
+        } else {
+            self.words[i / WORD] &= !mask;
+        }
+    }
+
+    pub fn flip(&mut self, i: usize) {
+        assert!(i < self.len, "bit {i} out of range for length {}", self.len);
+        self.words[i / WORD] ^= 1 << (i % WORD);
+    }
+
+    /// The number of set bits, or the vector's weight.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// The indices of the set bits, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    i * WORD + bit
+                })
+            })
+        })
+    }
+
+    /// The parity of the bits set in both vectors.
+    pub fn dot(&self, other: &BitVec) -> bool {
+        assert_eq!(self.len, other.len, "vectors differ in length");
+        let ones: u32 = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones())
+            .sum();
+        ones % 2 == 1
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        let bits: Vec<bool> = bits.into_iter().collect();
+        Self::from_indices(bits.len(), (0..bits.len()).filter(|&i| bits[i]))
+    }
+}
+
+impl BitXorAssign<&BitVec> for BitVec {
+    fn bitxor_assign(&mut self, other: &BitVec) {
+        assert_eq!(self.len, other.len, "vectors differ in length");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a ^= b;
+        }
+    }
+}
+
+impl BitXor for &BitVec {
+    type Output = BitVec;
+
+    fn bitxor(self, other: &BitVec) -> BitVec {
+        let mut sum = self.clone();
+        sum ^= other;
+        sum
+    }
+}
+
+/// Bit 0 first, as `0` and `1`.
+impl Display for BitVec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (0..self.len).try_for_each(|i| write!(f, "{}", self.get(i) as u8))
+    }
+}
+
+/// A matrix of bits, held as a [`BitVec`] per row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMatrix {
+    cols: usize,
+    rows: Vec<BitVec>,
+}
+
+impl BitMatrix {
+    /// A matrix with the given rows. Panics if a row isn't `cols` long.
+    pub fn new(cols: usize, rows: Vec<BitVec>) -> Self {
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows differ in length"
+        );
+        Self { cols, rows }
+    }
+
+    /// A matrix with the given columns. Panics if a column isn't `rows` long.
+    pub fn from_columns(rows: usize, columns: &[BitVec]) -> Self {
+        assert!(
+            columns.iter().all(|column| column.len() == rows),
+            "columns differ in length"
+        );
+        let rows = (0..rows)
+            .map(|row| columns.iter().map(|column| column.get(row)).collect())
+            .collect();
+        Self::new(columns.len(), rows)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &BitVec {
+        &self.rows[row]
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        self.rows[row].get(col)
+    }
+
+    /// The product `A x`.
+    pub fn mul_vec(&self, x: &BitVec) -> BitVec {
+        self.rows.iter().map(|row| row.dot(x)).collect()
+    }
+
+    /// The reduced row echelon form, found by XOR-ing rows together, with the column of each
+    /// row's leading one.
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        let mut rows = self.rows.clone();
+        let mut pivots = Vec::new();
+        for col in 0..self.cols {
+            let rank = pivots.len();
+            let Some(pivot) = (rank..rows.len()).find(|&r| rows[r].get(col)) else {
+                continue;
+            };
+            rows.swap(rank, pivot);
+            let pivot_row = rows[rank].clone();
+            for (r, row) in rows.iter_mut().enumerate() {
+                if r != rank && row.get(col) {
+                    *row ^= &pivot_row;
+                }
+            }
+            pivots.push(col);
+        }
+        (Self::new(self.cols, rows), pivots)
+    }
+
+    /// The number of linearly independent rows (or columns).
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// A basis for the vectors `x` with `A x = 0`, one vector per free column.
+    pub fn nullspace(&self) -> Vec<BitVec> {
+        let (m, pivots) = self.rref();
+        Self::nullspace_of_rref(&m, &pivots, self.cols)
+    }
+
+    /// The nullspace basis read off a reduced matrix, of which only the first `cols` columns
+    /// belong to `A`.
+    fn nullspace_of_rref(m: &Self, pivots: &[usize], cols: usize) -> Vec<BitVec> {
+        (0..cols)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut x = BitVec::zeros(cols);
+                x.set(free, true);
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    x.set(pivot, m.get(row, free));
+                }
+                x
+            })
+            .collect()
+    }
+
+    /// Every solution of `A x = rhs`, or `None` if there are none.
+    pub fn solve(&self, rhs: &BitVec) -> Option<Solutions> {
+        assert_eq!(rhs.len(), self.rows(), "one bit per row");
+        let augmented = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(r, row)| {
+                (0..self.cols)
+                    .map(|c| row.get(c))
+                    .chain([rhs.get(r)])
+                    .collect()
+            })
+            .collect();
+        let (m, pivots) = Self::new(self.cols + 1, augmented).rref();
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+
+        // Clearing every free variable leaves each pivot variable equal to its row's right-hand
+        // side
+        let particular = BitVec::from_indices(
+            self.cols,
+            pivots
+                .iter()
+                .enumerate()
+                .filter(|&(row, _)| m.get(row, self.cols))
+                .map(|(_, &pivot)| pivot),
+        );
+        Some(Solutions {
+            particular,
+            nullspace: Self::nullspace_of_rref(&m, &pivots, self.cols),
+        })
+    }
+}
+
+/// Every solution of `A x = b`: `particular` XOR any combination of the `nullspace` vectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solutions {
+    pub particular: BitVec,
+    pub nullspace: Vec<BitVec>,
+}
+
+impl Solutions {
+    /// Every solution, each differing from the last by one nullspace vector (a Gray code).
+    /// There are `2^k` of them for a `k`-dimensional nullspace, so `k` must be below 64.
+    pub fn iter(&self) -> impl Iterator<Item = BitVec> + '_ {
+        let k = self.nullspace.len();
+        assert!(k < 64, "too many solutions to enumerate");
+        let mut x = self.particular.clone();
+        (0..1u64 << k).map(move |i| {
+            if i > 0 {
+                x ^= &self.nullspace[i.trailing_zeros() as usize];
+            }
+            x.clone()
+        })
+    }
+
+    /// A solution with the fewest set bits, found by trying all `2^k` of them.
+    pub fn min_weight(&self) -> BitVec {
+        let k = self.nullspace.len();
+        assert!(k < 64, "too many solutions to search");
+        let mut x = self.particular.clone();
+        let mut best = (x.count_ones(), 0);
+        for i in 1..1u64 << k {
+            x ^= &self.nullspace[i.trailing_zeros() as usize];
+            let weight = x.count_ones();
+            if weight < best.0 {
+                best = (weight, i);
+            }
+        }
+
+        // Rebuild the best from its position in the Gray code
+        let gray = best.1 ^ (best.1 >> 1);
+        let mut x = self.particular.clone();
+        for (bit, v) in self.nullspace.iter().enumerate() {
+            if gray >> bit & 1 == 1 {
+                x ^= v;
+            }
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> BitVec {
+        s.chars().map(|c| c == '1').collect()
+    }
+
+    /// Pressing a light on a `size` × `size` board flips it and its orthogonal neighbours
+    fn lights_out(size: usize) -> BitMatrix {
+        let buttons: Vec<BitVec> = (0..size * size)
+            .map(|i| {
+                let (row, col) = (i / size, i % size);
+                let mut flips = vec![i];
+                if row > 0 {
+                    flips.push(i - size);
+                }
+                if row + 1 < size {
+                    flips.push(i + size);
+                }
+                if col > 0 {
+                    flips.push(i - 1);
+                }
+                if col + 1 < size {
+                    flips.push(i + 1);
+                }
+                BitVec::from_indices(size * size, flips)
+            })
+            .collect();
+        BitMatrix::from_columns(size * size, &buttons)
+    }
+
+    #[test]
+    fn test_bit_vec() {
+        let mut v = BitVec::from_indices(130, [0, 63, 64, 129]);
+        assert_eq!(v.count_ones(), 4);
+        assert_eq!(v.ones().collect::<Vec<_>>(), [0, 63, 64, 129]);
+        v.flip(64);
+        v.set(100, true);
+        assert_eq!(v.ones().collect::<Vec<_>>(), [0, 63, 100, 129]);
+
+        let w = BitVec::from_indices(130, [63, 100, 101]);
+        assert_eq!((&v ^ &w).ones().collect::<Vec<_>>(), [0, 101, 129]);
+        assert!(!v.dot(&w));
+        assert!(v.dot(&BitVec::from_indices(130, [129])));
+        assert_eq!(bits("0110").to_string(), "0110");
+    }
+
+    #[test]
+    fn test_rref() {
+        let m = BitMatrix::new(4, vec![bits("0110"), bits("1100"), bits("1010")]);
+        let (reduced, pivots) = m.rref();
+        assert_eq!(pivots, [0, 1]);
+        assert_eq!(
+            reduced,
+            BitMatrix::new(4, vec![bits("1010"), bits("0110"), bits("0000")])
+        );
+        assert_eq!(m.rank(), 2);
+
+        let nullspace = m.nullspace();
+        assert_eq!(nullspace, [bits("1110"), bits("0001")]);
+        assert!(nullspace.iter().all(|v| m.mul_vec(v).is_zero()));
+    }
+
+    #[test]
+    fn test_solve() {
+        let m = BitMatrix::new(4, vec![bits("0110"), bits("1100"), bits("1010")]);
+        assert_eq!(m.solve(&bits("111")), None);
+
+        let solutions = m.solve(&bits("101")).unwrap();
+        let all: Vec<BitVec> = solutions.iter().collect();
+        assert_eq!(all.len(), 4);
+        assert!(all.iter().all(|x| m.mul_vec(x) == bits("101")));
+        assert_eq!(solutions.min_weight(), bits("0010"));
+    }
+
+    #[test]
+    fn test_lights_out() {
+        // The classic 5 × 5 board has a two-dimensional nullspace, and turning every light on
+        // takes 15 presses at best
+        let board = lights_out(5);
+        assert_eq!(board.rank(), 23);
+        let solutions = board.solve(&BitVec::from_indices(25, 0..25)).unwrap();
+        assert_eq!(solutions.nullspace.len(), 2);
+        assert_eq!(solutions.min_weight().count_ones(), 15);
+
+        // A 10 × 10 board needs more than one word per row and is always solvable
+        let board = lights_out(10);
+        assert_eq!(board.rank(), 100);
+        let centre = BitVec::from_indices(100, [55]);
+        let x = board.solve(&centre).unwrap().min_weight();
+        assert_eq!(board.mul_vec(&x), centre);
+    }
+}
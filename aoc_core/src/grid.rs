@@ -0,0 +1,768 @@
+//! Grid helper functions for `Vec<Vec<T>>` grids.
+
+/// Grid type alias
+pub type Grid<T> = Vec<Vec<T>>;
+
+/// Get grid dimensions (rows, cols)
+pub fn dimensions<T>(grid: &[Vec<T>]) -> (usize, usize) {
+    let rows = grid.len();
+    let cols = if rows > 0 { grid[0].len() } else { 0 };
+    (rows, cols)
+}
+
+/// Check if a position is within grid bounds
+pub fn in_bounds<T>(grid: &[Vec<T>], row: isize, col: isize) -> bool {
+    row >= 0 && col >= 0 && (row as usize) < grid.len() && (col as usize) < grid[0].len()
+}
+
+/// Get value at position if in bounds
+pub fn get<T>(grid: &[Vec<T>], row: isize, col: isize) -> Option<&T> {
+    if in_bounds(grid, row, col) {
+        Some(&grid[row as usize][col as usize])
+    } else {
+        None
+    }
+}
+
+/// Get 4-directional neighbors (up, down, left, right)
+pub fn neighbors4(row: isize, col: isize) -> [(isize, isize); 4] {
+    [
+        (row - 1, col), // up
+        (row + 1, col), // down
+        (row, col - 1), // left
+        (row, col + 1), // right
+    ]
+}
+
+/// Get 8-directional neighbors (including diagonals)
+pub fn neighbors8(row: isize, col: isize) -> [(isize, isize); 8] {
+    [
+        (row - 1, col - 1), // up-left
+        (row - 1, col),     // up
+        (row - 1, col + 1), // up-right
+        (row, col - 1),     // left
+        (row, col + 1),     // right
+        (row + 1, col - 1), // down-left
+        (row + 1, col),     // down
+        (row + 1, col + 1), // down-right
+    ]
+}
+
+/// Get valid 4-directional neighbors within grid bounds
+pub fn valid_neighbors4<T>(grid: &[Vec<T>], row: isize, col: isize) -> Vec<(usize, usize)> {
+    neighbors4(row, col)
+        .iter()
+        .filter(|(r, c)| in_bounds(grid, *r, *c))
+        .map(|(r, c)| (*r as usize, *c as usize))
+        .collect()
+}
+
+/// Get valid 8-directional neighbors within grid bounds
+pub fn valid_neighbors8<T>(grid: &[Vec<T>], row: isize, col: isize) -> Vec<(usize, usize)> {
+    neighbors8(row, col)
+        .iter()
+        .filter(|(r, c)| in_bounds(grid, *r, *c))
+        .map(|(r, c)| (*r as usize, *c as usize))
+        .collect()
+}
+
+/// Iterate over all positions in the grid
+pub fn positions<T>(grid: &[Vec<T>]) -> impl Iterator<Item = (usize, usize)> {
+    let (rows, cols) = dimensions(grid);
+    (0..rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+}
+
+/// Find all positions where predicate is true
+pub fn find_all<T, F>(grid: &[Vec<T>], predicate: F) -> Vec<(usize, usize)>
+where
+    F: Fn(&T) -> bool,
+{
+    let (rows, cols) = dimensions(grid);
+    (0..rows)
+        .flat_map(|r| (0..cols).map(move |c| (r, c)))
+        .filter(|(r, c)| predicate(&grid[*r][*c]))
+        .collect()
+}
+
+/// Find first position where predicate is true
+pub fn find<T, F>(grid: &[Vec<T>], predicate: F) -> Option<(usize, usize)>
+where
+    F: Fn(&T) -> bool,
+{
+    for (r, row) in grid.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            if predicate(cell) {
+                return Some((r, c));
+            }
+        }
+    }
+    None
+}
+
+/// Run Dijkstra's algorithm on a grid with custom cost function
+/// Returns a map of positions to their minimum cost from the start
+pub fn dijkstra<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    cost_fn: F,
+) -> std::collections::HashMap<(usize, usize), usize>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap};
+
+    let mut dist: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start, 0);
+    heap.push((Reverse(0), start));
+
+    while let Some((Reverse(cost), pos)) = heap.pop() {
+        let (row, col) = pos;
+
+        if dist.get(&pos).is_some_and(|&d| cost > d) {
+            continue;
+        }
+
+        for (next_row, next_col) in valid_neighbors4(grid, row as isize, col as isize) {
+            let current_cell = &grid[row][col];
+            let next_cell = &grid[next_row][next_col];
+
+            if let Some(edge_cost) = cost_fn(current_cell, next_cell) {
+                let next_cost = cost + edge_cost;
+                let next_pos = (next_row, next_col);
+
+                if next_cost < *dist.get(&next_pos).unwrap_or(&usize::MAX) {
+                    dist.insert(next_pos, next_cost);
+                    heap.push((Reverse(next_cost), next_pos));
+                }
+            }
+        }
+    }
+
+    dist
+}
+
+/// Run Dijkstra's algorithm and find shortest path to target
+/// Returns (cost, path) if a path exists
+pub fn dijkstra_path<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: (usize, usize),
+    cost_fn: F,
+) -> Option<(usize, Vec<(usize, usize)>)>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap};
+
+    let mut dist: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut prev: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start, 0);
+    heap.push((Reverse(0), start));
+
+    while let Some((Reverse(cost), pos)) = heap.pop() {
+        if pos == target {
+            // Reconstruct path
+            let mut path = vec![target];
+            let mut current = target;
+            while current != start {
+                current = *prev.get(&current)?;
+                path.push(current);
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        if dist.get(&pos).is_some_and(|&d| cost > d) {
+            continue;
+        }
+
+        let (row, col) = pos;
+
+        for (next_row, next_col) in valid_neighbors4(grid, row as isize, col as isize) {
+            let current_cell = &grid[row][col];
+            let next_cell = &grid[next_row][next_col];
+
+            if let Some(edge_cost) = cost_fn(current_cell, next_cell) {
+                let next_cost = cost + edge_cost;
+                let next_pos = (next_row, next_col);
+
+                if next_cost < *dist.get(&next_pos).unwrap_or(&usize::MAX) {
+                    dist.insert(next_pos, next_cost);
+                    prev.insert(next_pos, pos);
+                    heap.push((Reverse(next_cost), next_pos));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Distances from a grid search, stored densely at `row * cols + col`
+///
+/// Supports the same lookups as the `HashMap` returned by [`dijkstra`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances {
+    cols: usize,
+    dist: Vec<usize>,
+}
+
+impl Distances {
+    fn new<T>(grid: &[Vec<T>]) -> Self {
+        let (rows, cols) = dimensions(grid);
+        Self {
+            cols,
+            dist: vec![usize::MAX; rows * cols],
+        }
+    }
+
+    /// Distance to a position, if it was reached
+    pub fn get(&self, &(row, col): &(usize, usize)) -> Option<&usize> {
+        if col >= self.cols {
+            return None;
+        }
+        self.dist
+            .get(row * self.cols + col)
+            .filter(|&&d| d != usize::MAX)
+    }
+
+    pub fn contains_key(&self, pos: &(usize, usize)) -> bool {
+        self.get(pos).is_some()
+    }
+
+    /// Number of reached positions
+    pub fn len(&self) -> usize {
+        self.dist.iter().filter(|&&d| d != usize::MAX).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reached positions and their distances
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), usize)> + '_ {
+        self.dist
+            .iter()
+            .enumerate()
+            .filter(|(_, &d)| d != usize::MAX)
+            .map(|(i, &d)| ((i / self.cols, i % self.cols), d))
+    }
+}
+
+/// Run Dijkstra's algorithm with a binary heap over flat distance storage
+/// Same as [`dijkstra`] without the hashing
+pub fn dijkstra_dense<T, F>(grid: &[Vec<T>], start: (usize, usize), cost_fn: F) -> Distances
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    dijkstra_dense_impl(grid, start, None, cost_fn).0
+}
+
+/// Run Dijkstra's algorithm over flat storage and find shortest path to target
+/// Same as [`dijkstra_path`] without the hashing
+pub fn dijkstra_dense_path<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: (usize, usize),
+    cost_fn: F,
+) -> Option<(usize, Vec<(usize, usize)>)>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    let (dist, prev) = dijkstra_dense_impl(grid, start, Some(target), cost_fn);
+    trace_path(&dist, &prev, start, target)
+}
+
+/// Follow `prev` links back from `target` to `start`
+fn trace_path(
+    dist: &Distances,
+    prev: &[usize],
+    start: (usize, usize),
+    target: (usize, usize),
+) -> Option<(usize, Vec<(usize, usize)>)> {
+    let cost = *dist.get(&target)?;
+    let cols = dist.cols;
+    let mut path = vec![target];
+    let mut current = target.0 * cols + target.1;
+    let start = start.0 * cols + start.1;
+    while current != start {
+        current = prev[current];
+        path.push((current / cols, current % cols));
+    }
+    path.reverse();
+    Some((cost, path))
+}
+
+fn dijkstra_dense_impl<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: Option<(usize, usize)>,
+    cost_fn: F,
+) -> (Distances, Vec<usize>)
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let mut dist = Distances::new(grid);
+    let cols = dist.cols;
+    let mut prev = vec![usize::MAX; dist.dist.len()];
+    let mut heap = BinaryHeap::new();
+
+    let start = start.0 * cols + start.1;
+    let target = target.map(|(row, col)| row * cols + col);
+    dist.dist[start] = 0;
+    heap.push(Reverse((0, start)));
+
+    while let Some(Reverse((cost, pos))) = heap.pop() {
+        if Some(pos) == target {
+            break;
+        }
+        if cost > dist.dist[pos] {
+            continue;
+        }
+
+        let (row, col) = (pos / cols, pos % cols);
+        for (next_row, next_col) in valid_neighbors4(grid, row as isize, col as isize) {
+            if let Some(edge_cost) = cost_fn(&grid[row][col], &grid[next_row][next_col]) {
+                let next_cost = cost + edge_cost;
+                let next = next_row * cols + next_col;
+                if next_cost < dist.dist[next] {
+                    dist.dist[next] = next_cost;
+                    prev[next] = pos;
+                    heap.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+    }
+
+    (dist, prev)
+}
+
+/// Monotone priority queue for integer keys
+///
+/// Keys popped never decrease, so entries are bucketed by the highest bit in which they differ
+/// from the last popped key. Pushing and popping are O(1) amortised, which beats a binary heap
+/// when edge weights are small integers.
+#[derive(Debug, Clone)]
+pub struct RadixHeap<V> {
+    last: usize,
+    len: usize,
+    buckets: Vec<Vec<(usize, V)>>,
+}
+
+impl<V> Default for RadixHeap<V> {
+    fn default() -> Self {
+        Self {
+            last: 0,
+            len: 0,
+            buckets: (0..=usize::BITS).map(|_| Vec::new()).collect(),
+        }
+    }
+}
+
+impl<V> RadixHeap<V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn bucket(&self, key: usize) -> usize {
+        (usize::BITS - (key ^ self.last).leading_zeros()) as usize
+    }
+
+    /// Add an entry. Panics if `key` is smaller than the last popped key.
+    pub fn push(&mut self, key: usize, value: V) {
+        assert!(key >= self.last, "RadixHeap keys must not decrease");
+        let bucket = self.bucket(key);
+        self.buckets[bucket].push((key, value));
+        self.len += 1;
+    }
+
+    /// Remove an entry with the smallest key.
+    pub fn pop(&mut self) -> Option<(usize, V)> {
+        if self.buckets[0].is_empty() {
+            let i = self.buckets.iter().position(|b| !b.is_empty())?;
+            let entries = std::mem::take(&mut self.buckets[i]);
+            self.last = entries.iter().map(|&(key, _)| key).min().unwrap();
+            for (key, value) in entries {
+                let bucket = self.bucket(key);
+                self.buckets[bucket].push((key, value));
+            }
+        }
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+}
+
+/// Run Dijkstra's algorithm with a radix heap over flat distance storage
+/// Best suited to small integer edge costs
+pub fn dijkstra_radix<T, F>(grid: &[Vec<T>], start: (usize, usize), cost_fn: F) -> Distances
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    dijkstra_radix_impl(grid, start, None, cost_fn).0
+}
+
+/// Run Dijkstra's algorithm with a radix heap and find shortest path to target
+/// Same as [`dijkstra_path`] with small integer edge costs
+pub fn dijkstra_radix_path<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: (usize, usize),
+    cost_fn: F,
+) -> Option<(usize, Vec<(usize, usize)>)>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    let (dist, prev) = dijkstra_radix_impl(grid, start, Some(target), cost_fn);
+    trace_path(&dist, &prev, start, target)
+}
+
+fn dijkstra_radix_impl<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: Option<(usize, usize)>,
+    cost_fn: F,
+) -> (Distances, Vec<usize>)
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    let mut dist = Distances::new(grid);
+    let cols = dist.cols;
+    let mut prev = vec![usize::MAX; dist.dist.len()];
+    let mut heap = RadixHeap::new();
+
+    let start = start.0 * cols + start.1;
+    let target = target.map(|(row, col)| row * cols + col);
+    dist.dist[start] = 0;
+    heap.push(0, start);
+
+    while let Some((cost, pos)) = heap.pop() {
+        if Some(pos) == target {
+            break;
+        }
+        if cost > dist.dist[pos] {
+            continue;
+        }
+
+        let (row, col) = (pos / cols, pos % cols);
+        for (next_row, next_col) in valid_neighbors4(grid, row as isize, col as isize) {
+            if let Some(edge_cost) = cost_fn(&grid[row][col], &grid[next_row][next_col]) {
+                let next_cost = cost + edge_cost;
+                let next = next_row * cols + next_col;
+                if next_cost < dist.dist[next] {
+                    dist.dist[next] = next_cost;
+                    prev[next] = pos;
+                    heap.push(next_cost, next);
+                }
+            }
+        }
+    }
+
+    (dist, prev)
+}
+
+/// Shortest distances when every edge costs 0 or 1, using a deque instead of a heap
+/// Panics if `cost_fn` returns any other cost
+pub fn bfs_01<T, F>(grid: &[Vec<T>], start: (usize, usize), cost_fn: F) -> Distances
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    bfs_01_impl(grid, start, None, cost_fn).0
+}
+
+/// Shortest path to target when every edge costs 0 or 1
+/// Same as [`dijkstra_path`], and panics like [`bfs_01`]
+pub fn bfs_01_path<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: (usize, usize),
+    cost_fn: F,
+) -> Option<(usize, Vec<(usize, usize)>)>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    let (dist, prev) = bfs_01_impl(grid, start, Some(target), cost_fn);
+    trace_path(&dist, &prev, start, target)
+}
+
+fn bfs_01_impl<T, F>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: Option<(usize, usize)>,
+    cost_fn: F,
+) -> (Distances, Vec<usize>)
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    use std::collections::VecDeque;
+
+    let mut dist = Distances::new(grid);
+    let cols = dist.cols;
+    let mut prev = vec![usize::MAX; dist.dist.len()];
+    let mut queue = VecDeque::new();
+
+    let start = start.0 * cols + start.1;
+    let target = target.map(|(row, col)| row * cols + col);
+    dist.dist[start] = 0;
+    queue.push_back((0, start));
+
+    while let Some((cost, pos)) = queue.pop_front() {
+        if Some(pos) == target {
+            break;
+        }
+        if cost > dist.dist[pos] {
+            continue;
+        }
+
+        let (row, col) = (pos / cols, pos % cols);
+        for (next_row, next_col) in valid_neighbors4(grid, row as isize, col as isize) {
+            let Some(edge_cost) = cost_fn(&grid[row][col], &grid[next_row][next_col]) else {
+                continue;
+            };
+            assert!(edge_cost <= 1, "bfs_01 edge costs must be 0 or 1");
+            let next_cost = cost + edge_cost;
+            let next = next_row * cols + next_col;
+            if next_cost < dist.dist[next] {
+                dist.dist[next] = next_cost;
+                prev[next] = pos;
+                if edge_cost == 0 {
+                    queue.push_front((next_cost, next));
+                } else {
+                    queue.push_back((next_cost, next));
+                }
+            }
+        }
+    }
+
+    (dist, prev)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+
+    #[test]
+    fn test_grid_dimensions() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(dimensions(&grid), (2, 3));
+    }
+
+    #[test]
+    fn test_grid_in_bounds() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert!(in_bounds(&grid, 0, 0));
+        assert!(in_bounds(&grid, 1, 2));
+        assert!(!in_bounds(&grid, 2, 0));
+        assert!(!in_bounds(&grid, 0, 3));
+        assert!(!in_bounds(&grid, -1, 0));
+    }
+
+    #[test]
+    fn test_grid_get() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(get(&grid, 0, 0), Some(&1));
+        assert_eq!(get(&grid, 1, 2), Some(&6));
+        assert_eq!(get(&grid, 2, 0), None);
+        assert_eq!(get(&grid, -1, 0), None);
+    }
+
+    #[test]
+    fn test_grid_neighbors4() {
+        let neighbors = neighbors4(1, 1);
+        assert_eq!(neighbors, [(0, 1), (2, 1), (1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn test_grid_valid_neighbors4() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let neighbors = valid_neighbors4(&grid, 0, 0);
+        assert_eq!(neighbors, vec![(1, 0), (0, 1)]);
+
+        let neighbors = valid_neighbors4(&grid, 1, 1);
+        assert_eq!(neighbors, vec![(0, 1), (1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn test_grid_find() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(find(&grid, |&x| x == 5), Some((1, 1)));
+        assert_eq!(find(&grid, |&x| x == 7), None);
+    }
+
+    #[test]
+    fn test_grid_find_all() {
+        let grid = vec![vec![1, 2, 3], vec![2, 4, 2]];
+        let positions = find_all(&grid, |&x| x == 2);
+        assert_eq!(positions, vec![(0, 1), (1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Simple 3x3 grid with uniform cost of 1
+        let grid = vec![vec![1, 1, 1], vec![1, 1, 1], vec![1, 1, 1]];
+
+        let start = (0, 0);
+        let distances = dijkstra(&grid, start, |_, _| Some(1));
+
+        // Check distances from top-left corner
+        assert_eq!(distances.get(&(0, 0)), Some(&0));
+        assert_eq!(distances.get(&(0, 1)), Some(&1));
+        assert_eq!(distances.get(&(1, 0)), Some(&1));
+        assert_eq!(distances.get(&(2, 2)), Some(&4));
+    }
+
+    #[test]
+    fn test_dijkstra_with_walls() {
+        // Grid where 0 is passable (cost 1) and 9 is a wall
+        // Path from (0,0) to (2,2): (0,0) -> (0,1) -> (1,1) -> (2,1) -> (2,2)
+        let grid = vec![vec![0, 0, 9], vec![9, 0, 9], vec![0, 0, 0]];
+
+        let start = (0, 0);
+        let distances = dijkstra(&grid, start, |_, next| {
+            if *next == 9 {
+                None // Wall - no path
+            } else {
+                Some(1) // Passable - cost 1
+            }
+        });
+
+        assert_eq!(distances.get(&(0, 0)), Some(&0));
+        assert_eq!(distances.get(&(0, 1)), Some(&1));
+        assert_eq!(distances.get(&(2, 2)), Some(&4)); // Correct distance is 4
+        assert_eq!(distances.get(&(0, 2)), None); // Wall is unreachable
+    }
+
+    #[test]
+    fn test_dijkstra_path() {
+        let grid = vec![vec![1, 1, 1], vec![1, 1, 1], vec![1, 1, 1]];
+
+        let start = (0, 0);
+        let target = (2, 2);
+        let result = dijkstra_path(&grid, start, target, |_, _| Some(1));
+
+        assert!(result.is_some());
+        let (cost, path) = result.unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(path.len(), 5);
+        assert_eq!(path[0], start);
+        assert_eq!(path[path.len() - 1], target);
+    }
+
+    #[test]
+    fn test_dijkstra_path_no_path() {
+        // Grid completely blocked
+        let grid = vec![vec![0, 9, 0], vec![9, 9, 9], vec![0, 9, 0]];
+
+        let start = (0, 0);
+        let target = (2, 2);
+        let result = dijkstra_path(&grid, start, target, |_, next| {
+            if *next == 9 {
+                None
+            } else {
+                Some(1)
+            }
+        });
+
+        assert!(result.is_none());
+    }
+
+    fn variable_cost_grid() -> Vec<Vec<usize>> {
+        (0..12)
+            .map(|i| (0..9).map(|j| (i * 7 + j * 3) % 9 + 1).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_dense_variants_match_dijkstra() {
+        let grid = variable_cost_grid();
+        let cost = |_: &usize, next: &usize| (*next != 9).then_some(*next);
+        let expected = dijkstra(&grid, (0, 0), cost);
+
+        for dist in [
+            dijkstra_dense(&grid, (0, 0), cost),
+            dijkstra_radix(&grid, (0, 0), cost),
+        ] {
+            assert_eq!(dist.len(), expected.len());
+            for (pos, d) in dist.iter() {
+                assert_eq!(expected.get(&pos), Some(&d));
+            }
+        }
+        assert_eq!(dijkstra_dense(&grid, (0, 0), cost).get(&(0, 9)), None);
+    }
+
+    #[test]
+    fn test_dense_path_variants() {
+        let grid = variable_cost_grid();
+        let cost = |_: &usize, next: &usize| Some(*next);
+        let (expected_cost, _) = dijkstra_path(&grid, (0, 0), (11, 8), cost).unwrap();
+        for (cost_found, path) in [
+            dijkstra_dense_path(&grid, (0, 0), (11, 8), cost).unwrap(),
+            dijkstra_radix_path(&grid, (0, 0), (11, 8), cost).unwrap(),
+        ] {
+            assert_eq!(cost_found, expected_cost);
+            assert_eq!(path[0], (0, 0));
+            assert_eq!(*path.last().unwrap(), (11, 8));
+            let total: usize = path[1..].iter().map(|&(r, c)| grid[r][c]).sum();
+            assert_eq!(total, cost_found);
+        }
+
+        let walled = vec![vec![0, 9, 0], vec![9, 9, 9], vec![0, 9, 0]];
+        let blocked = |_: &i32, next: &i32| (*next != 9).then_some(1);
+        assert!(dijkstra_dense_path(&walled, (0, 0), (2, 2), blocked).is_none());
+        assert!(dijkstra_radix_path(&walled, (0, 0), (2, 2), blocked).is_none());
+        assert!(bfs_01_path(&walled, (0, 0), (2, 2), blocked).is_none());
+    }
+
+    #[test]
+    fn test_bfs_01() {
+        // Moving onto '.' is free, onto '#' costs one
+        let grid = parse_grid("..#.\n##.#\n..#.");
+        let cost = |_: &char, next: &char| Some(usize::from(*next == '#'));
+        let expected = dijkstra(&grid, (0, 0), cost);
+        let dist = bfs_01(&grid, (0, 0), cost);
+        assert_eq!(dist.len(), expected.len());
+        for (pos, d) in dist.iter() {
+            assert_eq!(expected.get(&pos), Some(&d));
+        }
+        assert_eq!(dist.get(&(2, 3)), Some(&2));
+
+        let (cost_found, path) = bfs_01_path(&grid, (0, 0), (2, 3), cost).unwrap();
+        assert_eq!(cost_found, 2);
+        assert_eq!((path[0], *path.last().unwrap()), ((0, 0), (2, 3)));
+        let walls = path[1..]
+            .iter()
+            .filter(|&&(r, c)| grid[r][c] == '#')
+            .count();
+        assert_eq!(walls, cost_found);
+    }
+
+    #[test]
+    fn test_radix_heap() {
+        let mut heap = RadixHeap::new();
+        for key in [5, 3, 9, 3, 0, 17] {
+            heap.push(key, key * 10);
+        }
+        assert_eq!(heap.pop(), Some((0, 0)));
+        heap.push(4, 40);
+        let keys: Vec<_> = std::iter::from_fn(|| heap.pop()).map(|(k, _)| k).collect();
+        assert_eq!(keys, vec![3, 3, 4, 5, 9, 17]);
+        assert!(heap.is_empty());
+    }
+}
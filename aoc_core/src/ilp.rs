@@ -0,0 +1,402 @@
+//! Integer linear programming: minimising a linear cost over whole, non-negative numbers that
+//! satisfy a set of linear equations.
+//!
+//! [`IlpSolver`] is implemented by [`BranchAndBound`], written in plain Rust on top of
+//! [`linalg`](crate::linalg), and, with the `good_lp` feature, by `GoodLp`, which hands the
+//! problem to the HiGHS C++ solver.
+//!
+//! [`BranchAndBound`] is aimed at the small, nearly square systems puzzles produce. Gaussian
+//! elimination leaves each pivot variable as a function of the free variables, so only the free
+//! variables need searching; it enumerates them within bounds taken from the constraints,
+//! pruning branches that can't beat the best solution found or can't keep every pivot variable
+//! non-negative.
+
+use crate::linalg::{Matrix, Rational};
+use num_integer::Integer;
+use std::fmt::{self, Display, Formatter};
+
+/// Minimise `objective · x` subject to every constraint `a · x = b`, with each `x` a whole number
+/// no less than zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    objective: Vec<i64>,
+    constraints: Vec<(Vec<i64>, i64)>,
+}
+
+impl Problem {
+    /// A problem over `objective.len()` variables, with no constraints yet.
+    pub fn new(objective: Vec<i64>) -> Self {
+        Self {
+            objective,
+            constraints: Vec::new(),
+        }
+    }
+
+    /// This problem with the constraint `coefficients · x = rhs` added. Panics if there isn't one
+    /// coefficient per variable.
+    pub fn with_constraint(mut self, coefficients: Vec<i64>, rhs: i64) -> Self {
+        assert_eq!(
+            coefficients.len(),
+            self.variables(),
+            "one coefficient per variable"
+        );
+        self.constraints.push((coefficients, rhs));
+        self
+    }
+
+    pub fn variables(&self) -> usize {
+        self.objective.len()
+    }
+
+    pub fn objective(&self) -> &[i64] {
+        &self.objective
+    }
+
+    pub fn constraints(&self) -> impl Iterator<Item = (&[i64], i64)> {
+        self.constraints
+            .iter()
+            .map(|(coefficients, rhs)| (coefficients.as_slice(), *rhs))
+    }
+
+    /// The cost of `x`.
+    pub fn cost(&self, x: &[i64]) -> i64 {
+        dot(&self.objective, x)
+    }
+
+    /// Whether `x` is non-negative and meets every constraint.
+    pub fn is_feasible(&self, x: &[i64]) -> bool {
+        x.len() == self.variables()
+            && x.iter().all(|&v| v >= 0)
+            && self.constraints().all(|(a, b)| dot(a, x) == b)
+    }
+
+    /// The largest value `variable` can take, from a constraint with no negative coefficients
+    /// that it appears in.
+    pub fn upper_bound(&self, variable: usize) -> Option<i64> {
+        self.constraints()
+            .filter(|(a, _)| a[variable] > 0 && a.iter().all(|&c| c >= 0))
+            .map(|(a, b)| b.div_euclid(a[variable]))
+            .min()
+    }
+}
+
+fn dot(a: &[i64], x: &[i64]) -> i64 {
+    a.iter().zip(x).map(|(a, x)| a * x).sum()
+}
+
+/// Why a solver couldn't answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IlpError {
+    /// Nothing bounds how large the variable can get.
+    Unbounded { variable: usize },
+    /// The working values grew too large for an `i128`.
+    Overflow,
+    /// The backend failed for its own reasons.
+    Solver(String),
+}
+
+impl Display for IlpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IlpError::Unbounded { variable } => write!(f, "variable {variable} is unbounded"),
+            IlpError::Overflow => write!(f, "arithmetic overflow"),
+            IlpError::Solver(message) => write!(f, "solver failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for IlpError {}
+
+/// Something that can solve integer linear programs.
+pub trait IlpSolver {
+    /// A cheapest solution to `problem`, or `None` if there are no solutions. When several
+    /// solutions tie, any of them may be returned.
+    fn minimise(&self, problem: &Problem) -> Result<Option<Vec<i64>>, IlpError>;
+}
+
+/// Branch and bound over the free variables left by Gaussian elimination. Every free variable
+/// needs an [`upper_bound`](Problem::upper_bound).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BranchAndBound;
+
+/// A row of the reduced system, scaled to whole numbers:
+/// `scale * x[pivot] = rhs - coefficients · free`.
+struct PivotRow {
+    pivot: usize,
+    scale: i128,
+    rhs: i128,
+    coefficients: Vec<i128>,
+}
+
+impl PivotRow {
+    fn new(m: &Matrix<i128>, row: usize, pivot: usize, free: &[usize]) -> Result<Self, IlpError> {
+        let rhs_col = m.cols() - 1;
+        let entries = || free.iter().chain([&rhs_col]).map(|&col| &m[(row, col)]);
+        let scale = entries().fold(1, |scale, entry| scale.lcm(entry.denom()));
+        let scaled = |entry: &Rational<i128>| {
+            entry
+                .numer()
+                .checked_mul(scale / entry.denom())
+                .ok_or(IlpError::Overflow)
+        };
+        Ok(Self {
+            pivot,
+            scale,
+            rhs: scaled(&m[(row, rhs_col)])?,
+            coefficients: free
+                .iter()
+                .map(|&col| scaled(&m[(row, col)]))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+struct Search<'a> {
+    rows: &'a [PivotRow],
+    free: &'a [usize],
+    bounds: &'a [i64],
+    /// What each free variable adds to the cost, scaled by `cost_scale`
+    reduced_costs: Vec<i128>,
+    /// The most each row's right-hand side can still grow by, from free variables `k..`
+    headroom: Vec<Vec<i128>>,
+    /// The most the cost can still fall by, from free variables `k..`
+    savings: Vec<i128>,
+    values: Vec<i64>,
+    best: Option<(i128, Vec<i64>)>,
+}
+
+impl Search<'_> {
+    /// Try every value of free variable `k` onwards, given what's left of each row's right-hand
+    /// side and the cost so far.
+    fn run(&mut self, k: usize, rhs: &[i128], cost: i128) {
+        if let Some((best, _)) = &self.best {
+            if cost - self.savings[k] >= *best {
+                return;
+            }
+        }
+        if rhs
+            .iter()
+            .zip(&self.headroom)
+            .any(|(&rhs, headroom)| rhs + headroom[k] < 0)
+        {
+            return;
+        }
+
+        if k == self.free.len() {
+            self.settle(rhs, cost);
+            return;
+        }
+
+        let mut rhs = rhs.to_vec();
+        for value in 0..=self.bounds[k] {
+            self.values[self.free[k]] = value;
+            let cost = cost + self.reduced_costs[k] * value as i128;
+            self.run(k + 1, &rhs, cost);
+            for (rhs, row) in rhs.iter_mut().zip(self.rows) {
+                *rhs -= row.coefficients[k];
+            }
+        }
+    }
+
+    /// With every free variable chosen, fill in the pivot variables if they're whole and
+    /// non-negative.
+    fn settle(&mut self, rhs: &[i128], cost: i128) {
+        for (&rhs, row) in rhs.iter().zip(self.rows) {
+            if rhs < 0 || rhs % row.scale != 0 {
+                return;
+            }
+            self.values[row.pivot] = (rhs / row.scale) as i64;
+        }
+        self.best = Some((cost, self.values.clone()));
+    }
+}
+
+impl IlpSolver for BranchAndBound {
+    fn minimise(&self, problem: &Problem) -> Result<Option<Vec<i64>>, IlpError> {
+        let n = problem.variables();
+        if problem.constraints.is_empty() {
+            // With nothing to satisfy, all zeros is cheapest unless some cost is negative
+            return match problem.objective.iter().position(|&c| c < 0) {
+                Some(variable) => Err(IlpError::Unbounded { variable }),
+                None => Ok(Some(vec![0; n])),
+            };
+        }
+        let augmented = problem
+            .constraints()
+            .map(|(a, b)| a.iter().chain([&b]).map(|&c| c as i128).collect())
+            .collect();
+        let (m, pivots) = Matrix::new(augmented)
+            .rref()
+            .map_err(|_| IlpError::Overflow)?;
+        if pivots.last() == Some(&n) {
+            // The equations contradict each other
+            return Ok(None);
+        }
+
+        let free: Vec<usize> = (0..n).filter(|v| !pivots.contains(v)).collect();
+        let bounds = free
+            .iter()
+            .map(|&variable| {
+                problem
+                    .upper_bound(variable)
+                    .ok_or(IlpError::Unbounded { variable })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let rows = pivots
+            .iter()
+            .enumerate()
+            .map(|(row, &pivot)| PivotRow::new(&m, row, pivot, &free))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Substituting the pivot rows into the objective leaves a constant plus a reduced cost
+        // per free variable, all multiplied by `cost_scale` to stay whole
+        let cost_scale = rows.iter().fold(1, |scale, row| scale.lcm(&row.scale));
+        let weight =
+            |row: &PivotRow| problem.objective[row.pivot] as i128 * (cost_scale / row.scale);
+        let constant: i128 = rows.iter().map(|row| weight(row) * row.rhs).sum();
+        let reduced_costs: Vec<i128> = free
+            .iter()
+            .enumerate()
+            .map(|(k, &variable)| {
+                problem.objective[variable] as i128 * cost_scale
+                    - rows
+                        .iter()
+                        .map(|row| weight(row) * row.coefficients[k])
+                        .sum::<i128>()
+            })
+            .collect();
+
+        let suffix_sums = |term: &dyn Fn(usize) -> i128| {
+            let mut sums = vec![0; free.len() + 1];
+            for k in (0..free.len()).rev() {
+                sums[k] = sums[k + 1] + term(k);
+            }
+            sums
+        };
+        let headroom = rows
+            .iter()
+            .map(|row| suffix_sums(&|k| (-row.coefficients[k]).max(0) * bounds[k] as i128))
+            .collect();
+        let savings = suffix_sums(&|k| (-reduced_costs[k]).max(0) * bounds[k] as i128);
+
+        let mut search = Search {
+            rows: &rows,
+            free: &free,
+            bounds: &bounds,
+            reduced_costs,
+            headroom,
+            savings,
+            values: vec![0; n],
+            best: None,
+        };
+        let rhs: Vec<i128> = rows.iter().map(|row| row.rhs).collect();
+        search.run(0, &rhs, constant);
+        Ok(search.best.map(|(_, x)| x))
+    }
+}
+
+/// The HiGHS solver, through `good_lp`. Building it needs a C++ toolchain.
+#[cfg(feature = "good_lp")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GoodLp;
+
+#[cfg(feature = "good_lp")]
+impl IlpSolver for GoodLp {
+    fn minimise(&self, problem: &Problem) -> Result<Option<Vec<i64>>, IlpError> {
+        use good_lp::*;
+
+        let mut vars = ProblemVariables::new();
+        let x: Vec<Variable> = (0..problem.variables())
+            .map(|_| vars.add(variable().min(0).integer()))
+            .collect();
+        let linear = |coefficients: &[i64]| -> Expression {
+            coefficients
+                .iter()
+                .zip(&x)
+                .map(|(&c, &v)| c as f64 * v)
+                .sum()
+        };
+
+        let mut model = highs(vars.minimise(linear(problem.objective())));
+        for (coefficients, rhs) in problem.constraints() {
+            model.add_constraint(linear(coefficients).eq(rhs as f64));
+        }
+        match model.solve() {
+            Ok(solution) => Ok(Some(
+                x.iter()
+                    .map(|&v| solution.value(v).round() as i64)
+                    .collect(),
+            )),
+            Err(ResolutionError::Infeasible) => Ok(None),
+            Err(error) => Err(IlpError::Solver(error.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upper_bound() {
+        let problem = Problem::new(vec![1, 1, 1])
+            .with_constraint(vec![1, 2, 0], 7)
+            .with_constraint(vec![1, -1, 1], 2);
+        assert_eq!(problem.upper_bound(0), Some(7));
+        assert_eq!(problem.upper_bound(1), Some(3));
+        assert_eq!(problem.upper_bound(2), None);
+    }
+
+    #[test]
+    fn test_branch_and_bound() {
+        // Buttons (3) (1,3) (2) (2,3) (0,2) (0,1) and counters {3,5,4,7}
+        let buttons: [&[usize]; 6] = [&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]];
+        let problem = [3, 5, 4, 7].into_iter().enumerate().fold(
+            Problem::new(vec![1; 6]),
+            |problem, (counter, target)| {
+                let coefficients = buttons
+                    .iter()
+                    .map(|wires| wires.contains(&counter) as i64)
+                    .collect();
+                problem.with_constraint(coefficients, target)
+            },
+        );
+        let x = BranchAndBound.minimise(&problem).unwrap().unwrap();
+        assert!(problem.is_feasible(&x));
+        assert_eq!(problem.cost(&x), 10);
+    }
+
+    #[test]
+    fn test_fractional_pivots() {
+        // 2x + 2y = 6 and 2y + 3z = 7 reduce to fractions, but only z = 1 leaves 7 - 3z even
+        // and non-negative, so (1, 2, 1) is the one whole solution
+        let problem = Problem::new(vec![1, 1, 1])
+            .with_constraint(vec![2, 2, 0], 6)
+            .with_constraint(vec![0, 2, 3], 7);
+        assert_eq!(BranchAndBound.minimise(&problem), Ok(Some(vec![1, 2, 1])));
+    }
+
+    #[test]
+    fn test_negative_costs() {
+        // x + y = 4 with y costing less than nothing, so all of it goes to y
+        let problem = Problem::new(vec![1, -1]).with_constraint(vec![1, 1], 4);
+        assert_eq!(BranchAndBound.minimise(&problem), Ok(Some(vec![0, 4])));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let contradictory = Problem::new(vec![1, 1])
+            .with_constraint(vec![1, 1], 3)
+            .with_constraint(vec![2, 2], 5);
+        assert_eq!(BranchAndBound.minimise(&contradictory), Ok(None));
+
+        let fractional = Problem::new(vec![1, 1]).with_constraint(vec![2, 4], 3);
+        assert_eq!(BranchAndBound.minimise(&fractional), Ok(None));
+
+        let negative = Problem::new(vec![1, 1]).with_constraint(vec![1, -1], -3);
+        assert_eq!(
+            BranchAndBound.minimise(&negative),
+            Err(IlpError::Unbounded { variable: 1 })
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::grid::Grid;
+use crate::inputs::InputError;
+
+fn read_file(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|source| InputError::Io { path, source })
+}
+
+/// Read test input file for a given day
+pub fn try_read_test_input(day: u8) -> Result<String, InputError> {
+    read_file(format!("inputs/day{:02}_test.txt", day).into())
+}
+
+/// Read 2nd test input file for a given day
+pub fn try_read_test_input_2(day: u8) -> Result<String, InputError> {
+    read_file(format!("inputs/day{:02}_test2.txt", day).into())
+}
+
+/// Read test input file for a given day, panicking if it can't be read
+pub fn read_test_input(day: u8) -> String {
+    try_read_test_input(day).unwrap_or_else(|e| panic!("Failed to read test input file: {e}"))
+}
+
+/// Read 2nd test input file for a given day, panicking if it can't be read
+pub fn read_test_input_2(day: u8) -> String {
+    try_read_test_input_2(day).unwrap_or_else(|e| panic!("Failed to read test input file: {e}"))
+}
+
+/// Parse lines into a vector of type T
+pub fn parse_lines<T>(input: &str) -> Vec<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Debug,
+{
+    input
+        .lines()
+        .map(|line| line.parse().expect("Failed to parse line"))
+        .collect()
+}
+
+/// Parse a grid of characters
+pub fn parse_grid(input: &str) -> Grid<char> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Parse a grid of digits
+pub fn parse_digit_grid(input: &str) -> Grid<u32> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).expect("Invalid digit"))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_test_input() {
+        let err = try_read_test_input(0).unwrap_err();
+        assert!(matches!(err, InputError::Io { .. }));
+        assert!(err.to_string().starts_with("inputs/day00_test.txt: "));
+        assert!(try_read_test_input_2(0).is_err());
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1\n2\n3";
+        let result: Vec<i32> = parse_lines(input);
+        assert_eq!(result, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let input = "abc\ndef";
+        let result = parse_grid(input);
+        assert_eq!(result, vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+    }
+}
@@ -0,0 +1,489 @@
+//! Loading puzzle inputs.
+//!
+//! An [`InputSource`] knows how to produce the input for a year and day. The sources here can
+//! be combined: [`RepoInputs`] reads the files where each year keeps them, [`LocalMirror`]
+//! reads a shared directory of inputs, [`Cached`] stores whatever another source returns in a
+//! directory that is ignored by git, and [`HttpFetcher`] (feature `fetch`) downloads inputs
+//! from the Advent of Code site or a local stand-in server.
+//!
+//! [`load_input`] tries them in that order.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::runner::Year;
+
+/// Environment variable naming a local mirror of inputs (see [`LocalMirror`]).
+pub const MIRROR_VAR: &str = "AOC_INPUT_MIRROR";
+/// Environment variable holding the session token, overriding the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable naming the file that holds the session token.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+/// Why an input couldn't be loaded.
+#[derive(Debug)]
+pub enum InputError {
+    /// The source has no input for this day.
+    NotFound { year: u16, day: u8 },
+    /// Reading or writing an input file failed.
+    Io { path: PathBuf, source: io::Error },
+    /// Fetching needs a session token and none was configured.
+    MissingSession,
+    /// The server answered with an error status.
+    Http { status: u16, message: String },
+    /// The server couldn't be reached.
+    Network(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { year, day } => write!(f, "No input for {year} day {day}"),
+            InputError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            InputError::MissingSession => write!(
+                f,
+                "No session token: set {SESSION_VAR} or save it in {}",
+                session_file().display()
+            ),
+            InputError::Http { status, message } => write!(f, "HTTP {status}: {message}"),
+            InputError::Network(message) => write!(f, "Network error: {message}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A place puzzle inputs can be loaded from.
+pub trait InputSource {
+    /// Load the input for a day. Returns [`InputError::NotFound`] if this source doesn't
+    /// have it, so that the next source can be tried.
+    fn load(&self, year: u16, day: u8) -> Result<String, InputError>;
+}
+
+impl<S: InputSource + ?Sized> InputSource for Box<S> {
+    fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        (**self).load(year, day)
+    }
+}
+
+/// Read a file, mapping a missing file to [`InputError::NotFound`].
+fn read_file(path: &Path, year: u16, day: u8) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound { year, day },
+        _ => InputError::Io {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+/// Inputs stored in the repository, wherever each year's layout expects them:
+/// `inputs/dayNN.txt` for the single package years, `dayNN/input.txt` for the workspaces.
+#[derive(Debug, Clone)]
+pub struct RepoInputs {
+    root: PathBuf,
+}
+
+impl RepoInputs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Path of a day's input under this repository.
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        Year::open(&self.root, year).map(|y| y.input_path(day))
+    }
+}
+
+impl InputSource for RepoInputs {
+    fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self
+            .path(year, day)
+            .ok_or(InputError::NotFound { year, day })?;
+        read_file(&path, year, day)
+    }
+}
+
+/// A directory of inputs laid out the same way for every year: `<dir>/<year>/dayNN.txt`.
+#[derive(Debug, Clone)]
+pub struct LocalMirror {
+    dir: PathBuf,
+}
+
+impl LocalMirror {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Store an input in the mirror.
+    pub fn store(&self, year: u16, day: u8, input: &str) -> Result<(), InputError> {
+        let path = self.path(year, day);
+        let io_err = |source| InputError::Io {
+            path: path.clone(),
+            source,
+        };
+        fs::create_dir_all(path.parent().unwrap()).map_err(io_err)?;
+        fs::write(&path, input).map_err(io_err)
+    }
+}
+
+impl InputSource for LocalMirror {
+    fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        read_file(&self.path(year, day), year, day)
+    }
+}
+
+/// Keeps a copy of everything `inner` returns, so each input is only fetched once.
+///
+/// Inputs are personal and shouldn't be published, so the cache directory must not be
+/// committed; [`default_cache_dir`] is covered by the repository's `.gitignore`.
+#[derive(Debug, Clone)]
+pub struct Cached<S> {
+    cache: LocalMirror,
+    inner: S,
+}
+
+impl<S: InputSource> Cached<S> {
+    pub fn new(dir: impl Into<PathBuf>, inner: S) -> Self {
+        Self {
+            cache: LocalMirror::new(dir),
+            inner,
+        }
+    }
+}
+
+impl<S: InputSource> InputSource for Cached<S> {
+    fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self.cache.load(year, day) {
+            Err(InputError::NotFound { .. }) => {
+                let input = self.inner.load(year, day)?;
+                self.cache.store(year, day, &input)?;
+                Ok(input)
+            }
+            result => result,
+        }
+    }
+}
+
+/// Tries each source in turn until one has the input.
+#[derive(Default)]
+pub struct Fallback {
+    sources: Vec<Box<dyn InputSource>>,
+}
+
+impl Fallback {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, source: impl InputSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+}
+
+impl InputSource for Fallback {
+    fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        for source in &self.sources {
+            match source.load(year, day) {
+                Err(InputError::NotFound { .. }) => continue,
+                result => return result,
+            }
+        }
+        Err(InputError::NotFound { year, day })
+    }
+}
+
+/// Downloads inputs over HTTP using the session cookie from adventofcode.com.
+#[cfg(feature = "fetch")]
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+#[cfg(feature = "fetch")]
+impl HttpFetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            base_url: Self::BASE_URL.to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// Use the token from `AOC_SESSION` or the session file (see [`session_file`]).
+    pub fn from_env() -> Result<Self, InputError> {
+        if let Ok(session) = std::env::var(SESSION_VAR) {
+            return Ok(Self::new(session.trim()));
+        }
+        Self::from_session_file(&session_file())
+    }
+
+    pub fn from_session_file(path: &Path) -> Result<Self, InputError> {
+        match fs::read_to_string(path) {
+            Ok(session) if !session.trim().is_empty() => Ok(Self::new(session.trim())),
+            Ok(_) => Err(InputError::MissingSession),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::MissingSession),
+            Err(source) => Err(InputError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    /// Point the fetcher at another server, e.g. a local stand-in for tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl InputSource for HttpFetcher {
+    fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        const USER_AGENT: &str = "github.com/stevedoyle/advent-of-code (aoc_core)";
+
+        let response = ureq::get(&self.url(year, day))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| InputError::Network(e.to_string())),
+            Err(ureq::Error::Status(404, _)) => Err(InputError::NotFound { year, day }),
+            Err(ureq::Error::Status(status, response)) => Err(InputError::Http {
+                status,
+                message: response
+                    .into_string()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+            }),
+            Err(ureq::Error::Transport(e)) => Err(InputError::Network(e.to_string())),
+        }
+    }
+}
+
+/// The session token file: `AOC_SESSION_FILE`, or `~/.config/aoc/session`.
+pub fn session_file() -> PathBuf {
+    if let Ok(path) = std::env::var(SESSION_FILE_VAR) {
+        return PathBuf::from(path);
+    }
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    Path::new(&home).join(".config").join("aoc").join("session")
+}
+
+/// Root of the repository that contains this crate.
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Where fetched inputs are kept.
+pub fn default_cache_dir(root: &Path) -> PathBuf {
+    root.join(".cache").join("inputs")
+}
+
+/// The usual chain of sources: the repository's own input files, then the mirror named by
+/// `AOC_INPUT_MIRROR`, then (with the `fetch` feature) a cached download.
+pub fn default_source() -> Fallback {
+    default_source_in(&repo_root())
+}
+
+/// [`default_source`] for the repository at `root`.
+pub fn default_source_in(root: &Path) -> Fallback {
+    let mut sources = Fallback::new().with(RepoInputs::new(root));
+    if let Ok(dir) = std::env::var(MIRROR_VAR) {
+        sources = sources.with(LocalMirror::new(dir));
+    }
+    #[cfg(feature = "fetch")]
+    if let Ok(fetcher) = HttpFetcher::from_env() {
+        sources = sources.with(Cached::new(default_cache_dir(root), fetcher));
+    }
+    sources
+}
+
+/// Load the input for a day from the default sources.
+pub fn load_input(year: u16, day: u8) -> Result<String, InputError> {
+    default_source().load(year, day)
+}
+
+/// Entry point for a day binary's input: [`load_input`], exiting with the reason if it fails.
+pub fn puzzle_input(year: u16, day: u8) -> String {
+    load_input(year, day).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::scratch_dir;
+    use std::cell::Cell;
+
+    /// Counts how often it's asked for an input.
+    struct Counting {
+        calls: Cell<usize>,
+    }
+
+    impl InputSource for Counting {
+        fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("{year}-{day}"))
+        }
+    }
+
+    #[test]
+    fn test_repo_inputs_follow_year_layout() {
+        let root = scratch_dir("repo_inputs");
+        fs::create_dir_all(root.join("2020/inputs")).unwrap();
+        fs::write(root.join("2020/Cargo.toml"), "[package]\n").unwrap();
+        fs::write(root.join("2020/inputs/day03.txt"), "package").unwrap();
+        fs::create_dir_all(root.join("2021/day03")).unwrap();
+        fs::write(root.join("2021/Cargo.toml"), "[workspace]\n").unwrap();
+        fs::write(root.join("2021/day03/input.txt"), "workspace").unwrap();
+
+        let repo = RepoInputs::new(&root);
+        assert_eq!(repo.load(2020, 3).unwrap(), "package");
+        assert_eq!(repo.load(2021, 3).unwrap(), "workspace");
+        assert!(matches!(
+            repo.load(2021, 4),
+            Err(InputError::NotFound { year: 2021, day: 4 })
+        ));
+        assert!(matches!(
+            repo.load(2019, 1),
+            Err(InputError::NotFound { .. })
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_cached_fetches_once() {
+        let dir = scratch_dir("cached");
+        let cached = Cached::new(
+            &dir,
+            Counting {
+                calls: Cell::new(0),
+            },
+        );
+        assert_eq!(cached.load(2024, 7).unwrap(), "2024-7");
+        assert_eq!(cached.load(2024, 7).unwrap(), "2024-7");
+        assert_eq!(cached.inner.calls.get(), 1);
+        assert_eq!(
+            fs::read_to_string(dir.join("2024/day07.txt")).unwrap(),
+            "2024-7"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fallback_tries_sources_in_order() {
+        let dir = scratch_dir("fallback");
+        let mirror = LocalMirror::new(&dir);
+        mirror.store(2023, 1, "mirrored").unwrap();
+
+        let sources = Fallback::new()
+            .with(LocalMirror::new(dir.join("empty")))
+            .with(mirror)
+            .with(Counting {
+                calls: Cell::new(0),
+            });
+        assert_eq!(sources.load(2023, 1).unwrap(), "mirrored");
+        assert_eq!(sources.load(2023, 2).unwrap(), "2023-2");
+        assert!(matches!(
+            Fallback::new().load(2023, 1),
+            Err(InputError::NotFound { .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_session_file() {
+        let dir = scratch_dir("session");
+        let path = dir.join("session");
+        assert!(matches!(
+            HttpFetcher::from_session_file(&path),
+            Err(InputError::MissingSession)
+        ));
+        fs::write(&path, "abc123\n").unwrap();
+        let fetcher = HttpFetcher::from_session_file(&path).unwrap();
+        assert_eq!(fetcher.session, "abc123");
+        assert_eq!(
+            fetcher.url(2022, 5),
+            "https://adventofcode.com/2022/day/5/input"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_http_fetcher_with_local_server() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut lines = BufReader::new(&stream).lines().map(|l| l.unwrap());
+                let request: Vec<String> = lines.by_ref().take_while(|l| !l.is_empty()).collect();
+                let (status, body) = if request[0].starts_with("GET /2022/day/5/input ") {
+                    ("200 OK", "1\n2\n3\n")
+                } else {
+                    ("404 Not Found", "Not found")
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+
+        let fetcher = HttpFetcher::new("abc123").with_base_url(format!("http://{addr}/"));
+        assert_eq!(fetcher.load(2022, 5).unwrap(), "1\n2\n3\n");
+        assert!(matches!(
+            fetcher.load(2022, 6),
+            Err(InputError::NotFound { year: 2022, day: 6 })
+        ));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].iter().any(|h| h == "Cookie: session=abc123"));
+    }
+}
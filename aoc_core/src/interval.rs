@@ -0,0 +1,456 @@
+//! Sets of integer intervals and N-dimensional boxes.
+//!
+//! Both are half-open, like `Range`: an interval `start..end` holds `start` but not `end`, and
+//! a [`Box`] holds the points with `min[i] <= p[i] < max[i]` on every axis. Puzzles mostly give
+//! inclusive bounds, so there are constructors that take those too.
+//!
+//! [`IntervalSet`] keeps its intervals sorted, disjoint and with gaps between them, so two sets
+//! holding the same values are equal. [`Box`] is a single box; set operations on boxes return
+//! lists of disjoint boxes, since the union or difference of two boxes generally isn't a box.
+//! It shares its name with `std::boxed::Box`, so refer to it through the module, e.g.
+//! `type Cuboid = interval::Box<i32, 3>;`.
+
+use std::fmt::Debug;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// An integer coordinate.
+pub trait Coord: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ONE: Self;
+
+    /// The number of values in `start..end`, which must not be empty.
+    fn span(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ONE: Self = 1;
+
+                fn span(start: Self, end: Self) -> u64 {
+                    end.abs_diff(start) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i16, i32, i64, isize, u16, u32, u64, usize);
+
+/// A set of values stored as sorted, disjoint, non-adjacent intervals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Coord> IntervalSet<T> {
+    /// An empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The intervals, in order
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| T::span(r.start, r.end)).sum()
+    }
+
+    /// The smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// The largest value in the set
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|r| r.end - T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    /// Adds the values in `range`, merging it with any intervals it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let lo = self.ranges.partition_point(|r| r.end < range.start);
+        let hi = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if lo == hi {
+            range
+        } else {
+            self.ranges[lo].start.min(range.start)..self.ranges[hi - 1].end.max(range.end)
+        };
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// Removes the values in `range`.
+    pub fn remove(&mut self, range: Range<T>) {
+        *self = self.difference(&IntervalSet::from(range));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = other.ranges.get(j).filter(|cut| cut.start < range.end) {
+                if cut.end <= start {
+                    j += 1;
+                    continue;
+                }
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                if cut.end >= range.end {
+                    start = range.end;
+                    break;
+                }
+                start = cut.end;
+                j += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Splits the set into the values below `at` and the values from `at` up.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Self::new();
+        let mut above = Self::new();
+        for range in &self.ranges {
+            if range.end <= at {
+                below.ranges.push(range.clone());
+            } else if range.start >= at {
+                above.ranges.push(range.clone());
+            } else {
+                below.ranges.push(range.start..at);
+                above.ranges.push(at..range.end);
+            }
+        }
+        (below, above)
+    }
+
+    /// Maps the set piecewise. Each part of the set that falls in one of the `domains` is
+    /// passed to `f` along with the domain's index, and replaced by the interval `f` returns.
+    /// A value in several domains goes to the first; values in none are left as they are.
+    pub fn remap(&self, domains: &[Range<T>], f: impl Fn(usize, Range<T>) -> Range<T>) -> Self {
+        let mut remaining = self.clone();
+        let mut mapped = Self::new();
+        for (i, domain) in domains.iter().enumerate() {
+            let domain = IntervalSet::from(domain.clone());
+            for range in remaining.intersection(&domain).ranges {
+                mapped.insert(f(i, range));
+            }
+            remaining = remaining.difference(&domain);
+        }
+        mapped.union(&remaining)
+    }
+}
+
+impl<T: Coord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coord> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Coord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+impl<T: Coord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        iter.into_iter()
+            .map(|range| *range.start()..*range.end() + T::ONE)
+            .collect()
+    }
+}
+
+/// An axis-aligned box in N dimensions, holding the points with `min[i] <= p[i] < max[i]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Box<T, const N: usize> {
+    pub min: [T; N],
+    pub max: [T; N],
+}
+
+impl<T: Coord, const N: usize> Box<T, N> {
+    pub fn new(min: [T; N], max: [T; N]) -> Self {
+        Self { min, max }
+    }
+
+    /// The box with `a` and `b` as opposite corners, both of them inside it.
+    pub fn from_inclusive(a: [T; N], b: [T; N]) -> Self {
+        Self {
+            min: std::array::from_fn(|i| a[i].min(b[i])),
+            max: std::array::from_fn(|i| a[i].max(b[i]) + T::ONE),
+        }
+    }
+
+    /// The box's extent along one axis
+    pub fn interval(&self, axis: usize) -> Range<T> {
+        self.min[axis]..self.max[axis]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|i| self.min[i] >= self.max[i])
+    }
+
+    /// The number of integer points in the box
+    pub fn volume(&self) -> u64 {
+        if self.is_empty() {
+            return 0;
+        }
+        (0..N).map(|i| T::span(self.min[i], self.max[i])).product()
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] < self.max[i])
+    }
+
+    /// Whether the two boxes' extents overlap along `axis`, whatever they do on the others.
+    pub fn overlaps_along(&self, other: &Self, axis: usize) -> bool {
+        self.min[axis] < other.max[axis] && other.min[axis] < self.max[axis]
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.overlaps_along(other, i))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.intersects(other).then(|| Self {
+            min: std::array::from_fn(|i| self.min[i].max(other.min[i])),
+            max: std::array::from_fn(|i| self.max[i].min(other.max[i])),
+        })
+    }
+
+    /// The part of `self` outside `other`, as at most `2 * N` disjoint boxes. The box is cut
+    /// into slabs one axis at a time, so the slabs along the first axis are the largest.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for i in 0..N {
+            if rest.min[i] < cut.min[i] {
+                let mut below = rest;
+                below.max[i] = cut.min[i];
+                pieces.push(below);
+                rest.min[i] = cut.min[i];
+            }
+            if cut.max[i] < rest.max[i] {
+                let mut above = rest;
+                above.min[i] = cut.max[i];
+                pieces.push(above);
+                rest.max[i] = cut.max[i];
+            }
+        }
+        pieces
+    }
+
+    /// The points in either box, as `self` followed by disjoint pieces of `other`.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        let mut pieces = vec![*self];
+        pieces.extend(other.difference(self));
+        pieces
+    }
+
+    /// Splits the box across `axis` into the part below `at` and the part from `at` up.
+    pub fn split(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        if at <= self.min[axis] {
+            return (None, Some(*self));
+        }
+        if at >= self.max[axis] {
+            return (Some(*self), None);
+        }
+        let (mut below, mut above) = (*self, *self);
+        below.max[axis] = at;
+        above.min[axis] = at;
+        (Some(below), Some(above))
+    }
+}
+
+/// Moves the box by an offset.
+impl<T: Coord, const N: usize> Add<[T; N]> for Box<T, N> {
+    type Output = Self;
+
+    fn add(self, offset: [T; N]) -> Self {
+        Self {
+            min: std::array::from_fn(|i| self.min[i] + offset[i]),
+            max: std::array::from_fn(|i| self.max[i] + offset[i]),
+        }
+    }
+}
+
+/// Moves the box back by an offset.
+impl<T: Coord, const N: usize> Sub<[T; N]> for Box<T, N> {
+    type Output = Self;
+
+    fn sub(self, offset: [T; N]) -> Self {
+        Self {
+            min: std::array::from_fn(|i| self.min[i] - offset[i]),
+            max: std::array::from_fn(|i| self.max[i] - offset[i]),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut s = set(&[10..20, 30..40]);
+        s.insert(20..25);
+        assert_eq!(s.ranges(), &[10..25, 30..40]);
+        s.insert(5..35);
+        assert_eq!(s.ranges(), &[5..40]);
+        s.insert(50..50);
+        assert_eq!(s.ranges(), &[5..40]);
+        s.insert(0..2);
+        assert_eq!(s.ranges(), &[0..2, 5..40]);
+        assert_eq!(s.len(), 37);
+        assert_eq!((s.min(), s.max()), (Some(0), Some(39)));
+    }
+
+    #[test]
+    fn test_inclusive() {
+        let s: IntervalSet<usize> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(s.ranges(), &[3..6, 10..21]);
+        assert_eq!(s.len(), 14);
+        assert!(s.contains(5) && s.contains(10) && s.contains(20));
+        assert!(!s.contains(6) && !s.contains(9) && !s.contains(21) && !s.contains(0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..10, 20..30, 40..50]);
+        let b = set(&[5..25, 28..45]);
+        assert_eq!(a.union(&b).ranges(), &[0..50]);
+        assert_eq!(
+            a.intersection(&b).ranges(),
+            &[5..10, 20..25, 28..30, 40..45]
+        );
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28, 45..50]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+        assert!(a.difference(&set(&[-5..60])).is_empty());
+
+        let mut c = a.clone();
+        c.remove(25..42);
+        assert_eq!(c.ranges(), &[0..10, 20..25, 42..50]);
+    }
+
+    #[test]
+    fn test_split_and_remap() {
+        let a = set(&[0..10, 20..30]);
+        let (below, above) = a.split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(above.ranges(), &[25..30]);
+
+        // Shift 5..8 up by 100 and 6..25 (less what the first domain took) down by 5.
+        let mapped = a.remap(&[5..8, 6..25], |i, r| match i {
+            0 => r.start + 100..r.end + 100,
+            _ => r.start - 5..r.end - 5,
+        });
+        assert_eq!(mapped.ranges(), &[0..5, 15..20, 25..30, 105..108]);
+    }
+
+    #[test]
+    fn test_box() {
+        let a = Box::from_inclusive([0, 0, 0], [9, 9, 9]);
+        assert_eq!(a.volume(), 1000);
+        assert!(a.contains([9, 0, 5]) && !a.contains([10, 0, 5]));
+        let b = Box::new([5, 5, 5], [15, 15, 15]);
+        assert_eq!(a.intersection(&b), Some(Box::new([5, 5, 5], [10, 10, 10])));
+        assert_eq!(a.intersection(&(b + [10, 0, 0])), None);
+
+        let diff = a.difference(&b);
+        assert_eq!(diff.len(), 3);
+        assert_eq!(diff.iter().map(Box::volume).sum::<u64>(), 1000 - 125);
+        for (i, p) in diff.iter().enumerate() {
+            assert!(!p.intersects(&b));
+            assert!(diff[i + 1..].iter().all(|q| !p.intersects(q)));
+        }
+        let union = a.union(&b);
+        assert_eq!(union.iter().map(Box::volume).sum::<u64>(), 2000 - 125);
+
+        let hole = Box::new([3, 3, 3], [6, 6, 6]);
+        assert_eq!(a.difference(&hole).len(), 6);
+        assert_eq!(a.difference(&a), vec![]);
+    }
+
+    #[test]
+    fn test_box_split_and_move() {
+        let a: Box<usize, 2> = Box::new([1, 1], [4001, 4001]);
+        let (below, above) = a.split(0, 1000);
+        assert_eq!(below, Some(Box::new([1, 1], [1000, 4001])));
+        assert_eq!(above, Some(Box::new([1000, 1], [4001, 4001])));
+        assert_eq!(a.split(1, 1), (None, Some(a)));
+        assert_eq!(a.split(1, 4001), (Some(a), None));
+        assert_eq!(a.interval(1), 1..4001);
+        assert_eq!((a + [1, 2]) - [1, 2], a);
+        assert!(a.overlaps_along(&Box::new([0, 5000], [2, 6000]), 0));
+        assert!(Box::new([1, 1], [1, 5]).is_empty());
+    }
+}
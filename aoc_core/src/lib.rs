@@ -0,0 +1,43 @@
+//! Code shared by the Advent of Code solutions across all years.
+//!
+//! The per-year helper crates (`aoc2020`, `aoc2025` and the 2022 `aoc_utils`) now re-export
+//! from here, so new code should depend on `aoc_core` directly.
+
+pub mod answers;
+pub mod automaton;
+pub mod cycle;
+pub mod flat_grid;
+pub mod geometry;
+pub mod gf2;
+pub mod grid;
+#[cfg(feature = "grid")]
+pub mod grid_crate;
+#[cfg(feature = "ilp")]
+pub mod ilp;
+mod input;
+pub mod inputs;
+pub mod interval;
+#[cfg(feature = "linalg")]
+pub mod linalg;
+pub mod packing;
+mod point;
+pub mod rng;
+pub mod runner;
+pub mod search;
+mod solution;
+#[cfg(test)]
+mod testing;
+pub mod vm;
+
+pub use answers::{Answers, Verdict};
+pub use grid::Grid;
+pub use input::{
+    parse_digit_grid, parse_grid, parse_lines, read_test_input, read_test_input_2,
+    try_read_test_input, try_read_test_input_2,
+};
+pub use inputs::{load_input, puzzle_input, InputError, InputSource};
+pub use interval::IntervalSet;
+pub use point::{parse_points, Point};
+pub use solution::{
+    parse_report_line, report_line, run, solve, Part, PartResult, RunOptions, Solution,
+};
@@ -0,0 +1,429 @@
+//! Exact linear algebra over the rationals, for puzzles that come down to simultaneous
+//! equations.
+//!
+//! A [`Matrix`] holds [`Rational`] entries over an integer type `T`. With `T = i128` every
+//! operation is checked, so a system whose working values outgrow `i128` gives an
+//! [`Overflow`] rather than a wrong answer; with `T = BigInt` nothing can overflow. Either way
+//! no floats are involved, so an answer that should be a whole number can be checked for
+//! being one with [`to_integers`] rather than rounded and hoped for.
+
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Signed, Zero};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+pub use num_bigint::BigInt;
+
+/// Exact rational numbers over the integer type `T`, always held in lowest terms.
+pub type Rational<T> = num_rational::Ratio<T>;
+
+/// The integer types rationals can be built from here: `i64`, `i128` and [`BigInt`].
+pub trait Int:
+    Clone
+    + Debug
+    + Display
+    + Integer
+    + Signed
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + CheckedDiv
+    + From<i64>
+{
+}
+
+impl<T> Int for T where
+    T: Clone
+        + Debug
+        + Display
+        + Integer
+        + Signed
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+        + From<i64>
+{
+}
+
+/// A value grew too large for the integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+fn mul<T: Int>(a: &Rational<T>, b: &Rational<T>) -> Result<Rational<T>, Overflow> {
+    a.checked_mul(b).ok_or(Overflow)
+}
+
+fn div<T: Int>(a: &Rational<T>, b: &Rational<T>) -> Result<Rational<T>, Overflow> {
+    a.checked_div(b).ok_or(Overflow)
+}
+
+fn sub<T: Int>(a: &Rational<T>, b: &Rational<T>) -> Result<Rational<T>, Overflow> {
+    a.checked_sub(b).ok_or(Overflow)
+}
+
+/// The values as integers, if every one of them is whole.
+pub fn to_integers<T: Int>(values: &[Rational<T>]) -> Option<Vec<T>> {
+    values
+        .iter()
+        .map(|value| value.is_integer().then(|| value.to_integer()))
+        .collect()
+}
+
+/// Every solution of a system of linear equations `A x = b`.
+#[derive(Debug, Clone)]
+pub enum Solutions<T> {
+    /// The equations contradict each other.
+    None,
+    /// Exactly one `x` satisfies them.
+    Unique(Vec<Rational<T>>),
+    /// The solutions are `particular` plus any combination of the `nullspace` vectors.
+    Many {
+        particular: Vec<Rational<T>>,
+        nullspace: Vec<Vec<Rational<T>>>,
+    },
+}
+
+// Deriving these would only ask for `T: PartialEq`, but comparing rationals needs `T: Integer`
+impl<T: Int> PartialEq for Solutions<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Solutions::None, Solutions::None) => true,
+            (Solutions::Unique(a), Solutions::Unique(b)) => a == b,
+            (
+                Solutions::Many {
+                    particular: a,
+                    nullspace: n,
+                },
+                Solutions::Many {
+                    particular: b,
+                    nullspace: m,
+                },
+            ) => a == b && n == m,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Int> Eq for Solutions<T> {}
+
+impl<T: Int> Solutions<T> {
+    /// The only solution, if there is exactly one.
+    pub fn unique(&self) -> Option<&[Rational<T>]> {
+        match self {
+            Solutions::Unique(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// The only solution, if there is exactly one and it's all integers.
+    pub fn integer(&self) -> Option<Vec<T>> {
+        self.unique().and_then(to_integers)
+    }
+}
+
+/// A dense `rows` by `cols` matrix of rationals, stored in row-major order.
+#[derive(Debug, Clone)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<Rational<T>>,
+}
+
+impl<T: Int> Matrix<T> {
+    /// A matrix of integers, given row by row. Panics if the rows differ in length.
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        Self::from_rationals(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(Rational::from_integer).collect())
+                .collect(),
+        )
+    }
+
+    /// A matrix of rationals, given row by row. Panics if the rows differ in length.
+    pub fn from_rationals(rows: Vec<Vec<Rational<T>>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows differ in length"
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.entries.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+
+    /// This matrix with `column` appended as a last column.
+    fn augment(&self, column: &[T]) -> Self {
+        assert_eq!(column.len(), self.rows, "one value per row");
+        let rows = (0..self.rows)
+            .map(|row| {
+                let mut entries = self.entries[row * self.cols..(row + 1) * self.cols].to_vec();
+                entries.push(Rational::from_integer(column[row].clone()));
+                entries
+            })
+            .collect();
+        Self::from_rationals(rows)
+    }
+
+    /// The reduced row echelon form, found by Gauss-Jordan elimination, with the column of each
+    /// row's leading one.
+    pub fn rref(&self) -> Result<(Self, Vec<usize>), Overflow> {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        for col in 0..self.cols {
+            let row = pivots.len();
+            if row == self.rows {
+                break;
+            }
+            let Some(pivot) = (row..self.rows).find(|&r| !m[(r, col)].is_zero()) else {
+                continue;
+            };
+            m.swap_rows(row, pivot);
+
+            let scale = m[(row, col)].clone();
+            for c in col..self.cols {
+                m[(row, c)] = div(&m[(row, c)], &scale)?;
+            }
+            for r in (0..self.rows).filter(|&r| r != row) {
+                let factor = m[(r, col)].clone();
+                if factor.is_zero() {
+                    continue;
+                }
+                for c in col..self.cols {
+                    m[(r, c)] = sub(&m[(r, c)], &mul(&factor, &m[(row, c)])?)?;
+                }
+            }
+            pivots.push(col);
+        }
+        Ok((m, pivots))
+    }
+
+    /// The number of linearly independent rows (or columns).
+    pub fn rank(&self) -> Result<usize, Overflow> {
+        Ok(self.rref()?.1.len())
+    }
+
+    /// A basis for the vectors `x` with `A x = 0`, one vector per free column.
+    pub fn nullspace(&self) -> Result<Vec<Vec<Rational<T>>>, Overflow> {
+        let (m, pivots) = self.rref()?;
+        Ok(Self::nullspace_of_rref(&m, &pivots, self.cols))
+    }
+
+    /// The nullspace basis read off a reduced matrix, of which only the first `cols` columns
+    /// belong to `A`.
+    fn nullspace_of_rref(m: &Self, pivots: &[usize], cols: usize) -> Vec<Vec<Rational<T>>> {
+        (0..cols)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut x = vec![Rational::zero(); cols];
+                x[free] = Rational::from_integer(T::one());
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    x[pivot] = -m[(row, free)].clone();
+                }
+                x
+            })
+            .collect()
+    }
+
+    /// Every solution of `A x = rhs`.
+    pub fn solve(&self, rhs: &[T]) -> Result<Solutions<T>, Overflow> {
+        let (m, pivots) = self.augment(rhs).rref()?;
+        if pivots.last() == Some(&self.cols) {
+            return Ok(Solutions::None);
+        }
+
+        // Setting every free variable to zero leaves each pivot variable equal to its row's
+        // right-hand side
+        let mut particular = vec![Rational::zero(); self.cols];
+        for (row, &pivot) in pivots.iter().enumerate() {
+            particular[pivot] = m[(row, self.cols)].clone();
+        }
+        let nullspace = Self::nullspace_of_rref(&m, &pivots, self.cols);
+        Ok(if nullspace.is_empty() {
+            Solutions::Unique(particular)
+        } else {
+            Solutions::Many {
+                particular,
+                nullspace,
+            }
+        })
+    }
+
+    /// The determinant of a square matrix, by elimination to upper triangular form.
+    pub fn determinant(&self) -> Result<Rational<T>, Overflow> {
+        assert_eq!(self.rows, self.cols, "determinant of a non-square matrix");
+        if self.rows == 2 {
+            let ad = mul(&self[(0, 0)], &self[(1, 1)])?;
+            return sub(&ad, &mul(&self[(0, 1)], &self[(1, 0)])?);
+        }
+
+        let mut m = self.clone();
+        let mut det = Rational::from_integer(T::one());
+        for col in 0..self.cols {
+            let Some(pivot) = (col..self.rows).find(|&r| !m[(r, col)].is_zero()) else {
+                return Ok(Rational::zero());
+            };
+            if pivot != col {
+                m.swap_rows(col, pivot);
+                det = -det;
+            }
+            let scale = m[(col, col)].clone();
+            det = mul(&det, &scale)?;
+            for r in col + 1..self.rows {
+                let factor = div(&m[(r, col)], &scale)?;
+                if factor.is_zero() {
+                    continue;
+                }
+                for c in col..self.cols {
+                    m[(r, c)] = sub(&m[(r, c)], &mul(&factor, &m[(col, c)])?)?;
+                }
+            }
+        }
+        Ok(det)
+    }
+
+    /// The solution of the square system `A x = rhs` by Cramer's rule, or `None` if `A` is
+    /// singular. Each unknown is the determinant of `A` with its column replaced by `rhs`,
+    /// over the determinant of `A`; for two or three unknowns that's all there is to it.
+    pub fn cramer(&self, rhs: &[T]) -> Result<Option<Vec<Rational<T>>>, Overflow> {
+        assert_eq!(rhs.len(), self.rows, "one value per row");
+        let det = self.determinant()?;
+        if det.is_zero() {
+            return Ok(None);
+        }
+        (0..self.cols)
+            .map(|col| {
+                let mut replaced = self.clone();
+                for (row, value) in rhs.iter().enumerate() {
+                    replaced[(row, col)] = Rational::from_integer(value.clone());
+                }
+                div(&replaced.determinant()?, &det)
+            })
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+}
+
+impl<T: Int> PartialEq for Matrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows && self.cols == other.cols && self.entries == other.entries
+    }
+}
+
+impl<T: Int> Eq for Matrix<T> {}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = Rational<T>;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Rational<T> {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.entries[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Rational<T> {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &mut self.entries[row * self.cols + col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(num: i128, den: i128) -> Rational<i128> {
+        Rational::new(num, den)
+    }
+
+    #[test]
+    fn test_solve_unique() {
+        // 2x + y = 3 and 2x - 2y = 1
+        let a = Matrix::new(vec![vec![2i128, 1], vec![2, -2]]);
+        let solutions = a.solve(&[3, 1]).unwrap();
+        assert_eq!(solutions, Solutions::Unique(vec![r(7, 6), r(2, 3)]));
+        assert_eq!(solutions.integer(), None);
+        assert_eq!(a.cramer(&[3, 1]).unwrap(), Some(vec![r(7, 6), r(2, 3)]));
+
+        let solutions = a.solve(&[6, 0]).unwrap();
+        assert_eq!(solutions.integer(), Some(vec![2, 2]));
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let a = Matrix::new(vec![vec![1i128, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+        assert_eq!(a.rank().unwrap(), 2);
+        assert_eq!(a.determinant().unwrap(), r(0, 1));
+        assert_eq!(a.cramer(&[1, 2, 3]).unwrap(), None);
+        assert_eq!(a.solve(&[1, 3, 0]).unwrap(), Solutions::None);
+
+        let nullspace = a.nullspace().unwrap();
+        assert_eq!(nullspace, vec![vec![r(-1, 1), r(-1, 1), r(1, 1)]]);
+        match a.solve(&[1, 2, 3]).unwrap() {
+            Solutions::Many {
+                particular,
+                nullspace: basis,
+            } => {
+                assert_eq!(particular, vec![r(3, 1), r(-1, 1), r(0, 1)]);
+                assert_eq!(basis, nullspace);
+            }
+            other => panic!("expected many solutions, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rref() {
+        let a = Matrix::new(vec![vec![0i128, 2, 4], vec![1, 1, 1], vec![2, 2, 2]]);
+        let (m, pivots) = a.rref().unwrap();
+        assert_eq!(pivots, [0, 1]);
+        assert_eq!(
+            m,
+            Matrix::new(vec![vec![1, 0, -1], vec![0, 1, 2], vec![0, 0, 0]])
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        let a = Matrix::new(vec![vec![0i128, 1, 2], vec![3, 4, 5], vec![6, 7, 9]]);
+        assert_eq!(a.determinant().unwrap(), r(-3, 1));
+        assert_eq!(Matrix::<i128>::new(vec![]).determinant().unwrap(), r(1, 1));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = i64::MAX / 2;
+        let a = Matrix::new(vec![vec![big, 3], vec![5, big]]);
+        assert_eq!(a.determinant(), Err(Overflow));
+
+        let a = Matrix::new(vec![
+            vec![BigInt::from(big), 3.into()],
+            vec![5.into(), big.into()],
+        ]);
+        let expected = BigInt::from(big) * BigInt::from(big) - BigInt::from(15);
+        assert_eq!(a.determinant().unwrap(), Rational::from_integer(expected));
+    }
+}
@@ -0,0 +1,578 @@
+//! Packing polyominoes into a rectangle, where pieces may be rotated and flipped and cells may
+//! be left empty.
+//!
+//! [`pack`] first rejects anything whose pieces have more area than the region, then tries the
+//! easy answer of giving each piece its own block of the region. Failing that it searches
+//! exhaustively: cells are decided in reading order, and the first undecided cell is either
+//! the top-left cell of some piece or left empty, if there's room to spare. Each placement is
+//! a bitboard over the region, so checking it against the pieces already placed is a few word
+//! ANDs. Pieces of the same kind are interchangeable, so the search picks a kind rather than a
+//! particular piece and never tries the same packing twice. Branches are pruned when the
+//! remaining pieces need more cells than are left or than they could still reach, or when a
+//! checkerboard colouring shows they can't cover the right mix of colours: a piece covers one
+//! of two colour counts wherever it goes, so three T tetrominoes (each three of one colour and
+//! one of the other) can never exactly fill a 4 × 3 box.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A shape made of unit squares, as `(row, col)` cells shifted so the smallest row and column
+/// are zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Polyomino {
+    cells: Vec<(usize, usize)>,
+}
+
+impl Polyomino {
+    /// The shape made of `cells`, which may be anywhere. Panics if there are none.
+    pub fn new(cells: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut cells: Vec<(usize, usize)> = cells.into_iter().collect();
+        let top = cells.iter().map(|&(row, _)| row).min().expect("no cells");
+        let left = cells.iter().map(|&(_, col)| col).min().unwrap();
+        for (row, col) in &mut cells {
+            *row -= top;
+            *col -= left;
+        }
+        cells.sort_unstable();
+        cells.dedup();
+        Self { cells }
+    }
+
+    /// The cells in reading order.
+    pub fn cells(&self) -> &[(usize, usize)] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.iter().map(|&(row, _)| row + 1).max().unwrap()
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.iter().map(|&(_, col)| col + 1).max().unwrap()
+    }
+
+    /// The shape turned a quarter clockwise.
+    pub fn rotate(&self) -> Self {
+        let height = self.height();
+        Self::new(self.cells.iter().map(|&(row, col)| (col, height - 1 - row)))
+    }
+
+    /// The shape flipped left to right.
+    pub fn reflect(&self) -> Self {
+        let width = self.width();
+        Self::new(self.cells.iter().map(|&(row, col)| (row, width - 1 - col)))
+    }
+
+    /// The distinct rotations and reflections of the shape: one for a square, up to eight for
+    /// a shape with no symmetry.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations: Vec<Self> = [self.clone(), self.reflect()]
+            .into_iter()
+            .flat_map(|shape| std::iter::successors(Some(shape), |s| Some(s.rotate())).take(4))
+            .collect();
+        orientations.sort();
+        orientations.dedup();
+        orientations
+    }
+}
+
+/// Rows of `#` for the shape's cells and `.` for the rest.
+impl FromStr for Polyomino {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        for (row, line) in s
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .enumerate()
+        {
+            for (col, c) in line.chars().enumerate() {
+                match c {
+                    '#' => cells.push((row, col)),
+                    '.' => {}
+                    _ => return Err(format!("unexpected '{c}' in shape")),
+                }
+            }
+        }
+        if cells.is_empty() {
+            return Err("shape has no cells".to_string());
+        }
+        Ok(Self::new(cells))
+    }
+}
+
+impl Display for Polyomino {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in 0..self.height() {
+            let line: String = (0..self.width())
+                .map(|col| {
+                    if self.cells.contains(&(row, col)) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Pieces placed in a `width` × `height` region.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packing {
+    pub width: usize,
+    pub height: usize,
+    /// The kind of each piece, as an index into the pieces asked for, and the `(row, col)`
+    /// cells it covers.
+    pub pieces: Vec<(usize, Vec<(usize, usize)>)>,
+}
+
+/// The region with each piece drawn in its own letter and empty cells as `.`.
+impl Display for Packing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        let mut grid = vec![vec!['.'; self.width]; self.height];
+        for (i, (_, cells)) in self.pieces.iter().enumerate() {
+            for &(row, col) in cells {
+                grid[row][col] = LETTERS[i % LETTERS.len()] as char;
+            }
+        }
+        for row in grid {
+            writeln!(f, "{}", row.into_iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// Some way of fitting `count` copies of each shape in `pieces` into a `width` × `height`
+/// region without overlaps, or `None` if there is no way.
+pub fn pack(width: usize, height: usize, pieces: &[(Polyomino, usize)]) -> Option<Packing> {
+    let area: usize = pieces
+        .iter()
+        .map(|(shape, count)| shape.area() * count)
+        .sum();
+    if area > width * height {
+        return None;
+    }
+    pack_in_blocks(width, height, pieces).or_else(|| search(width, height, pieces))
+}
+
+/// Cut the region into blocks as big as the largest piece and put one piece in each, if there
+/// are enough blocks.
+fn pack_in_blocks(width: usize, height: usize, pieces: &[(Polyomino, usize)]) -> Option<Packing> {
+    let used = || pieces.iter().filter(|(_, count)| *count > 0);
+    let block_height = used().map(|(shape, _)| shape.height()).max().unwrap_or(1);
+    let block_width = used().map(|(shape, _)| shape.width()).max().unwrap_or(1);
+    let across = width / block_width;
+    let blocks = (0..(height / block_height) * across)
+        .map(|i| ((i / across) * block_height, (i % across) * block_width));
+
+    let shapes = pieces
+        .iter()
+        .enumerate()
+        .flat_map(|(kind, (shape, count))| std::iter::repeat_n((kind, shape), *count));
+    let placed: Vec<_> = shapes
+        .zip(blocks)
+        .map(|((kind, shape), (top, left))| {
+            let cells = shape.cells().iter().map(|&(r, c)| (top + r, left + c));
+            (kind, cells.collect())
+        })
+        .collect();
+
+    let total: usize = pieces.iter().map(|(_, count)| count).sum();
+    (placed.len() == total).then_some(Packing {
+        width,
+        height,
+        pieces: placed,
+    })
+}
+
+const WORD: usize = u64::BITS as usize;
+
+/// A piece in one orientation at one position, as a bitboard starting at word `start`.
+struct Placement {
+    kind: usize,
+    start: usize,
+    mask: Vec<u64>,
+    /// How many cells of each checkerboard colour it covers
+    colours: [usize; 2],
+}
+
+impl Placement {
+    fn new(kind: usize, cells: &[usize], width: usize) -> Self {
+        let start = cells[0] / WORD;
+        let mut mask = vec![0; cells[cells.len() - 1] / WORD - start + 1];
+        let mut colours = [0; 2];
+        for &cell in cells {
+            mask[cell / WORD - start] |= 1 << (cell % WORD);
+            colours[colour(cell, width)] += 1;
+        }
+        Self {
+            kind,
+            start,
+            mask,
+            colours,
+        }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = usize> + '_ {
+        self.mask.iter().enumerate().flat_map(move |(i, &word)| {
+            (0..WORD)
+                .filter(move |bit| word >> bit & 1 == 1)
+                .map(move |bit| (self.start + i) * WORD + bit)
+        })
+    }
+}
+
+fn colour(cell: usize, width: usize) -> usize {
+    (cell / width + cell % width) % 2
+}
+
+/// How many cells of colour 0 a piece of one kind can cover, over all its placements.
+#[derive(Debug, Clone, Copy)]
+struct Coverage {
+    least: usize,
+    most: usize,
+    /// The parity of the count, if it's the same wherever the piece goes
+    parity: Option<usize>,
+}
+
+impl Coverage {
+    fn new(orientations: &[Polyomino]) -> Self {
+        // At an anchor of one colour the piece covers `even` cells of colour 0, at the other
+        // `area - even`
+        let counts: Vec<usize> = orientations
+            .iter()
+            .flat_map(|shape| {
+                let even = shape
+                    .cells()
+                    .iter()
+                    .filter(|&&(r, c)| (r + c) % 2 == 0)
+                    .count();
+                [even, shape.area() - even]
+            })
+            .collect();
+        let parity = counts[0] % 2;
+        Self {
+            least: *counts.iter().min().unwrap(),
+            most: *counts.iter().max().unwrap(),
+            parity: counts.iter().all(|c| c % 2 == parity).then_some(parity),
+        }
+    }
+}
+
+struct Search<'a> {
+    width: usize,
+    /// The placements whose top-left cell is each cell of the region
+    placements: &'a [Vec<Placement>],
+    /// The cells covered or left empty so far
+    board: Vec<u64>,
+    counts: Vec<usize>,
+    areas: Vec<usize>,
+    coverage: Vec<Coverage>,
+    /// Undecided cells of each colour
+    free: [usize; 2],
+    remaining_area: usize,
+    chosen: Vec<&'a Placement>,
+    /// Scratch space for the cells the remaining pieces could reach
+    reach: Vec<u64>,
+}
+
+impl<'a> Search<'a> {
+    fn is_decided(&self, cell: usize) -> bool {
+        self.board[cell / WORD] >> (cell % WORD) & 1 == 1
+    }
+
+    fn toggle(&mut self, placement: &Placement) {
+        for (word, mask) in self.board[placement.start..]
+            .iter_mut()
+            .zip(&placement.mask)
+        {
+            *word ^= mask;
+        }
+    }
+
+    fn fits(&self, placement: &Placement) -> bool {
+        self.board[placement.start..]
+            .iter()
+            .zip(&placement.mask)
+            .all(|(word, mask)| word & mask == 0)
+    }
+
+    /// Whether the remaining pieces could still fit in the undecided cells
+    fn hopeful(&self) -> bool {
+        let Some(slack) = (self.free[0] + self.free[1]).checked_sub(self.remaining_area) else {
+            return false;
+        };
+
+        // The pieces must cover no more colour 0 cells than are free, and leave no more colour
+        // 1 cells uncovered than there's slack for
+        let remaining = || {
+            self.counts
+                .iter()
+                .zip(&self.coverage)
+                .filter(|(&count, _)| count > 0)
+        };
+        let least: usize = remaining().map(|(count, c)| count * c.least).sum();
+        let most: usize = remaining().map(|(count, c)| count * c.most).sum();
+        if least > self.free[0] || most < self.free[0].saturating_sub(slack) {
+            return false;
+        }
+
+        // With no slack they must cover exactly the free colour 0 cells
+        let parity: Option<usize> = remaining()
+            .map(|(count, c)| c.parity.map(|p| count * p))
+            .sum();
+        slack > 0 || parity.is_none_or(|parity| parity % 2 == self.free[0] % 2)
+    }
+
+    /// How many undecided cells some remaining piece could still cover, given that every cell
+    /// before `from` is decided. The rest can only be left empty.
+    fn coverable(&mut self, from: usize) -> usize {
+        let mut reach = std::mem::take(&mut self.reach);
+        reach.fill(0);
+        for (cell, placements) in self.placements.iter().enumerate().skip(from) {
+            if self.is_decided(cell) {
+                continue;
+            }
+            for placement in placements {
+                if self.counts[placement.kind] > 0 && self.fits(placement) {
+                    for (word, mask) in reach[placement.start..].iter_mut().zip(&placement.mask) {
+                        *word |= mask;
+                    }
+                }
+            }
+        }
+        let coverable = reach.iter().map(|word| word.count_ones() as usize).sum();
+        self.reach = reach;
+        coverable
+    }
+
+    /// Decide the cells from `from` onwards, returning whether every piece was placed.
+    fn run(&mut self, from: usize) -> bool {
+        if self.remaining_area == 0 {
+            return true;
+        }
+        if !self.hopeful() {
+            return false;
+        }
+        let cells = self.placements.len();
+        let Some(cell) = (from..cells).find(|&cell| !self.is_decided(cell)) else {
+            return false;
+        };
+        if self.coverable(cell) < self.remaining_area {
+            return false;
+        }
+
+        let placements = self.placements;
+        for placement in &placements[cell] {
+            if self.counts[placement.kind] == 0 || !self.fits(placement) {
+                continue;
+            }
+            self.place(placement, true);
+            self.chosen.push(placement);
+            if self.run(cell + 1) {
+                return true;
+            }
+            self.chosen.pop();
+            self.place(placement, false);
+        }
+
+        // Leave the cell empty, if there's room to
+        if self.remaining_area < self.free[0] + self.free[1] {
+            let c = colour(cell, self.width);
+            self.board[cell / WORD] ^= 1 << (cell % WORD);
+            self.free[c] -= 1;
+            if self.run(cell + 1) {
+                return true;
+            }
+            self.free[c] += 1;
+            self.board[cell / WORD] ^= 1 << (cell % WORD);
+        }
+        false
+    }
+
+    /// Put `placement` down, or pick it back up.
+    fn place(&mut self, placement: &Placement, down: bool) {
+        self.toggle(placement);
+        let area = self.areas[placement.kind];
+        if down {
+            self.counts[placement.kind] -= 1;
+            self.remaining_area -= area;
+            self.free[0] -= placement.colours[0];
+            self.free[1] -= placement.colours[1];
+        } else {
+            self.counts[placement.kind] += 1;
+            self.remaining_area += area;
+            self.free[0] += placement.colours[0];
+            self.free[1] += placement.colours[1];
+        }
+    }
+}
+
+fn search(width: usize, height: usize, pieces: &[(Polyomino, usize)]) -> Option<Packing> {
+    let orientations: Vec<Vec<Polyomino>> = pieces
+        .iter()
+        .map(|(shape, _)| shape.orientations())
+        .collect();
+
+    // Each orientation of each piece, anchored by its first cell in reading order at every cell
+    // it fits from
+    let mut placements: Vec<Vec<Placement>> = (0..width * height).map(|_| Vec::new()).collect();
+    for (kind, shapes) in orientations.iter().enumerate() {
+        for shape in shapes
+            .iter()
+            .filter(|s| s.height() <= height && s.width() <= width)
+        {
+            let (_, anchor) = shape.cells()[0];
+            for top in 0..=height - shape.height() {
+                for left in 0..=width - shape.width() {
+                    let cells: Vec<usize> = shape
+                        .cells()
+                        .iter()
+                        .map(|&(r, c)| (top + r) * width + left + c)
+                        .collect();
+                    placements[top * width + left + anchor]
+                        .push(Placement::new(kind, &cells, width));
+                }
+            }
+        }
+    }
+
+    let cells = width * height;
+    let even = (0..cells).filter(|&cell| colour(cell, width) == 0).count();
+    let mut search = Search {
+        width,
+        placements: &placements,
+        board: vec![0; cells.div_ceil(WORD)],
+        counts: pieces.iter().map(|(_, count)| *count).collect(),
+        areas: pieces.iter().map(|(shape, _)| shape.area()).collect(),
+        coverage: orientations
+            .iter()
+            .map(|shapes| Coverage::new(shapes))
+            .collect(),
+        free: [even, cells - even],
+        remaining_area: pieces
+            .iter()
+            .map(|(shape, count)| shape.area() * count)
+            .sum(),
+        chosen: Vec::new(),
+        reach: vec![0; cells.div_ceil(WORD)],
+    };
+    if !search.run(0) {
+        return None;
+    }
+
+    let pieces = search
+        .chosen
+        .iter()
+        .map(|placement| {
+            let cells = placement.cells().map(|cell| (cell / width, cell % width));
+            (placement.kind, cells.collect())
+        })
+        .collect();
+    Some(Packing {
+        width,
+        height,
+        pieces,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(s: &str) -> Polyomino {
+        s.replace(' ', "\n").parse().unwrap()
+    }
+
+    /// Checks every piece is a copy of its shape, within the region and clear of the others
+    fn check(packing: &Packing, pieces: &[(Polyomino, usize)]) {
+        let mut seen = vec![vec![false; packing.width]; packing.height];
+        for (kind, cells) in &packing.pieces {
+            assert!(pieces[*kind]
+                .0
+                .orientations()
+                .contains(&Polyomino::new(cells.clone())));
+            for &(row, col) in cells {
+                assert!(!seen[row][col], "pieces overlap at ({row}, {col})");
+                seen[row][col] = true;
+            }
+        }
+        for (kind, (_, count)) in pieces.iter().enumerate() {
+            let placed = packing.pieces.iter().filter(|(k, _)| k == &kind).count();
+            assert_eq!(placed, *count);
+        }
+    }
+
+    #[test]
+    fn test_orientations() {
+        assert_eq!(shape("##").orientations().len(), 2);
+        assert_eq!(shape("## ##").orientations().len(), 1);
+        assert_eq!(shape("### #..").orientations().len(), 8);
+        assert_eq!(shape(".#. ###").orientations().len(), 4);
+        assert_eq!(shape("##. .##").orientations().len(), 4);
+
+        let l = shape("#. #. ##");
+        assert_eq!(l.rotate().to_string(), "###\n#..\n");
+        assert_eq!(l.reflect().to_string(), ".#\n.#\n##\n");
+        assert_eq!(l.rotate().rotate().rotate().rotate(), l);
+    }
+
+    #[test]
+    fn test_pack_exactly() {
+        // Twelve of the five-cell pentominoes tile a 6 × 10 rectangle, and it takes a search
+        // rather than blocks to find how
+        let pentominoes = [
+            "#####",
+            "#### #...",
+            "### ##.",
+            "### #.. #..",
+            "##. .## ..#",
+            ".## ##. .#.",
+            "### .#. .#.",
+            "#.# ###",
+            "##. .#. .##",
+            ".#. ### .#.",
+            "#### .#..",
+            "###. ..##",
+        ];
+        let pieces: Vec<_> = pentominoes.iter().map(|s| (shape(s), 1)).collect();
+        let packing = pack(10, 6, &pieces).unwrap();
+        check(&packing, &pieces);
+        assert!(!packing.to_string().contains('.'));
+    }
+
+    #[test]
+    fn test_pack_with_gaps() {
+        // Three L trominoes and a domino in a 3 × 4 box, with a cell to spare
+        let pieces = [(shape("#. ##"), 3), (shape("##"), 1)];
+        let packing = pack(4, 3, &pieces).unwrap();
+        check(&packing, &pieces);
+        assert_eq!(packing.to_string().matches('.').count(), 1);
+    }
+
+    #[test]
+    fn test_reject() {
+        // Too much area
+        assert_eq!(pack(3, 3, &[(shape("##"), 5)]), None);
+
+        // Enough area, but a 2 × 2 square can't go in a 1-wide strip
+        assert_eq!(pack(8, 1, &[(shape("## ##"), 1)]), None);
+
+        // A 4 × 3 box has six cells of each colour, but three T tetrominoes cover an odd
+        // number of either. Four of them do tile a 4 × 4 box.
+        let t = shape("### .#.");
+        assert_eq!(pack(4, 3, &[(t.clone(), 3)]), None);
+        let packing = pack(4, 4, &[(t.clone(), 4)]).unwrap();
+        check(&packing, &[(t, 4)]);
+    }
+}
@@ -0,0 +1,57 @@
+//! A small, seeded random number generator for test data and benchmarks.
+//!
+//! Tests and benchmarks want the same "random" input on every run, without pulling in the
+//! `rand` crate, so this is Marsaglia's xorshift64. It is fast and reproducible, and not
+//! suitable for anything that needs real randomness.
+
+/// The xorshift64 generator with shifts 13, 7 and 17.
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// Start from `seed`. Panics if it's zero, which would only ever produce zeros.
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "xorshift needs a non-zero seed");
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// True about once in every `n` calls.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n)
+    }
+}
+
+impl Iterator for XorShift {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        Some(self.next_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xorshift() {
+        let mut rng = XorShift::new(1);
+        assert_eq!(rng.next_u64(), 1082269761);
+        assert_eq!(
+            XorShift::new(42).take(5).collect::<Vec<_>>(),
+            XorShift::new(42).take(5).collect::<Vec<_>>()
+        );
+
+        let hits = (0..3000).filter(|_| rng.one_in(3)).count();
+        assert!((900..1100).contains(&hits), "{hits}");
+    }
+}
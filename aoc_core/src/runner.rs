@@ -0,0 +1,242 @@
+//! Discovery and execution of the per-year day binaries.
+//!
+//! Each year lives in its own cargo project, and the years don't share a toolchain setup
+//! (editions, nightly features, native solver dependencies), so the runner drives the day
+//! binaries through `cargo run` rather than linking them all into one executable. Days report
+//! their answers back with `--report` (see [`crate::report_line`]).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::inputs::{default_source_in, InputSource};
+use crate::{parse_report_line, Answers, Part, PartResult};
+
+/// How a year's solutions are organised on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// A single package with one `src/bin/dayNN.rs` binary per day (2020, 2025).
+    Package,
+    /// A cargo workspace with one `dayNN` package per day (2021-2024).
+    Workspace,
+}
+
+/// One year's cargo project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Year {
+    pub year: u16,
+    pub dir: PathBuf,
+    pub layout: Layout,
+}
+
+impl Year {
+    /// Inspect `root/<year>` and work out its layout.
+    pub fn open(root: &Path, year: u16) -> Option<Self> {
+        let dir = root.join(year.to_string());
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        let layout = if manifest.contains("[workspace]") {
+            Layout::Workspace
+        } else {
+            Layout::Package
+        };
+        Some(Self { year, dir, layout })
+    }
+
+    /// Days that have a solution, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let (dir, suffix) = match self.layout {
+            Layout::Package => (self.dir.join("src").join("bin"), ".rs"),
+            Layout::Workspace => (self.dir.clone(), ""),
+        };
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut days: Vec<u8> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let day = name.strip_prefix("day")?.strip_suffix(suffix)?;
+                if day.len() != 2 {
+                    return None;
+                }
+                if self.layout == Layout::Workspace && !entry.path().join("Cargo.toml").exists() {
+                    return None;
+                }
+                day.parse().ok()
+            })
+            .collect();
+        days.sort_unstable();
+        days
+    }
+
+    /// Directory the day binary is run from.
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        match self.layout {
+            Layout::Package => self.dir.clone(),
+            Layout::Workspace => self.dir.join(format!("day{day:02}")),
+        }
+    }
+
+    /// Where the puzzle input for a day is kept.
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        match self.layout {
+            Layout::Package => self.dir.join("inputs").join(format!("day{day:02}.txt")),
+            Layout::Workspace => self.day_dir(day).join("input.txt"),
+        }
+    }
+
+    /// Expected answers from the year's `answers.toml`.
+    pub fn answers(&self) -> Result<Answers, String> {
+        Answers::load(&self.dir)
+    }
+
+    /// Build the `cargo run` command for a day.
+    pub fn command(&self, day: u8, part: Part, release: bool) -> Command {
+        let name = format!("day{day:02}");
+        let mut cmd = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+        cmd.arg("run").arg("--quiet");
+        if release {
+            cmd.arg("--release");
+        }
+        match self.layout {
+            Layout::Package => cmd.args(["--bin", &name]),
+            Layout::Workspace => cmd.args(["-p", &name]),
+        };
+        let part = match part {
+            Part::One => "1",
+            Part::Two => "2",
+            Part::Both => "both",
+        };
+        cmd.args(["--", "--part", part, "--report"]);
+        cmd.current_dir(self.day_dir(day));
+        cmd
+    }
+}
+
+/// Years with a cargo project under `root`, in order.
+pub fn years(root: &Path) -> Vec<Year> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut years: Vec<Year> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok()?.parse().ok())
+        .filter_map(|year| Year::open(root, year))
+        .collect();
+    years.sort_by_key(|y| y.year);
+    years
+}
+
+/// Outcome of running one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Run a day binary and collect its reported answers.
+///
+/// The input is loaded first, so a missing one is reported (or fetched and cached, ready for
+/// the day binary) without building anything.
+pub fn run_day(year: &Year, day: u8, part: Part, release: bool) -> Result<DayReport, String> {
+    let root = year.dir.parent().unwrap_or(Path::new("."));
+    default_source_in(root)
+        .load(year.year, day)
+        .map_err(|e| e.to_string())?;
+
+    let output = year
+        .command(day, part, release)
+        .output()
+        .map_err(|e| format!("Failed to run cargo: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr
+            .lines()
+            .find(|line| line.starts_with("error") || line.contains("panicked"))
+            .unwrap_or("exited with an error");
+        return Err(reason.trim().to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut report = DayReport {
+        year: year.year,
+        day,
+        parse: Duration::ZERO,
+        parts: Vec::new(),
+    };
+    for (label, elapsed, answer) in stdout.lines().filter_map(parse_report_line) {
+        match label {
+            "parse" => report.parse = elapsed,
+            "part1" | "part2" => report.parts.push(PartResult {
+                part: label.as_bytes()[4] - b'0',
+                answer,
+                elapsed,
+            }),
+            _ => {}
+        }
+    }
+    if report.parts.is_empty() {
+        return Err("no answers reported".to_string());
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::scratch_dir;
+
+    #[test]
+    fn test_discover_layouts() {
+        let root = scratch_dir("layouts");
+
+        let package = root.join("2020");
+        fs::create_dir_all(package.join("src/bin")).unwrap();
+        fs::write(
+            package.join("Cargo.toml"),
+            "[package]\nname = \"aoc2020\"\n",
+        )
+        .unwrap();
+        for bin in ["day02.rs", "day01.rs", "day_template.rs"] {
+            fs::write(package.join("src/bin").join(bin), "").unwrap();
+        }
+
+        let workspace = root.join("2021");
+        fs::create_dir_all(&workspace).unwrap();
+        fs::write(workspace.join("Cargo.toml"), "[workspace]\n").unwrap();
+        for day in ["day01", "day03"] {
+            fs::create_dir_all(workspace.join(day)).unwrap();
+            fs::write(workspace.join(day).join("Cargo.toml"), "").unwrap();
+        }
+        fs::create_dir_all(workspace.join("day04")).unwrap();
+        fs::create_dir_all(root.join("snippets")).unwrap();
+
+        let found = years(&root);
+        assert_eq!(found.len(), 2);
+        assert_eq!((found[0].year, found[0].layout), (2020, Layout::Package));
+        assert_eq!(found[0].days(), vec![1, 2]);
+        assert_eq!(found[0].day_dir(1), package);
+        assert_eq!((found[1].year, found[1].layout), (2021, Layout::Workspace));
+        assert_eq!(found[1].days(), vec![1, 3]);
+        assert_eq!(found[1].day_dir(3), workspace.join("day03"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_missing_input_is_reported() {
+        let root = scratch_dir("missing_input");
+        fs::create_dir_all(root.join("2021/day01")).unwrap();
+        fs::write(root.join("2021/Cargo.toml"), "[workspace]\n").unwrap();
+
+        let year = Year::open(&root, 2021).unwrap();
+        let err = run_day(&year, 1, Part::Both, false).unwrap_err();
+        assert_eq!(err, "No input for 2021 day 1");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
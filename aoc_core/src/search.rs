@@ -0,0 +1,552 @@
+//! Graph searches over arbitrary state types.
+//!
+//! The searches take a start state and a `successors` function, so the state can be anything
+//! hashable: a grid position, a (position, direction) pair, a whole burrow of amphipods. Each
+//! search returns [`Paths`], which holds the distance to every reached state and its
+//! predecessors, from which paths can be rebuilt.
+//!
+//! Edge costs must not be negative.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Edge cost: any ordered number that starts at `Default::default()` (zero).
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Result of a search: distances from the start and the predecessor DAG.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    dist: HashMap<N, C>,
+    /// Predecessors of each state. The first entry is the one that set the state's distance;
+    /// [`dijkstra_all`] also keeps the others that reach it at the same cost.
+    preds: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Cost> Paths<N, C> {
+    fn new(start: N) -> Self {
+        Self {
+            dist: HashMap::from([(start, C::default())]),
+            preds: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// The first goal state reached, if the search found one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goals.first()
+    }
+
+    /// Every goal state reached at the best cost ([`dijkstra_all`] finds all of them, the
+    /// other searches stop at the first).
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Cost of the best path to the goal.
+    pub fn cost(&self) -> Option<C> {
+        self.goal().and_then(|g| self.distance(g))
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.dist
+    }
+
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.preds.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from the start to `node`, including both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.dist.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// One shortest path from the start to the goal.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal()?)
+    }
+
+    /// Every state that lies on some shortest path to one of `targets`.
+    pub fn nodes_on_paths_to<'a>(&self, targets: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&N> = targets
+            .into_iter()
+            .filter(|t| self.dist.contains_key(t))
+            .collect();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.predecessors(node));
+            }
+        }
+        seen
+    }
+
+    /// Every state on some shortest path to a goal.
+    pub fn nodes_on_best_paths(&self) -> HashSet<N> {
+        self.nodes_on_paths_to(&self.goals)
+    }
+
+    /// Relax the edge `from -> to` reaching `to` at `cost`. Returns true if it improved the
+    /// distance to `to`.
+    fn relax(&mut self, from: &N, to: N, cost: C, keep_ties: bool) -> bool {
+        match self.dist.entry(to) {
+            Entry::Vacant(e) => {
+                self.preds.insert(e.key().clone(), vec![from.clone()]);
+                e.insert(cost);
+                true
+            }
+            Entry::Occupied(mut e) => {
+                if cost < *e.get() {
+                    self.preds.insert(e.key().clone(), vec![from.clone()]);
+                    e.insert(cost);
+                    true
+                } else {
+                    if keep_ties && cost == *e.get() {
+                        let preds = self.preds.entry(e.key().clone()).or_default();
+                        if !preds.contains(from) {
+                            preds.push(from.clone());
+                        }
+                    }
+                    false
+                }
+            }
+        }
+    }
+}
+
+/// Breadth-first search with unit edge costs. Stops at the first state matching `is_goal`;
+/// pass `|_| false` to explore everything reachable.
+pub fn bfs<N, S, I>(
+    start: N,
+    mut successors: S,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, dist)) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goals.push(node);
+            break;
+        }
+        for next in successors(&node) {
+            if !paths.dist.contains_key(&next) && paths.relax(&node, next.clone(), dist + 1, false)
+            {
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+    paths
+}
+
+/// Heap entry ordered so that the lowest estimate is popped first.
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for a min-heap; prefer the deeper entry when estimates tie
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// Shared best-first search behind Dijkstra and A*.
+fn best_first<N, C, S, I>(
+    start: N,
+    mut successors: S,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    all: bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut heap = BinaryHeap::from([Queued {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+    let mut best = None;
+    while let Some(Queued {
+        estimate,
+        cost,
+        node,
+    }) = heap.pop()
+    {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        if paths.dist.get(&node).is_some_and(|&d| cost > d) {
+            continue;
+        }
+        if is_goal(&node) {
+            if !paths.goals.contains(&node) {
+                paths.goals.push(node.clone());
+            }
+            if !all {
+                break;
+            }
+            best = Some(cost);
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.relax(&node, next.clone(), next_cost, all) {
+                heap.push(Queued {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm. Stops at the first state matching `is_goal`; pass `|_| false` to
+/// find the distance to everything reachable.
+pub fn dijkstra<N, C, S, I>(start: N, successors: S, is_goal: impl FnMut(&N) -> bool) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, successors, |_| C::default(), is_goal, false)
+}
+
+/// Dijkstra's algorithm keeping every shortest path: the predecessors of each state include
+/// all states that reach it at the best cost, and every goal reachable at the best cost is
+/// found. Use [`Paths::nodes_on_best_paths`] to collect the states on any best path.
+pub fn dijkstra_all<N, C, S, I>(
+    start: N,
+    successors: S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, successors, |_| C::default(), is_goal, true)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<N, C, S, I>(
+    start: N,
+    successors: S,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, successors, heuristic, is_goal, false)
+}
+
+/// Result of [`bidirectional_bfs`]: the two half searches and where they met.
+#[derive(Debug, Clone)]
+pub struct Bidirectional<N> {
+    /// Distances from the start.
+    pub forward: Paths<N, usize>,
+    /// Distances to the goal, following edges backwards.
+    pub backward: Paths<N, usize>,
+    meeting: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone> Bidirectional<N> {
+    /// The state where the two searches met.
+    pub fn meeting(&self) -> Option<&N> {
+        self.meeting.as_ref()
+    }
+
+    /// Length of the shortest path from start to goal.
+    pub fn distance(&self) -> Option<usize> {
+        let m = self.meeting.as_ref()?;
+        Some(self.forward.distance(m)? + self.backward.distance(m)?)
+    }
+
+    /// A shortest path from start to goal, including both ends.
+    pub fn path(&self) -> Option<Vec<N>> {
+        let m = self.meeting.as_ref()?;
+        let mut path = self.forward.path_to(m)?;
+        let mut back = self.backward.path_to(m)?;
+        back.pop();
+        path.extend(back.into_iter().rev());
+        Some(path)
+    }
+}
+
+/// Breadth-first search from both ends at once, expanding the smaller frontier each step.
+/// `predecessors` gives the states with an edge into a state (the same as `successors` for
+/// undirected graphs).
+pub fn bidirectional_bfs<N, S, P, I, J>(
+    start: N,
+    goal: N,
+    mut successors: S,
+    mut predecessors: P,
+) -> Bidirectional<N>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    P: FnMut(&N) -> J,
+    I: IntoIterator<Item = N>,
+    J: IntoIterator<Item = N>,
+{
+    let mut result = Bidirectional {
+        forward: Paths::new(start.clone()),
+        backward: Paths::new(goal.clone()),
+        meeting: (start == goal).then(|| start.clone()),
+    };
+    let mut fwd_frontier = vec![start];
+    let mut bwd_frontier = vec![goal];
+
+    while result.meeting.is_none() && !fwd_frontier.is_empty() && !bwd_frontier.is_empty() {
+        let forward = fwd_frontier.len() <= bwd_frontier.len();
+        let (frontier, this, other) = if forward {
+            (&mut fwd_frontier, &mut result.forward, &result.backward)
+        } else {
+            (&mut bwd_frontier, &mut result.backward, &result.forward)
+        };
+
+        // Expand a whole layer, then pick the meeting point with the shortest total
+        let mut next_frontier = Vec::new();
+        let mut best: Option<(usize, N)> = None;
+        for node in frontier.drain(..) {
+            let dist = this.dist[&node] + 1;
+            let neighbours: Vec<N> = if forward {
+                successors(&node).into_iter().collect()
+            } else {
+                predecessors(&node).into_iter().collect()
+            };
+            for next in neighbours {
+                if this.dist.contains_key(&next) {
+                    continue;
+                }
+                this.relax(&node, next.clone(), dist, false);
+                if let Some(rest) = other.distance(&next) {
+                    if best.as_ref().is_none_or(|(b, _)| dist + rest < *b) {
+                        best = Some((dist + rest, next.clone()));
+                    }
+                }
+                next_frontier.push(next);
+            }
+        }
+        *frontier = next_frontier;
+        result.meeting = best.map(|(_, n)| n);
+    }
+
+    if result.meeting.is_some() {
+        result.forward.goals = result.meeting.iter().cloned().collect();
+        result.backward.goals = result.meeting.iter().cloned().collect();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn find(maze: &[Vec<char>], c: char) -> Point {
+        for (y, row) in maze.iter().enumerate() {
+            if let Some(x) = row.iter().position(|&v| v == c) {
+                return Point::new(x as isize, y as isize);
+            }
+        }
+        panic!("{c} not found")
+    }
+
+    fn open_neighbors(maze: &[Vec<char>], p: Point) -> Vec<Point> {
+        p.neighbors4()
+            .into_iter()
+            .filter(|n| crate::grid::get(maze, n.y, n.x).is_some_and(|&c| c != '#'))
+            .collect()
+    }
+
+    fn parse() -> (Vec<Vec<char>>, Point, Point) {
+        let maze = crate::parse_grid(MAZE);
+        let (s, e) = (find(&maze, 'S'), find(&maze, 'E'));
+        (maze, s, e)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (maze, start, end) = parse();
+        let paths = bfs(start, |&p| open_neighbors(&maze, p), |&p| p == end);
+        assert_eq!(paths.goal(), Some(&end));
+        assert_eq!(paths.cost(), Some(15));
+        let path = paths.path().unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!((path[0], path[15]), (start, end));
+        assert!(path
+            .windows(2)
+            .all(|w| w[0].manhattan_distance_to(&w[1]) == 1));
+
+        let unreachable = bfs(start, |&p| open_neighbors(&maze, p), |_| false);
+        assert_eq!(unreachable.goal(), None);
+        assert_eq!(unreachable.distance(&end), Some(15));
+        assert_eq!(unreachable.distance(&Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar_agree() {
+        // Entering a cell costs its digit
+        let grid = crate::parse_digit_grid("1163751\n1381373\n2136511\n3694931\n7463417");
+        let end = Point::new(6, 4);
+        let successors = |p: &Point| {
+            p.neighbors4()
+                .into_iter()
+                .filter_map(|n| Some((n, *crate::grid::get(&grid, n.y, n.x)?)))
+                .collect::<Vec<_>>()
+        };
+        let d = dijkstra(Point::new(0, 0), successors, |&p| p == end);
+        let a = astar(
+            Point::new(0, 0),
+            successors,
+            |p| p.manhattan_distance_to(&end) as u32,
+            |&p| p == end,
+        );
+        assert_eq!(d.cost(), Some(28));
+        assert_eq!(a.cost(), Some(28));
+        let path = a.path().unwrap();
+        let cost: u32 = path[1..]
+            .iter()
+            .map(|p| grid[p.y as usize][p.x as usize])
+            .sum();
+        assert_eq!(cost, 28);
+    }
+
+    #[test]
+    fn test_dijkstra_all_keeps_every_best_path() {
+        // Two equal routes around the pillar
+        let maze = crate::parse_grid("S..\n.#.\n..E");
+        let (start, end) = (Point::new(0, 0), Point::new(2, 2));
+        let successors = |&p: &Point| open_neighbors(&maze, p).into_iter().map(|n| (n, 1));
+
+        let paths = dijkstra_all(start, successors, |&p| p == end);
+        assert_eq!(paths.cost(), Some(4));
+        assert_eq!(paths.predecessors(&end).len(), 2);
+        assert_eq!(paths.nodes_on_best_paths().len(), 8);
+
+        // A single best path only has one predecessor per state
+        let paths = dijkstra(start, successors, |&p| p == end);
+        assert_eq!(paths.predecessors(&end).len(), 1);
+        assert_eq!(paths.nodes_on_best_paths().len(), 5);
+
+        // Several goals at the same best cost are all found
+        let corners = [Point::new(2, 0), Point::new(0, 2)];
+        let paths = dijkstra_all(start, successors, |p| corners.contains(p));
+        assert_eq!(paths.goals().len(), 2);
+        assert_eq!(paths.cost(), Some(2));
+    }
+
+    #[test]
+    fn test_bidirectional_bfs() {
+        let (maze, start, end) = parse();
+        let result = bidirectional_bfs(
+            start,
+            end,
+            |&p| open_neighbors(&maze, p),
+            |&p| open_neighbors(&maze, p),
+        );
+        assert_eq!(result.distance(), Some(15));
+        let path = result.path().unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!((path[0], path[15]), (start, end));
+        assert!(path
+            .windows(2)
+            .all(|w| w[0].manhattan_distance_to(&w[1]) == 1));
+
+        let blocked = bidirectional_bfs(start, Point::new(3, 0), |_| vec![], |_| vec![]);
+        assert_eq!(blocked.distance(), None);
+        assert_eq!(
+            bidirectional_bfs(start, start, |_| vec![], |_| vec![]).distance(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_directed_bidirectional_bfs() {
+        // 0 -> 1 -> 2 -> ... -> 9, plus a shortcut 2 -> 7
+        let succ = |&n: &u32| {
+            let mut next = vec![];
+            if n < 9 {
+                next.push(n + 1);
+            }
+            if n == 2 {
+                next.push(7);
+            }
+            next
+        };
+        let pred = |&n: &u32| {
+            let mut prev = vec![];
+            if n > 0 {
+                prev.push(n - 1);
+            }
+            if n == 7 {
+                prev.push(2);
+            }
+            prev
+        };
+        let result = bidirectional_bfs(0, 9, succ, pred);
+        assert_eq!(result.distance(), Some(5));
+        assert_eq!(result.path().unwrap(), vec![0, 1, 2, 7, 8, 9]);
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// A single day's puzzle solution.
+///
+/// The input is parsed once and the parsed form is shared by both parts. `Input` may borrow
+/// from the raw puzzle text, so days that work directly on the string can use `&'a str`.
+pub trait Solution {
+    /// Parsed representation of the puzzle input.
+    type Input<'a>;
+
+    /// Parse the raw puzzle input.
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    /// Solve part 1.
+    fn part1(input: &Self::Input<'_>) -> impl Display;
+
+    /// Solve part 2. Puzzles without a second part (day 25) keep the default.
+    fn part2(_input: &Self::Input<'_>) -> impl Display {
+        "-"
+    }
+}
+
+/// Which part(s) of a puzzle to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    /// Parse a part selector: "1", "2" or "both"
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => Err(format!("Invalid part: {s}")),
+        }
+    }
+
+    pub fn includes(&self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Part::Both, _) | (Part::One, 1) | (Part::Two, 2)
+        )
+    }
+}
+
+/// Options understood by every day binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunOptions {
+    pub part: Part,
+    /// Emit machine readable lines for the `aoc` runner instead of the usual output.
+    pub report: bool,
+}
+
+impl RunOptions {
+    /// Parse `--part <1|2|both>` and `--report` from the command line arguments.
+    pub fn from_args<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = RunOptions {
+            part: Part::Both,
+            report: false,
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let value = args.next().ok_or("Missing value for --part")?;
+                    options.part = Part::parse(&value)?;
+                }
+                "--report" => options.report = true,
+                _ => return Err(format!("Unknown argument: {arg}")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Answer and timing for one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// Parse the input and run the requested parts of a solution.
+pub fn solve<S: Solution>(input: &str, part: Part) -> (Duration, Vec<PartResult>) {
+    let start = Instant::now();
+    let parsed = S::parse(input);
+    let parse_elapsed = start.elapsed();
+
+    let mut results = Vec::new();
+    if part.includes(1) {
+        let start = Instant::now();
+        let answer = S::part1(&parsed).to_string();
+        let elapsed = start.elapsed();
+        results.push(PartResult {
+            part: 1,
+            answer,
+            elapsed,
+        });
+    }
+    if part.includes(2) {
+        let start = Instant::now();
+        let answer = S::part2(&parsed).to_string();
+        let elapsed = start.elapsed();
+        results.push(PartResult {
+            part: 2,
+            answer,
+            elapsed,
+        });
+    }
+    (parse_elapsed, results)
+}
+
+/// Entry point for a day binary.
+///
+/// Runs the parts selected on the command line and prints the answers with their timings.
+pub fn run<S: Solution>(input: &str) {
+    let options = RunOptions::from_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        eprintln!("Usage: [--part <1|2|both>] [--report]");
+        std::process::exit(2);
+    });
+
+    let (parse_elapsed, results) = solve::<S>(input, options.part);
+    if options.report {
+        println!("{}", report_line("parse", parse_elapsed, ""));
+        for result in &results {
+            let label = format!("part{}", result.part);
+            println!("{}", report_line(&label, result.elapsed, &result.answer));
+        }
+        return;
+    }
+
+    for result in &results {
+        let (answer, elapsed) = (&result.answer, result.elapsed);
+        if answer.contains('\n') {
+            println!("Part {}: elapsed: {elapsed:.1?}\n{answer}", result.part);
+        } else {
+            println!("Part {}: {answer}, elapsed: {elapsed:.1?}", result.part);
+        }
+    }
+}
+
+/// Format one `--report` line: `label<TAB>nanoseconds<TAB>answer` with newlines escaped.
+pub fn report_line(label: &str, elapsed: Duration, answer: &str) -> String {
+    let answer = answer.replace('\\', "\\\\").replace('\n', "\\n");
+    format!("{label}\t{}\t{answer}", elapsed.as_nanos())
+}
+
+/// Parse a line produced by `report_line`.
+pub fn parse_report_line(line: &str) -> Option<(&str, Duration, String)> {
+    let mut fields = line.splitn(3, '\t');
+    let label = fields.next()?;
+    let nanos: u64 = fields.next()?.parse().ok()?;
+    let escaped = fields.next()?;
+
+    let mut answer = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => answer.push('\n'),
+                Some(other) => answer.push(other),
+                None => answer.push('\\'),
+            }
+        } else {
+            answer.push(c);
+        }
+    }
+    Some((label, Duration::from_nanos(nanos), answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<i32>;
+
+        fn parse(input: &str) -> Vec<i32> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Display {
+            input.iter().sum::<i32>()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Display {
+            input.iter().product::<i32>()
+        }
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Vec<&str> {
+            input.lines().collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Display {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Display {
+            input.join("\n")
+        }
+    }
+
+    #[test]
+    fn test_solve_both_parts() {
+        let (_, results) = solve::<Sum>("2\n3\n4", Part::Both);
+        let answers: Vec<_> = results
+            .iter()
+            .map(|r| (r.part, r.answer.as_str()))
+            .collect();
+        assert_eq!(answers, vec![(1, "9"), (2, "24")]);
+    }
+
+    #[test]
+    fn test_solve_single_part() {
+        let (_, results) = solve::<Lines>("a\nb", Part::Two);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].part, 2);
+        assert_eq!(results[0].answer, "a\nb");
+    }
+
+    #[test]
+    fn test_run_options() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+        let options = RunOptions::from_args(args("--part 2 --report")).unwrap();
+        assert_eq!(options.part, Part::Two);
+        assert!(options.report);
+
+        let options = RunOptions::from_args(args("")).unwrap();
+        assert_eq!(options.part, Part::Both);
+        assert!(!options.report);
+
+        assert!(RunOptions::from_args(args("--part 3")).is_err());
+        assert!(RunOptions::from_args(args("--part")).is_err());
+        assert!(RunOptions::from_args(args("--bogus")).is_err());
+    }
+
+    #[test]
+    fn test_report_line_round_trip() {
+        let elapsed = Duration::from_nanos(1234);
+        let line = report_line("part2", elapsed, "##..\n..##\\");
+        assert!(!line.contains('\n'));
+        let (label, parsed_elapsed, answer) = parse_report_line(&line).unwrap();
+        assert_eq!(label, "part2");
+        assert_eq!(parsed_elapsed, elapsed);
+        assert_eq!(answer, "##..\n..##\\");
+    }
+}
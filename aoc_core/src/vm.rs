@@ -0,0 +1,401 @@
+//! A small virtual machine for the puzzles that come with their own assembly language.
+//!
+//! Each puzzle defines an [`Instruction`] type, which says how one instruction changes the
+//! machine's registers and where execution goes next. [`Machine`] does the rest: the program
+//! counter, output, step and cycle counts, and the debugging aids (tracing, breakpoints, loop
+//! detection and a step limit). [`Registers`] is a fixed-size register file for instruction
+//! sets that don't need anything fancier.
+
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+use std::ops::{Index, IndexMut};
+
+/// Where execution continues after an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// The next instruction.
+    Next,
+    /// An offset from the current instruction.
+    Jump(i64),
+    /// An absolute instruction index.
+    Goto(usize),
+    /// Stop the machine.
+    Halt,
+}
+
+/// One instruction of a puzzle's instruction set.
+pub trait Instruction: Clone + Debug {
+    /// The machine state the instructions operate on.
+    type Registers: Clone + Debug;
+    /// Values the program emits.
+    type Output: Clone + Debug;
+
+    fn execute(&self, regs: &mut Self::Registers, out: &mut Vec<Self::Output>) -> Flow;
+
+    /// Clock cycles the instruction takes.
+    fn cycles(&self) -> u64 {
+        1
+    }
+}
+
+/// Why [`Machine::run`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The program counter moved past the last instruction.
+    Exited,
+    /// An instruction returned [`Flow::Halt`].
+    Halted,
+    /// The program counter moved before the first instruction.
+    Fault(i64),
+    /// About to execute the instruction at a breakpoint. Running again executes it.
+    Breakpoint(usize),
+    /// About to execute an instruction for the second time (see [`Machine::detect_loops`]).
+    Loop(usize),
+    /// The step limit was reached.
+    StepLimit,
+}
+
+/// One executed instruction, with the registers as they were before it ran.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry<I: Instruction> {
+    pub step: u64,
+    pub pc: usize,
+    pub instr: I,
+    pub regs: I::Registers,
+}
+
+/// A program loaded into a machine, ready to run.
+#[derive(Debug, Clone)]
+pub struct Machine<I: Instruction> {
+    program: Vec<I>,
+    regs: I::Registers,
+    pc: usize,
+    steps: u64,
+    cycles: u64,
+    output: Vec<I::Output>,
+    breakpoints: BTreeSet<usize>,
+    /// Set when stopped at a breakpoint, so the next run executes the instruction.
+    resuming: bool,
+    step_limit: Option<u64>,
+    executed: Option<Vec<bool>>,
+    trace: Option<Vec<TraceEntry<I>>>,
+}
+
+impl<I: Instruction> Machine<I> {
+    pub fn new(program: Vec<I>, regs: I::Registers) -> Self {
+        Self {
+            program,
+            regs,
+            pc: 0,
+            steps: 0,
+            cycles: 0,
+            output: Vec::new(),
+            breakpoints: BTreeSet::new(),
+            resuming: false,
+            step_limit: None,
+            executed: None,
+            trace: None,
+        }
+    }
+
+    /// Stop with [`Stop::Loop`] instead of executing any instruction twice. This finds
+    /// infinite loops in programs whose jumps don't depend on the registers.
+    pub fn detect_loops(mut self) -> Self {
+        self.executed = Some(vec![false; self.program.len()]);
+        self
+    }
+
+    /// Stop with [`Stop::StepLimit`] once `limit` instructions have been executed.
+    pub fn with_step_limit(mut self, limit: u64) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    /// Record every executed instruction, see [`Machine::trace`].
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(Vec::new());
+        self
+    }
+
+    pub fn add_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn remove_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.remove(&pc);
+    }
+
+    pub fn program(&self) -> &[I] {
+        &self.program
+    }
+
+    pub fn regs(&self) -> &I::Registers {
+        &self.regs
+    }
+
+    pub fn regs_mut(&mut self) -> &mut I::Registers {
+        &mut self.regs
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Number of instructions executed.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Number of clock cycles elapsed.
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    pub fn output(&self) -> &[I::Output] {
+        &self.output
+    }
+
+    pub fn take_output(&mut self) -> Vec<I::Output> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// The executed instructions, if tracing is on.
+    pub fn trace(&self) -> Option<&[TraceEntry<I>]> {
+        self.trace.as_deref()
+    }
+
+    /// Start the program again from the first instruction with new registers. Breakpoints and
+    /// the other settings are kept.
+    pub fn reset(&mut self, regs: I::Registers) {
+        self.regs = regs;
+        self.pc = 0;
+        self.steps = 0;
+        self.cycles = 0;
+        self.output.clear();
+        self.resuming = false;
+        if let Some(executed) = &mut self.executed {
+            executed.fill(false);
+        }
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+    }
+
+    /// Execute a single instruction, or return why the machine can't.
+    pub fn step(&mut self) -> Result<(), Stop> {
+        self.step_with(&mut |_, _| {})
+    }
+
+    /// Run until the program stops.
+    pub fn run(&mut self) -> Stop {
+        self.run_with(|_, _| {})
+    }
+
+    /// Run until the program stops, calling `on_cycle` with the cycle number (from 1) and the
+    /// registers during every clock cycle. A multi-cycle instruction only changes the
+    /// registers at the end of its last cycle.
+    pub fn run_with(&mut self, mut on_cycle: impl FnMut(u64, &I::Registers)) -> Stop {
+        loop {
+            if let Err(stop) = self.step_with(&mut on_cycle) {
+                return stop;
+            }
+        }
+    }
+
+    fn step_with(&mut self, on_cycle: &mut impl FnMut(u64, &I::Registers)) -> Result<(), Stop> {
+        let pc = self.pc;
+        let Some(instr) = self.program.get(pc) else {
+            return Err(Stop::Exited);
+        };
+        if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+            return Err(Stop::StepLimit);
+        }
+        if !self.resuming && self.breakpoints.contains(&pc) {
+            self.resuming = true;
+            return Err(Stop::Breakpoint(pc));
+        }
+        self.resuming = false;
+        if let Some(executed) = &mut self.executed {
+            if std::mem::replace(&mut executed[pc], true) {
+                return Err(Stop::Loop(pc));
+            }
+        }
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry {
+                step: self.steps,
+                pc,
+                instr: instr.clone(),
+                regs: self.regs.clone(),
+            });
+        }
+
+        for _ in 0..instr.cycles() {
+            self.cycles += 1;
+            on_cycle(self.cycles, &self.regs);
+        }
+        self.steps += 1;
+        let target = match instr.execute(&mut self.regs, &mut self.output) {
+            Flow::Next => pc as i64 + 1,
+            Flow::Jump(offset) => pc as i64 + offset,
+            Flow::Goto(target) => target as i64,
+            Flow::Halt => return Err(Stop::Halted),
+        };
+        if target < 0 {
+            return Err(Stop::Fault(target));
+        }
+        self.pc = target as usize;
+        Ok(())
+    }
+}
+
+/// A register file of `N` registers, indexed by number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Registers<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> Registers<T, N> {
+    pub fn new(values: [T; N]) -> Self {
+        Self(values)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.0.iter()
+    }
+}
+
+impl<T: Default, const N: usize> Default for Registers<T, N> {
+    fn default() -> Self {
+        Self(std::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Registers<T, N> {
+    type Output = T;
+
+    fn index(&self, reg: usize) -> &T {
+        &self.0[reg]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Registers<T, N> {
+    fn index_mut(&mut self, reg: usize) -> &mut T {
+        &mut self.0[reg]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A counter machine: `Add(r, n)`, `Jnz(r, offset)` and `Out(r)`.
+    #[derive(Debug, Clone, PartialEq)]
+    enum Op {
+        Add(usize, i64),
+        Jnz(usize, i64),
+        Out(usize),
+        Slow,
+        Halt,
+    }
+
+    impl Instruction for Op {
+        type Registers = Registers<i64, 2>;
+        type Output = i64;
+
+        fn execute(&self, regs: &mut Self::Registers, out: &mut Vec<i64>) -> Flow {
+            match *self {
+                Op::Add(r, n) => regs[r] += n,
+                Op::Jnz(r, offset) if regs[r] != 0 => return Flow::Jump(offset),
+                Op::Jnz(..) | Op::Slow => {}
+                Op::Out(r) => out.push(regs[r]),
+                Op::Halt => return Flow::Halt,
+            }
+            Flow::Next
+        }
+
+        fn cycles(&self) -> u64 {
+            if *self == Op::Slow {
+                3
+            } else {
+                1
+            }
+        }
+    }
+
+    /// Count register 0 down from 3, emitting each value.
+    fn countdown() -> Vec<Op> {
+        vec![
+            Op::Add(0, 3),
+            Op::Out(0),
+            Op::Add(0, -1),
+            Op::Jnz(0, -2),
+            Op::Out(0),
+        ]
+    }
+
+    #[test]
+    fn test_run() {
+        let mut machine = Machine::new(countdown(), Registers::default());
+        assert_eq!(machine.run(), Stop::Exited);
+        assert_eq!(machine.output(), [3, 2, 1, 0]);
+        assert_eq!(machine.steps(), 11);
+        assert_eq!(machine.run(), Stop::Exited);
+
+        let mut machine = Machine::new(vec![Op::Halt, Op::Out(0)], Registers::default());
+        assert_eq!(machine.run(), Stop::Halted);
+        assert!(machine.output().is_empty());
+
+        let mut machine = Machine::new(vec![Op::Add(0, 1), Op::Jnz(0, -5)], Registers::default());
+        assert_eq!(machine.run(), Stop::Fault(-4));
+    }
+
+    #[test]
+    fn test_loops_and_limits() {
+        let forever = vec![Op::Add(0, 1), Op::Add(1, 1), Op::Jnz(1, -1)];
+        let mut machine = Machine::new(forever.clone(), Registers::default()).detect_loops();
+        assert_eq!(machine.run(), Stop::Loop(1));
+        assert_eq!(machine.regs()[1], 1);
+
+        let mut machine = Machine::new(forever, Registers::default()).with_step_limit(10);
+        assert_eq!(machine.run(), Stop::StepLimit);
+        assert_eq!(machine.regs()[1], 5);
+        assert_eq!(machine.steps(), 10);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut machine = Machine::new(countdown(), Registers::default());
+        machine.add_breakpoint(1);
+        let mut seen = Vec::new();
+        loop {
+            match machine.run() {
+                Stop::Breakpoint(pc) => seen.push((pc, machine.regs()[0])),
+                stop => {
+                    assert_eq!(stop, Stop::Exited);
+                    break;
+                }
+            }
+        }
+        assert_eq!(seen, [(1, 3), (1, 2), (1, 1)]);
+        assert_eq!(machine.output(), [3, 2, 1, 0]);
+
+        machine.reset(Registers::new([1, 0]));
+        machine.remove_breakpoint(1);
+        assert_eq!(machine.run(), Stop::Exited);
+        assert_eq!(machine.take_output(), [4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_trace_and_cycles() {
+        let program = vec![Op::Add(1, 2), Op::Slow, Op::Add(1, 1)];
+        let mut machine = Machine::new(program, Registers::default()).with_trace();
+        let mut during = Vec::new();
+        machine.run_with(|cycle, regs| during.push((cycle, regs[1])));
+        assert_eq!(during, [(1, 0), (2, 2), (3, 2), (4, 2), (5, 2)]);
+        assert_eq!(machine.cycles(), 5);
+
+        let trace = machine.trace().unwrap();
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace[2].pc, 2);
+        assert_eq!(trace[2].instr, Op::Add(1, 1));
+        assert_eq!(trace[2].regs, Registers::new([0, 2]));
+    }
+}
@@ -0,0 +1,32 @@
+//! Regression tests for the answers recorded in each year's `answers.toml`.
+//!
+//! `build.rs` generates one test per recorded answer. They run the day binaries against the
+//! real puzzle inputs, which aren't checked in, so they're ignored by default:
+//!
+//! ```sh
+//! cargo test --test answers -- --ignored
+//! ```
+
+use std::path::Path;
+
+use aoc_core::runner::{self, Year};
+use aoc_core::{Part, Verdict};
+
+fn check(year: u16, day: u8, part: u8) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let year = Year::open(&root, year).expect("year directory");
+    let answers = year.answers().unwrap();
+    let selected = if part == 1 { Part::One } else { Part::Two };
+
+    let report = runner::run_day(&year, day, selected, true).unwrap();
+    let result = report.parts.iter().find(|r| r.part == part).unwrap();
+    assert_eq!(
+        answers.check(day, part, &result.answer),
+        Verdict::Pass,
+        "expected {:?}, got {:?}",
+        answers.get(day, part).unwrap(),
+        result.answer
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));